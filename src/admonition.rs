@@ -0,0 +1,131 @@
+//! Admonitions of Python-Markdown and MkDocs, e.g. `!!! note`.
+
+use crate::LF;
+
+/// Whether an admonition is collapsible, per the PyMdown Details extension.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Collapse {
+    /// Always expanded (`!!!`).
+    #[default]
+    None,
+    /// Collapsible and initially collapsed (`???`).
+    Collapsed,
+    /// Collapsible and initially expanded (`???+`).
+    Expanded,
+}
+
+impl Collapse {
+    /// The marker opening the admonition.
+    pub fn marker(self) -> &'static str {
+        match self {
+            Collapse::None => "!!!",
+            Collapse::Collapsed => "???",
+            Collapse::Expanded => "???+",
+        }
+    }
+}
+
+/// An admonition whose body is indented four spaces beneath its `!!! type "Title"` line.
+///
+/// Without a title the type is shown as the title, while an empty title hides the title bar
+/// altogether. Quotes in the title are kept as they are, since Python-Markdown takes everything
+/// up to the last quote on the line as the title, but line breaks are replaced with spaces.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::admonition::{Admonition, Collapse};
+///
+/// let admonition = Admonition::new("warning")
+///     .title("Breaking \"change\"\nahead")
+///     .collapse(Collapse::Collapsed)
+///     .render("Rename `foo`.\n\n```sh\nsed -i s/foo/bar/\n```");
+///
+/// assert_eq!(
+///     admonition,
+///     "??? warning \"Breaking \"change\" ahead\"\n    Rename `foo`.\n\n    \
+///      ```sh\n    sed -i s/foo/bar/\n    ```"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://python-markdown.github.io/extensions/admonition/>
+/// - <https://squidfunk.github.io/mkdocs-material/reference/admonitions/>
+/// - <https://facelessuser.github.io/pymdown-extensions/extensions/details/>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Admonition<'a> {
+    kind: &'a str,
+    title: Option<&'a str>,
+    collapse: Collapse,
+}
+
+impl<'a> Admonition<'a> {
+    /// Create an admonition of the provided type, e.g. `note`, `tip` or `danger`.
+    pub fn new(kind: &'a str) -> Admonition<'a> {
+        Admonition { kind, title: None, collapse: Collapse::None }
+    }
+
+    /// Set the title shown instead of the type. An empty title hides the title bar.
+    pub fn title(self, title: &'a str) -> Admonition<'a> {
+        Admonition { title: Some(title), ..self }
+    }
+
+    /// Set whether the admonition is collapsible.
+    pub fn collapse(self, collapse: Collapse) -> Admonition<'a> {
+        Admonition { collapse, ..self }
+    }
+
+    /// Render the admonition around the provided body.
+    pub fn render(&self, body: &str) -> String {
+        let mut lines = vec![format!("{} {}", self.collapse.marker(), self.kind)];
+
+        if let Some(title) = self.title {
+            let title = title.lines().collect::<Vec<&str>>().join(" ");
+
+            lines[0].push_str(&format!(" \"{title}\""));
+        }
+
+        lines.extend(body.lines().map(|line| {
+            if line.trim().is_empty() { String::new() } else { format!("    {line}") }
+        }));
+        lines.join(&LF.to_string())
+    }
+}
+
+/// Create an always expanded admonition with an optional title.
+///
+/// Examples
+/// ========
+/// ```
+/// let note = md_writer::admonition::admonition("note", None, "Requires Python 3.8.");
+///
+/// assert_eq!(note, "!!! note\n    Requires Python 3.8.");
+/// ```
+pub fn admonition(kind: &str, title: Option<&str>, body: &str) -> String {
+    let admonition = Admonition::new(kind);
+
+    match title {
+        Some(title) => admonition.title(title).render(body),
+        None => admonition.render(body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_titles_and_expanded_admonitions() {
+        let admonition = Admonition::new("tip").title("").collapse(Collapse::Expanded);
+
+        assert_eq!(admonition.render("a\n  \nb"), "???+ tip \"\"\n    a\n\n    b");
+    }
+
+    #[test]
+    fn title_quotes_are_kept_and_line_breaks_joined() {
+        let admonition = Admonition::new("note").title("Say \"hi\"\r\nthere");
+
+        assert_eq!(admonition.render("a"), "!!! note \"Say \"hi\" there\"\n    a");
+    }
+}
@@ -0,0 +1,103 @@
+//! GitHub alerts, blockquotes which are rendered as callouts, e.g. `> [!NOTE]`.
+
+use crate::strict::{self, Expected};
+use crate::write::{write_alert, written};
+
+/// The kind of an alert, which determines its icon and color.
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#alerts>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AlertKind {
+    /// Useful information users should know, even when skimming.
+    Note,
+    /// Helpful advice for doing things better or more easily.
+    Tip,
+    /// Key information users need to know to achieve their goal.
+    Important,
+    /// Urgent info that needs immediate user attention to avoid problems.
+    Warning,
+    /// Advises about risks or negative outcomes of certain actions.
+    Caution,
+}
+
+impl AlertKind {
+    /// The type of the alert as written in its marker, e.g. `NOTE` in `[!NOTE]`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlertKind::Note => "NOTE",
+            AlertKind::Tip => "TIP",
+            AlertKind::Important => "IMPORTANT",
+            AlertKind::Warning => "WARNING",
+            AlertKind::Caution => "CAUTION",
+        }
+    }
+}
+
+/// Create a GitHub alert of the provided kind. Every line of the text is prefixed, so it may
+/// contain multiple paragraphs and other blocks.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::alert::{alert, AlertKind};
+///
+/// assert_eq!(
+///     alert(AlertKind::Warning, "Breaking change.\n\nSee the migration guide."),
+///     "> [!WARNING]\n> Breaking change.\n>\n> See the migration guide."
+/// );
+/// ```
+pub fn alert(kind: AlertKind, text: &str) -> String {
+    let alert = written(|out| write_alert(out, kind, text));
+
+    strict::verify(&alert, Expected::BlockQuote);
+
+    alert
+}
+
+/// Create a `[!NOTE]` alert, see [`alert`].
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::alert::note("Requires Rust 1.70."), "> [!NOTE]\n> Requires Rust 1.70.");
+/// ```
+pub fn note(text: &str) -> String {
+    alert(AlertKind::Note, text)
+}
+
+/// Create a `[!TIP]` alert, see [`alert`].
+pub fn tip(text: &str) -> String {
+    alert(AlertKind::Tip, text)
+}
+
+/// Create an `[!IMPORTANT]` alert, see [`alert`].
+pub fn important(text: &str) -> String {
+    alert(AlertKind::Important, text)
+}
+
+/// Create a `[!WARNING]` alert, see [`alert`].
+pub fn warning(text: &str) -> String {
+    alert(AlertKind::Warning, text)
+}
+
+/// Create a `[!CAUTION]` alert, see [`alert`].
+pub fn caution(text: &str) -> String {
+    alert(AlertKind::Caution, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alerts_prefix_nested_blocks() {
+        assert_eq!(
+            caution("Don't:\n\n- run as root"),
+            "> [!CAUTION]\n> Don't:\n>\n> - run as root"
+        );
+        assert_eq!(tip("a"), "> [!TIP]\n> a");
+        assert_eq!(important("a"), "> [!IMPORTANT]\n> a");
+    }
+}
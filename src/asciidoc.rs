@@ -0,0 +1,223 @@
+//! Conversion of documents to AsciiDoc, e.g. for migrating generators to Antora.
+
+use crate::document::{Block, Document, Inline};
+use crate::options::RenderOptions;
+use crate::LF;
+
+/// Convert a document to AsciiDoc.
+///
+/// Headings become sections of the same level (a level 1 heading becomes the document title),
+/// code blocks become source blocks, and lists, tables and blockquotes become their AsciiDoc
+/// counterparts. Raw HTML is passed through and lazy blocks are evaluated with the default
+/// options.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::asciidoc::to_asciidoc;
+/// use md_writer::document::{Block, Document, Inline};
+///
+/// let document = Document::from(vec![
+///     Block::heading(2, "Install"),
+///     Block::Paragraph(vec![
+///         Inline::text("Run "),
+///         Inline::Code("cargo add md-writer".to_owned()),
+///         Inline::text(" and see the "),
+///         Inline::Link {
+///             text: vec![Inline::text("docs")],
+///             destination: "https://docs.rs/md-writer".to_owned(),
+///             title: None,
+///         },
+///         Inline::text("."),
+///     ]),
+///     Block::CodeBlock { info: Some("rust".to_owned()), code: "use md_writer::h1;".to_owned() },
+/// ]);
+///
+/// assert_eq!(
+///     to_asciidoc(&document),
+///     "== Install\n\n\
+///      Run `+cargo add md-writer+` and see the https://docs.rs/md-writer[docs].\n\n\
+///      [source,rust]\n----\nuse md_writer::h1;\n----"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.asciidoctor.org/asciidoc/latest/syntax-quick-reference/>
+pub fn to_asciidoc(document: &Document) -> String {
+    blocks(document.blocks())
+}
+
+fn blocks(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(|block| block_to_asciidoc(block, 0))
+        .collect::<Vec<String>>()
+        .join(&LF.to_string().repeat(2))
+}
+
+/// Convert a block, nested in `depth` lists, to AsciiDoc.
+fn block_to_asciidoc(block: &Block, depth: usize) -> String {
+    match block {
+        Block::Heading { level, content } => {
+            format!("{} {}", "=".repeat((*level).clamp(1, 6).into()), inlines(content))
+        }
+        Block::Paragraph(content) => inlines(content),
+        Block::CodeBlock { info, code } => {
+            let delimiter = delimiter('-', code);
+            let language = info
+                .as_deref()
+                .and_then(|info| info.split_whitespace().next())
+                .map_or_else(String::new, |language| format!("[source,{language}]{LF}"));
+
+            format!("{language}{delimiter}{LF}{code}{LF}{delimiter}")
+        }
+        Block::BlockQuote(content) => {
+            let content = blocks(content);
+            let delimiter = delimiter('_', &content);
+
+            format!("{delimiter}{LF}{content}{LF}{delimiter}")
+        }
+        Block::List { start, items } => {
+            let marker = if start.is_some() { "." } else { "*" }.repeat(depth + 1);
+            let start = start
+                .filter(|start| *start != 1)
+                .map_or_else(String::new, |start| format!("[start={start}]{LF}"));
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| {
+                    let mut text = format!("{marker} ");
+
+                    for (index, block) in item.iter().enumerate() {
+                        match block {
+                            // Nested lists attach to the item by themselves, while other
+                            // blocks need a list continuation.
+                            Block::List { .. } => {
+                                text.push(LF);
+                                text.push_str(&block_to_asciidoc(block, depth + 1));
+                            }
+                            _ if index == 0 => text.push_str(&block_to_asciidoc(block, 0)),
+                            _ => {
+                                text.push_str(&format!("{LF}+{LF}"));
+                                text.push_str(&block_to_asciidoc(block, 0));
+                            }
+                        }
+                    }
+
+                    text
+                })
+                .collect();
+
+            format!("{start}{}", items.join(&LF.to_string()))
+        }
+        Block::Table { headers, rows } => {
+            let row = |cells: &[Vec<Inline>]| -> String {
+                cells
+                    .iter()
+                    .map(|cell| format!("|{}", inlines(cell).replace('|', "\\|")))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            };
+            let mut lines = vec!["|===".to_owned(), row(headers), String::new()];
+
+            lines.extend(rows.iter().map(|cells| row(cells)));
+            lines.push("|===".to_owned());
+            lines.join(&LF.to_string())
+        }
+        Block::ThematicBreak => "'''".to_owned(),
+        Block::Html(html) => format!("++++{LF}{}{LF}++++", html.trim_end()),
+        Block::Lazy(lazy) => block_to_asciidoc(&lazy.evaluate(&RenderOptions::default()), depth),
+    }
+}
+
+/// A block delimiter at least four characters long and longer than any line of the content
+/// made up of the same character.
+fn delimiter(char: char, content: &str) -> String {
+    let longest = content
+        .lines()
+        .filter(|line| !line.is_empty() && line.chars().all(|c| c == char))
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+
+    char.to_string().repeat(longest.max(3) + 1)
+}
+
+fn inlines(inlines: &[Inline]) -> String {
+    inlines.iter().map(inline).collect()
+}
+
+/// Escape the characters AsciiDoc reads as formatting marks: runs of text containing them are
+/// passed through as they are with `++…++`, and pluses, which would end a passthrough, are
+/// written as the `{plus}` attribute.
+fn escape(text: &str) -> String {
+    text.split('+')
+        .map(|run| {
+            if run.contains(['*', '_', '`', '#', '^', '~']) {
+                format!("++{run}++")
+            } else {
+                run.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("{plus}")
+}
+
+fn inline(inline: &Inline) -> String {
+    match inline {
+        Inline::Text(text) => escape(text),
+        Inline::Code(code) => format!("`+{code}+`"),
+        Inline::Emphasis(content) => format!("_{}_", inlines(content)),
+        Inline::Strong(content) => format!("*{}*", inlines(content)),
+        Inline::Strikethrough(content) => format!("[.line-through]#{}#", inlines(content)),
+        Inline::Link { text, destination, .. } => {
+            let text = inlines(text).replace(']', "\\]");
+
+            if destination.starts_with("http://") || destination.starts_with("https://") {
+                format!("{destination}[{text}]")
+            } else {
+                format!("link:{destination}[{text}]")
+            }
+        }
+        Inline::Image { alt, source, title } => {
+            let title = title
+                .as_deref()
+                .map_or_else(String::new, |title| format!(",title=\"{title}\""));
+
+            format!("image:{source}[\"{alt}\"{title}]")
+        }
+        Inline::Html(html) => format!("pass:[{html}]"),
+        Inline::LineBreak => format!(" +{LF}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_asciidoc_nests_lists_and_continues_items() {
+        let document = Document::from(vec![Block::List {
+            start: None,
+            items: vec![vec![
+                Block::paragraph("Install"),
+                Block::CodeBlock { info: None, code: "----\n".to_owned() },
+                Block::List { start: Some(3), items: vec![vec![Block::paragraph("Nested")]] },
+            ]],
+        }]);
+
+        assert_eq!(
+            to_asciidoc(&document),
+            "* Install\n+\n-----\n----\n\n-----\n[start=3]\n.. Nested"
+        );
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        let paragraph = |text: &str| to_asciidoc(&Document::from(vec![Block::paragraph(text)]));
+
+        assert_eq!(paragraph("a*b*c"), "++a*b*c++");
+        assert_eq!(paragraph("C++ in #rust_lang"), "C{plus}{plus}++ in #rust_lang++");
+        assert_eq!(paragraph("plain text"), "plain text");
+    }
+}
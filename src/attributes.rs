@@ -0,0 +1,93 @@
+//! Attributes of Pandoc, kramdown and Python-Markdown, e.g. `{#id .class key=value}`.
+
+/// A builder of an attribute list: an identifier, classes and key-value pairs.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::attributes::Attributes;
+///
+/// let attributes = Attributes::new()
+///     .id("api")
+///     .class("reference")
+///     .attribute("data-since", "1.2")
+///     .attribute("title", "HTTP API");
+///
+/// assert_eq!(attributes.render(), "{#api .reference data-since=1.2 title=\"HTTP API\"}");
+/// assert_eq!(Attributes::new().render(), "");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#extension-header_attributes>
+/// - <https://kramdown.gettalong.org/syntax.html#inline-attribute-lists>
+/// - <https://python-markdown.github.io/extensions/attr_list/>
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Attributes {
+    id: Option<String>,
+    classes: Vec<String>,
+    pairs: Vec<(String, String)>,
+}
+
+impl Attributes {
+    /// Create an empty attribute list.
+    pub fn new() -> Attributes {
+        Attributes::default()
+    }
+
+    /// Set the identifier, e.g. the anchor of a heading.
+    pub fn id(self, id: impl Into<String>) -> Attributes {
+        Attributes { id: Some(id.into()), ..self }
+    }
+
+    /// Add a class.
+    pub fn class(mut self, class: impl Into<String>) -> Attributes {
+        self.classes.push(class.into());
+        self
+    }
+
+    /// Add a key-value pair. Values which aren't a single word are quoted.
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Attributes {
+        self.pairs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Whether the list has no identifier, classes or key-value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() && self.classes.is_empty() && self.pairs.is_empty()
+    }
+
+    /// Render the attribute list in braces, or nothing when it's empty.
+    pub fn render(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let id = self.id.iter().map(|id| format!("#{id}"));
+        let classes = self.classes.iter().map(|class| format!(".{class}"));
+        let pairs = self.pairs.iter().map(|(key, value)| {
+            let bare = !value.is_empty()
+                && !value.contains(|char: char| char.is_whitespace() || "\"'{}".contains(char));
+
+            if bare {
+                format!("{key}={value}")
+            } else {
+                format!("{key}=\"{}\"", value.replace('"', "\\\""))
+            }
+        });
+
+        format!("{{{}}}", id.chain(classes).chain(pairs).collect::<Vec<String>>().join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_quotes_values_which_would_end_the_list() {
+        let attributes = Attributes::new().attribute("a", "}").attribute("b", "").class("c");
+
+        assert_eq!(attributes.render(), "{.c a=\"}\" b=\"\"}");
+    }
+}
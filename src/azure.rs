@@ -0,0 +1,80 @@
+//! Azure DevOps wiki constructs.
+
+use crate::container::Container;
+
+/// The directive the wiki replaces with a table of contents of the headings of the page.
+///
+/// Reference
+/// =========
+/// - <https://learn.microsoft.com/en-us/azure/devops/project/wiki/markdown-guidance#table-of-contents-toc-for-wiki-pages>
+pub const TABLE_OF_CONTENTS: &str = "[[_TOC_]]";
+
+/// The directive the wiki replaces with a list of the subpages of the page.
+///
+/// Reference
+/// =========
+/// - <https://learn.microsoft.com/en-us/azure/devops/project/wiki/markdown-guidance#add-a-subpages-table>
+pub const SUBPAGES: &str = "[[_TOSP_]]";
+
+/// Create a Mermaid diagram, which the wiki renders from a `::: mermaid` container rather than
+/// a fenced code block.
+///
+/// Examples
+/// ========
+/// ```
+/// let diagram = md_writer::azure::mermaid("graph LR\n    A --> B");
+///
+/// assert_eq!(diagram, "::: mermaid\ngraph LR\n    A --> B\n:::");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://learn.microsoft.com/en-us/azure/devops/project/wiki/markdown-guidance#add-mermaid-diagrams-to-a-wiki-page>
+pub fn mermaid(source: &str) -> String {
+    Container::new("mermaid").render(source.trim_end())
+}
+
+/// The path of a file attached to the wiki, which lives in its `.attachments` folder.
+///
+/// Spaces are percent-encoded since the wiki doesn't resolve paths containing them.
+///
+/// Examples
+/// ========
+/// ```
+/// let path = md_writer::azure::attachment_path("build log.txt");
+///
+/// assert_eq!(path, "/.attachments/build%20log.txt");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://learn.microsoft.com/en-us/azure/devops/project/wiki/markdown-guidance#attachments>
+pub fn attachment_path(file_name: &str) -> String {
+    format!("/.attachments/{}", file_name.replace(' ', "%20"))
+}
+
+/// Create a link to a file attached to the wiki.
+///
+/// Examples
+/// ========
+/// ```
+/// let link = md_writer::azure::attachment_link("Logs", "build.log");
+///
+/// assert_eq!(link, "[Logs](/.attachments/build.log)");
+/// ```
+pub fn attachment_link(text: &str, file_name: &str) -> String {
+    format!("[{text}]({})", attachment_path(file_name))
+}
+
+/// Create an image of a file attached to the wiki.
+///
+/// Examples
+/// ========
+/// ```
+/// let image = md_writer::azure::attachment_image("Architecture", "architecture.png");
+///
+/// assert_eq!(image, "![Architecture](/.attachments/architecture.png)");
+/// ```
+pub fn attachment_image(alt: &str, file_name: &str) -> String {
+    format!("![{alt}]({})", attachment_path(file_name))
+}
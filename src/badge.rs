@@ -0,0 +1,161 @@
+//! Shields.io badges, e.g. for the badge row of a README.
+
+use crate::url::percent_encode;
+use crate::{image, link};
+
+/// The style of a badge.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BadgeStyle {
+    /// Flat with rounded corners, shields.io's default.
+    #[default]
+    Flat,
+    /// Flat with square corners.
+    FlatSquare,
+    /// Plastic with a gradient.
+    Plastic,
+    /// Large and uppercased.
+    ForTheBadge,
+    /// Like a social network's button.
+    Social,
+}
+
+impl BadgeStyle {
+    /// The style as written in the `style` query parameter, e.g. `flat-square`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BadgeStyle::Flat => "flat",
+            BadgeStyle::FlatSquare => "flat-square",
+            BadgeStyle::Plastic => "plastic",
+            BadgeStyle::ForTheBadge => "for-the-badge",
+            BadgeStyle::Social => "social",
+        }
+    }
+}
+
+/// A builder of static shields.io badges, rendered as an image which is optionally linked.
+///
+/// The label and message are escaped as shields.io requires, with dashes and underscores
+/// doubled and spaces written as underscores, and then percent-encoded. An empty label gives a
+/// badge of only the message. Colors may be named (`brightgreen`) or hex, with or without `#`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::badge::{Badge, BadgeStyle};
+///
+/// let badge = Badge::new("build status", "passing", "brightgreen")
+///     .style(BadgeStyle::FlatSquare)
+///     .link("https://github.com/owner/repo/actions");
+///
+/// assert_eq!(
+///     badge.render(),
+///     "[![build status: passing](https://img.shields.io/badge/build_status-passing-brightgreen\
+///      ?style=flat-square)](https://github.com/owner/repo/actions)"
+/// );
+/// assert_eq!(
+///     Badge::new("", "v1.0-rc_1", "#ff69b4").url(),
+///     "https://img.shields.io/badge/v1.0--rc__1-ff69b4"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://shields.io/badges/static-badge>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Badge<'a> {
+    label: &'a str,
+    message: &'a str,
+    color: &'a str,
+    style: Option<BadgeStyle>,
+    logo: Option<&'a str>,
+    link: Option<&'a str>,
+}
+
+impl<'a> Badge<'a> {
+    /// Create a badge of the provided label, message and color.
+    pub fn new(label: &'a str, message: &'a str, color: &'a str) -> Badge<'a> {
+        Badge { label, message, color, style: None, logo: None, link: None }
+    }
+
+    /// Set the style of the badge.
+    pub fn style(self, style: BadgeStyle) -> Badge<'a> {
+        Badge { style: Some(style), ..self }
+    }
+
+    /// Set the logo shown before the label, e.g. a Simple Icons slug such as `rust`.
+    pub fn logo(self, logo: &'a str) -> Badge<'a> {
+        Badge { logo: Some(logo), ..self }
+    }
+
+    /// Set the URL the badge links to.
+    pub fn link(self, link: &'a str) -> Badge<'a> {
+        Badge { link: Some(link), ..self }
+    }
+
+    /// The URL of the badge's image.
+    pub fn url(&self) -> String {
+        let color = percent_encode(self.color.trim_start_matches('#'));
+        let mut url = if self.label.is_empty() {
+            format!("https://img.shields.io/badge/{}-{color}", escape(self.message))
+        } else {
+            format!(
+                "https://img.shields.io/badge/{}-{}-{color}",
+                escape(self.label),
+                escape(self.message)
+            )
+        };
+        let query: Vec<String> = [
+            self.style.map(|style| format!("style={}", style.as_str())),
+            self.logo.map(|logo| format!("logo={}", percent_encode(logo))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+
+        url
+    }
+
+    /// Render the badge as an image, linked when a link is set.
+    pub fn render(&self) -> String {
+        let alt = if self.label.is_empty() {
+            self.message.to_owned()
+        } else {
+            format!("{}: {}", self.label, self.message)
+        };
+        let image = image(&alt, &self.url(), None);
+
+        match self.link {
+            Some(destination) => link(&image, destination, None),
+            None => image,
+        }
+    }
+}
+
+/// Escape a badge's label or message for the path of its URL.
+fn escape(text: &str) -> String {
+    percent_encode(&text.replace('-', "--").replace('_', "__").replace(' ', "_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_percent_encodes_after_doubling_separators() {
+        assert_eq!(escape("C/C++ 100%"), "C%2FC%2B%2B_100%25");
+        assert_eq!(escape("a - b"), "a_--_b");
+    }
+
+    #[test]
+    fn logo_is_added_to_the_query() {
+        assert_eq!(
+            Badge::new("rust", "1.70+", "orange").logo("rust").render(),
+            "![rust: 1.70+](https://img.shields.io/badge/rust-1.70%2B-orange?logo=rust)"
+        );
+    }
+}
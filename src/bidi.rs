@@ -0,0 +1,121 @@
+//! Right-to-left and bidirectional text support.
+//!
+//! Mixed direction documents render sanely when runs of right-to-left text are wrapped in
+//! directional isolates, see [`isolate_rtl`]. The isolate characters (like every other
+//! bidirectional control character) take up no space when displayed, which [`display_width`]
+//! accounts for when sizing setext underlines.
+
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthChar;
+
+/// The right-to-left isolate control character.
+pub const RLI: char = '\u{2067}';
+
+/// The pop directional isolate control character.
+pub const PDI: char = '\u{2069}';
+
+/// Whether the provided character is a bidirectional formatting control character.
+///
+/// Examples
+/// ========
+/// ```
+/// assert!(md_writer::bidi::is_bidi_control('\u{200F}'));
+/// assert!(!md_writer::bidi::is_bidi_control('a'));
+/// ```
+pub fn is_bidi_control(char: char) -> bool {
+    matches!(
+        char,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Whether the provided character belongs to a right-to-left script.
+///
+/// Examples
+/// ========
+/// ```
+/// assert!(md_writer::bidi::is_rtl_char('ש'));
+/// assert!(!md_writer::bidi::is_rtl_char('s'));
+/// ```
+pub fn is_rtl_char(char: char) -> bool {
+    matches!(
+        char,
+        '\u{0590}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    )
+}
+
+/// Whether the provided text is right-to-left, going by its first strongly directional
+/// character.
+///
+/// Examples
+/// ========
+/// ```
+/// assert!(md_writer::bidi::is_rtl("שלום world"));
+/// assert!(!md_writer::bidi::is_rtl("Hello עולם"));
+/// assert!(!md_writer::bidi::is_rtl("123"));
+/// ```
+pub fn is_rtl(text: &str) -> bool {
+    text.chars()
+        .find(|char| char.is_alphabetic())
+        .is_some_and(is_rtl_char)
+}
+
+/// The number of columns the provided text takes up when displayed in a monospace font, not
+/// counting bidirectional control characters. Wide characters, e.g. CJK and most emoji, take up
+/// two columns.
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "\u{2067}שלום\u{2069}";
+///
+/// assert_eq!(md_writer::bidi::display_width(text), 4);
+/// assert_eq!(md_writer::bidi::display_width("日本語"), 6);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.chars()
+        .filter(|char| !is_bidi_control(*char))
+        .map(|char| char.width().unwrap_or(0))
+        .sum()
+}
+
+/// Wrap right-to-left text in a right-to-left isolate so it doesn't reorder the surrounding
+/// left-to-right content, leaving any other text as is.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::bidi::{isolate_rtl, PDI, RLI};
+///
+/// let text = "שלום";
+/// let h1 = md_writer::h1(&isolate_rtl(text));
+///
+/// assert_eq!(h1, format!("{RLI}{text}{PDI}\n===="));
+/// assert_eq!(isolate_rtl("Hello"), "Hello");
+/// ```
+pub fn isolate_rtl(text: &str) -> Cow<'_, str> {
+    if is_rtl(text) {
+        Cow::Owned(format!("{RLI}{text}{PDI}"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_ignores_marks_and_embeddings() {
+        assert_eq!(display_width("\u{202B}مرحبا\u{202C}\u{200F}"), 5);
+    }
+
+    #[test]
+    fn isolate_rtl_wraps_rtl_text() {
+        assert_eq!(isolate_rtl("مرحبا"), "\u{2067}مرحبا\u{2069}");
+    }
+}
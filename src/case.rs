@@ -0,0 +1,171 @@
+//! Title case and sentence case conversion for headings.
+
+use std::borrow::Cow;
+
+/// Words which aren't capitalized in title case unless they begin or end the title, per the AP
+/// and Chicago style guides.
+const SMALL_WORDS: [&str; 23] = [
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "off",
+    "on", "or", "per", "so", "the", "to", "up", "via", "vs",
+];
+
+/// A casing style to apply to text, e.g. to keep generated section titles consistent.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TextCase {
+    /// Leave the text as is.
+    #[default]
+    Preserve,
+    /// Convert the text with [`title_case`].
+    Title,
+    /// Convert the text with [`sentence_case`].
+    Sentence,
+}
+
+impl TextCase {
+    /// Apply this casing style to the provided text.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::case::TextCase;
+    ///
+    /// let h2 = md_writer::h2(&TextCase::Title.apply("getting started"));
+    ///
+    /// assert_eq!(h2, "Getting Started\n---------------");
+    /// ```
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            TextCase::Preserve => Cow::Borrowed(text),
+            TextCase::Title => Cow::Owned(title_case(text)),
+            TextCase::Sentence => Cow::Owned(sentence_case(text)),
+        }
+    }
+}
+
+/// Convert text to title case.
+///
+/// Every word is capitalized except for articles, conjunctions and short prepositions which
+/// aren't the first or last word of the title or the first word after a colon. Words which
+/// already contain a capital letter after their first letter (e.g. "API" or "GitHub") are left
+/// as is.
+///
+/// Examples
+/// ========
+/// ```
+/// let title = md_writer::case::title_case("the lord of the rings: the return of the king");
+///
+/// assert_eq!(title, "The Lord of the Rings: The Return of the King");
+/// assert_eq!(md_writer::case::title_case("using the GitHub API"), "Using the GitHub API");
+/// ```
+pub fn title_case(text: &str) -> String {
+    let words: Vec<&str> = text.split(' ').collect();
+    let last = words.iter().rposition(|word| !word.is_empty());
+    let mut capitalize_next = true;
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let force = capitalize_next || Some(index) == last;
+
+            if !word.is_empty() {
+                capitalize_next = word.ends_with(':');
+            }
+
+            word.split('-')
+                .enumerate()
+                .map(|(part_index, part)| {
+                    let bare = part.trim_matches(|char: char| !char.is_alphanumeric());
+
+                    if has_inner_capital(part) {
+                        part.to_owned()
+                    } else if (force && part_index == 0) || !is_small_word(bare) {
+                        capitalize(&part.to_lowercase())
+                    } else {
+                        part.to_lowercase()
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("-")
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Convert text to sentence case.
+///
+/// The first word is capitalized and every other word is lowercased, except for words which
+/// contain a capital letter after their first letter (e.g. "API" or "GitHub") and the pronoun
+/// "I".
+///
+/// Examples
+/// ========
+/// ```
+/// let sentence = md_writer::case::sentence_case("Configuring The GitHub API Client");
+///
+/// assert_eq!(sentence, "Configuring the GitHub API client");
+/// ```
+pub fn sentence_case(text: &str) -> String {
+    let mut first = true;
+
+    text.split(' ')
+        .map(|word| {
+            let converted = if has_inner_capital(word) || word == "I" {
+                word.to_owned()
+            } else if first && !word.is_empty() {
+                capitalize(&word.to_lowercase())
+            } else {
+                word.to_lowercase()
+            };
+
+            first = first && word.is_empty();
+
+            converted
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn is_small_word(word: &str) -> bool {
+    SMALL_WORDS.contains(&word.to_lowercase().as_str())
+}
+
+/// Whether the word contains an uppercase letter after its first letter.
+fn has_inner_capital(word: &str) -> bool {
+    word.chars()
+        .skip_while(|char| !char.is_alphabetic())
+        .skip(1)
+        .any(char::is_uppercase)
+}
+
+/// Uppercase the first letter of the word.
+fn capitalize(word: &str) -> String {
+    match word.char_indices().find(|(_, char)| char.is_alphabetic()) {
+        Some((index, char)) => {
+            let rest = &word[index + char.len_utf8()..];
+
+            format!("{}{}{rest}", &word[..index], char.to_uppercase())
+        }
+        None => word.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_case_capitalizes_a_trailing_small_word() {
+        assert_eq!(title_case("what it's made of"), "What It's Made Of");
+    }
+
+    #[test]
+    fn title_case_handles_hyphenated_words() {
+        assert_eq!(title_case("a step-by-step guide"), "A Step-by-Step Guide");
+    }
+
+    #[test]
+    fn sentence_case_keeps_acronyms() {
+        assert_eq!(sentence_case("Why I Use HTTP/2"), "Why I use HTTP/2");
+    }
+}
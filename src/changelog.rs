@@ -0,0 +1,291 @@
+//! Changelogs in the Keep a Changelog format.
+
+use crate::labels::Labels;
+use crate::link_reference::LinkReferences;
+use crate::list::list_item;
+use crate::{h1_atx, h2_atx, h3, LF};
+
+/// The introduction Keep a Changelog recommends.
+const DESCRIPTION: &str = "All notable changes to this project will be documented in this file.\n\n\
+    The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n\
+    and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).";
+
+/// The kind of a change, which determines the section of a release it's listed in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChangeKind {
+    /// A new feature.
+    Added,
+    /// A change in existing functionality.
+    Changed,
+    /// A soon-to-be removed feature.
+    Deprecated,
+    /// A now removed feature.
+    Removed,
+    /// A bug fix.
+    Fixed,
+    /// A fixed vulnerability.
+    Security,
+}
+
+impl ChangeKind {
+    /// Every kind of change, in the order their sections are rendered.
+    pub const ALL: [ChangeKind; 6] = [
+        ChangeKind::Added,
+        ChangeKind::Changed,
+        ChangeKind::Deprecated,
+        ChangeKind::Removed,
+        ChangeKind::Fixed,
+        ChangeKind::Security,
+    ];
+
+    /// The title of the kind's section in the provided labels.
+    pub fn label(self, labels: &Labels) -> &str {
+        match self {
+            ChangeKind::Added => &labels.added,
+            ChangeKind::Changed => &labels.changed,
+            ChangeKind::Deprecated => &labels.deprecated,
+            ChangeKind::Removed => &labels.removed,
+            ChangeKind::Fixed => &labels.fixed,
+            ChangeKind::Security => &labels.security,
+        }
+    }
+}
+
+/// A released version and its changes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Release {
+    version: String,
+    date: String,
+    yanked: bool,
+    changes: Vec<(ChangeKind, String)>,
+}
+
+impl Release {
+    /// Create a release of the provided version on the provided ISO 8601 date, e.g.
+    /// `2024-05-01`.
+    pub fn new(version: &str, date: &str) -> Release {
+        Release {
+            version: version.to_owned(),
+            date: date.to_owned(),
+            yanked: false,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Set whether the release was pulled because of a serious bug or security issue.
+    pub fn yanked(self, yanked: bool) -> Release {
+        Release { yanked, ..self }
+    }
+
+    /// Add a change, which may contain Markdown. Its continuation lines are indented so they
+    /// stay part of its list item.
+    pub fn change(mut self, kind: ChangeKind, change: &str) -> Release {
+        self.changes.push((kind, change.to_owned()));
+        self
+    }
+}
+
+/// A builder of a changelog in the Keep a Changelog 1.1.0 format.
+///
+/// The Unreleased section comes first, followed by the releases in the order they're added,
+/// which should be newest first. Changes are grouped by kind in the conventional order. When a
+/// repository is set, every version heading links to the comparison with the previous version,
+/// and the oldest to its tag.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::changelog::{ChangeKind, Changelog, Release};
+///
+/// let changelog = Changelog::new()
+///     .repository("https://github.com/owner/repo")
+///     .unreleased(ChangeKind::Fixed, "Escape pipes in table cells.")
+///     .release(
+///         Release::new("1.1.0", "2024-05-01")
+///             .change(ChangeKind::Fixed, "Wrap long lines.")
+///             .change(ChangeKind::Added, "Add `Changelog`."),
+///     )
+///     .release(Release::new("1.0.0", "2024-01-01").change(ChangeKind::Added, "Initial release."));
+///
+/// assert!(changelog.render().ends_with(
+///     "## [Unreleased]\n\n### Fixed\n\n- Escape pipes in table cells.\n\n## [1.1.0] - \
+///      2024-05-01\n\n### Added\n\n- Add `Changelog`.\n\n### Fixed\n\n- Wrap long lines.\n\n## \
+///      [1.0.0] - 2024-01-01\n\n### Added\n\n- Initial release.\n\n\
+///      [Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD\n\
+///      [1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n\
+///      [1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0"
+/// ));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://keepachangelog.com/en/1.1.0/>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Changelog {
+    description: String,
+    repository: Option<String>,
+    tag_prefix: String,
+    unreleased: Vec<(ChangeKind, String)>,
+    releases: Vec<Release>,
+    labels: Labels,
+}
+
+impl Default for Changelog {
+    fn default() -> Changelog {
+        Changelog {
+            description: DESCRIPTION.to_owned(),
+            repository: None,
+            tag_prefix: "v".to_owned(),
+            unreleased: Vec::new(),
+            releases: Vec::new(),
+            labels: Labels::default(),
+        }
+    }
+}
+
+impl Changelog {
+    /// Create a changelog with the introduction Keep a Changelog recommends and no releases.
+    pub fn new() -> Changelog {
+        Changelog::default()
+    }
+
+    /// Replace the introduction following the title.
+    pub fn description(self, description: &str) -> Changelog {
+        Changelog { description: description.to_owned(), ..self }
+    }
+
+    /// Set the URL of the GitHub or GitLab repository the version comparison links point to.
+    pub fn repository(self, url: &str) -> Changelog {
+        Changelog { repository: Some(url.trim_end_matches('/').to_owned()), ..self }
+    }
+
+    /// Set the prefix of the versions' tags, `v` by default.
+    pub fn tag_prefix(self, tag_prefix: &str) -> Changelog {
+        Changelog { tag_prefix: tag_prefix.to_owned(), ..self }
+    }
+
+    /// Use the provided labels for the titles of the changelog and its sections.
+    pub fn with_labels(self, labels: Labels) -> Changelog {
+        Changelog { labels, ..self }
+    }
+
+    /// Add an unreleased change, which may contain Markdown.
+    pub fn unreleased(mut self, kind: ChangeKind, change: &str) -> Changelog {
+        self.unreleased.push((kind, change.to_owned()));
+        self
+    }
+
+    /// Add a release, after those already added.
+    pub fn release(mut self, release: Release) -> Changelog {
+        self.releases.push(release);
+        self
+    }
+
+    /// Render the changelog.
+    pub fn render(&self) -> String {
+        let mut blocks = vec![h1_atx(&self.labels.changelog), self.description.clone()];
+        let mut references = LinkReferences::new();
+
+        blocks.push(h2_atx(&format!("[{}]", self.labels.unreleased)));
+        blocks.extend(self.changes(&self.unreleased));
+
+        for release in &self.releases {
+            let mut heading = format!("[{}] - {}", release.version, release.date);
+
+            if release.yanked {
+                heading.push_str(" [YANKED]");
+            }
+
+            blocks.push(h2_atx(&heading));
+            blocks.extend(self.changes(&release.changes));
+        }
+
+        if let Some(repository) = &self.repository {
+            let tag = |version: &str| format!("{}{version}", self.tag_prefix);
+
+            if let Some(latest) = self.releases.first() {
+                references.define(
+                    &self.labels.unreleased,
+                    &format!("{repository}/compare/{}...HEAD", tag(&latest.version)),
+                    None,
+                );
+            }
+
+            for (index, release) in self.releases.iter().enumerate() {
+                let destination = match self.releases.get(index + 1) {
+                    Some(previous) => format!(
+                        "{repository}/compare/{}...{}",
+                        tag(&previous.version),
+                        tag(&release.version)
+                    ),
+                    None => format!("{repository}/releases/tag/{}", tag(&release.version)),
+                };
+
+                references.define(&release.version, &destination, None);
+            }
+        }
+
+        if !references.is_empty() {
+            blocks.push(references.definitions());
+        }
+
+        blocks.join(&LF.to_string().repeat(2))
+    }
+
+    /// Render the sections of the provided changes, grouped by kind.
+    fn changes(&self, changes: &[(ChangeKind, String)]) -> Vec<String> {
+        ChangeKind::ALL
+            .iter()
+            .filter_map(|kind| {
+                let items: Vec<String> = changes
+                    .iter()
+                    .filter(|(change_kind, _)| change_kind == kind)
+                    .map(|(_, change)| list_item("- ", change, None))
+                    .collect();
+
+                (!items.is_empty()).then(|| {
+                    let heading = h3(kind.label(&self.labels));
+
+                    format!("{heading}{LF}{LF}{}", items.join(&LF.to_string()))
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_changelog_has_an_unreleased_section() {
+        assert_eq!(
+            Changelog::new().description("Notable changes.").render(),
+            "# Changelog\n\nNotable changes.\n\n## [Unreleased]"
+        );
+    }
+
+    #[test]
+    fn yanked_releases_are_marked() {
+        let changelog = Changelog::new()
+            .tag_prefix("")
+            .repository("https://gitlab.com/group/project/")
+            .release(Release::new("0.1.0", "2023-01-01").yanked(true));
+
+        assert!(changelog.render().ends_with(
+            "## [0.1.0] - 2023-01-01 [YANKED]\n\n\
+             [Unreleased]: https://gitlab.com/group/project/compare/0.1.0...HEAD\n\
+             [0.1.0]: https://gitlab.com/group/project/releases/tag/0.1.0"
+        ));
+    }
+
+    #[test]
+    fn multi_line_changes_stay_in_their_list_item() {
+        let changelog = Changelog::new()
+            .unreleased(ChangeKind::Changed, "Line one\n# Not a heading\n\n- Nested");
+
+        assert!(changelog.render().ends_with(
+            "### Changed\n\n- Line one\n  # Not a heading\n\n  - Nested"
+        ));
+    }
+}
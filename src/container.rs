@@ -0,0 +1,183 @@
+//! Custom containers, e.g. for VuePress, markdown-it-container, remark-directive or Pandoc.
+
+use crate::LF;
+
+/// A custom container whose fence is sized to enclose any containers nested in its body.
+///
+/// The fence is made up of a delimiter character (`:` by default) repeated at least a minimum
+/// number of times (3 by default). When the body contains a fence of the same character the
+/// container's fence is made one character longer than the longest one, so nested containers
+/// close where they're intended to.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::container::Container;
+///
+/// let inner = Container::new("tip").render("Nested.");
+/// let outer = Container::new("details").attributes("More").render(&inner);
+///
+/// assert_eq!(outer, ":::: details More\n::: tip\nNested.\n:::\n::::");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Container<'a> {
+    name: &'a str,
+    attributes: Option<&'a str>,
+    delimiter: char,
+    min_length: usize,
+    tight: bool,
+}
+
+impl<'a> Container<'a> {
+    /// Create a container with the provided name.
+    pub fn new(name: &'a str) -> Container<'a> {
+        Container {
+            name,
+            attributes: None,
+            delimiter: ':',
+            min_length: 3,
+            tight: false,
+        }
+    }
+
+    /// Set the attributes (or title, depending on the container plugin) following the name.
+    pub fn attributes(self, attributes: &'a str) -> Container<'a> {
+        Container { attributes: Some(attributes), ..self }
+    }
+
+    /// Set the character the fence is made of.
+    pub fn delimiter(self, delimiter: char) -> Container<'a> {
+        Container { delimiter, ..self }
+    }
+
+    /// Set the minimum length of the fence.
+    pub fn min_length(self, min_length: usize) -> Container<'a> {
+        Container { min_length, ..self }
+    }
+
+    /// Set whether the name directly follows the fence with no space in between, as
+    /// remark-directive container directives (`:::note`) are written.
+    pub fn tight(self, tight: bool) -> Container<'a> {
+        Container { tight, ..self }
+    }
+
+    /// Render the container around the provided body.
+    pub fn render(&self, body: &str) -> String {
+        let longest_nested = body
+            .lines()
+            .map(|line| fence_length(line, self.delimiter))
+            .max()
+            .unwrap_or(0);
+        let fence = self
+            .delimiter
+            .to_string()
+            .repeat(self.min_length.max(longest_nested + 1));
+        let separator = if self.tight { "" } else { " " };
+        let mut opening = format!("{fence}{separator}{}", self.name);
+
+        if let Some(attributes) = self.attributes.filter(|attributes| !attributes.is_empty()) {
+            opening.push(' ');
+            opening.push_str(attributes);
+        }
+
+        [opening, body.to_owned(), fence].join(&LF.to_string())
+    }
+}
+
+/// Create a custom container with a `:::` fence, lengthened as needed to enclose any containers
+/// nested in the body.
+///
+/// Examples
+/// ========
+/// ```
+/// let warning = md_writer::container::container("warning", Some("Careful"), "Hot surface.");
+///
+/// assert_eq!(warning, "::: warning Careful\nHot surface.\n:::");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.com/markdown-it/markdown-it-container>
+/// - <https://vuepress.vuejs.org/guide/markdown.html#custom-containers>
+pub fn container(name: &str, attributes: Option<&str>, body: &str) -> String {
+    let container = Container::new(name);
+
+    match attributes {
+        Some(attributes) => container.attributes(attributes).render(body),
+        None => container.render(body),
+    }
+}
+
+/// Create a Pandoc fenced div with the provided attributes, e.g. `.warning #intro`. A single
+/// class name is written bare, while anything else is wrapped in braces. The fence is
+/// lengthened as needed to enclose any divs nested in the content.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::container::fenced_div;
+///
+/// let inner = fenced_div("Back up first.", "warning");
+///
+/// assert_eq!(inner, "::: warning\nBack up first.\n:::");
+/// assert_eq!(
+///     fenced_div(&inner, ".section #intro lang=en"),
+///     ":::: {.section #intro lang=en}\n::: warning\nBack up first.\n:::\n::::"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#extension-fenced_divs>
+pub fn fenced_div(content: &str, attributes: &str) -> String {
+    let attributes = attributes.trim();
+    let is_bare = !attributes.is_empty()
+        && attributes
+            .chars()
+            .all(|char| char.is_alphanumeric() || matches!(char, '-' | '_'));
+    let name = if is_bare || (attributes.starts_with('{') && attributes.ends_with('}')) {
+        attributes.to_owned()
+    } else {
+        format!("{{{attributes}}}")
+    };
+
+    Container::new(&name).render(content)
+}
+
+/// The length of the run of the delimiter character beginning the line, allowing for up to
+/// three spaces of indentation.
+fn fence_length(line: &str, delimiter: char) -> usize {
+    let unindented = line.trim_start_matches(' ');
+
+    if line.len() - unindented.len() > 3 {
+        return 0;
+    }
+
+    unindented.chars().take_while(|char| *char == delimiter).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fenced_div_keeps_braced_attributes() {
+        assert_eq!(fenced_div("a", "{#id}"), "::: {#id}\na\n:::");
+        assert_eq!(fenced_div("a", ".note"), "::: {.note}\na\n:::");
+    }
+
+    #[test]
+    fn render_lengthens_the_fence_around_nested_containers() {
+        let inner = container("b", None, &container("c", None, "text"));
+        let outer = container("a", None, &inner);
+
+        assert_eq!(outer, "::::: a\n:::: b\n::: c\ntext\n:::\n::::\n:::::");
+    }
+
+    #[test]
+    fn render_supports_custom_delimiters() {
+        let container = Container::new("note").delimiter('!').min_length(4).tight(true);
+
+        assert_eq!(container.render("Hi"), "!!!!note\nHi\n!!!!");
+    }
+}
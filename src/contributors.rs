@@ -0,0 +1,135 @@
+//! Contributor sections, e.g. from the output of `git shortlog -sne`.
+
+use crate::escape::escape_html_attribute;
+use crate::table::pipe_table;
+use crate::url::percent_encode;
+
+/// A contributor to a project.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Contributor {
+    /// The name of the contributor.
+    pub name: String,
+    /// The email address of the contributor.
+    pub email: String,
+    /// The number of commits authored by the contributor.
+    pub commits: usize,
+}
+
+impl Contributor {
+    /// The URL of the avatar GitHub shows for the contributor's email address.
+    fn avatar_url(&self, size: u32) -> String {
+        format!(
+            "https://avatars.githubusercontent.com/u/e?email={}&s={size}",
+            percent_encode(&self.email)
+        )
+    }
+}
+
+/// How a contributor section is laid out.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ContributorLayout {
+    /// A table of names and commit counts.
+    #[default]
+    Table,
+    /// A grid of avatar images of the provided size in pixels, titled with names and commit
+    /// counts.
+    AvatarGrid(u32),
+}
+
+/// Parse the output of `git shortlog -sne` into contributors.
+///
+/// Lines which aren't of the form `<count>\t<name> <<email>>` are skipped.
+///
+/// Examples
+/// ========
+/// ```
+/// let shortlog = "    42\tAda Lovelace <ada@example.com>\n     7\tGrace Hopper <gh@example.com>";
+/// let contributors = md_writer::contributors::parse_shortlog(shortlog);
+///
+/// assert_eq!(contributors[0].name, "Ada Lovelace");
+/// assert_eq!(contributors[1].commits, 7);
+/// ```
+pub fn parse_shortlog(shortlog: &str) -> Vec<Contributor> {
+    shortlog
+        .lines()
+        .filter_map(|line| {
+            let (commits, author) = line.trim().split_once('\t')?;
+            let (name, email) = author.trim_end().strip_suffix('>')?.rsplit_once('<')?;
+
+            Some(Contributor {
+                name: name.trim().to_owned(),
+                email: email.to_owned(),
+                commits: commits.trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Render a contributor section in the provided layout, keeping the order of the contributors.
+///
+/// Email addresses aren't shown, though the avatar grid uses them to look up GitHub avatars.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::contributors::{contributors, parse_shortlog, ContributorLayout};
+///
+/// let list = parse_shortlog("    42\tAda Lovelace <ada@example.com>");
+///
+/// assert_eq!(
+///     contributors(&list, ContributorLayout::Table),
+///     "| Name | Commits |\n| --- | --- |\n| Ada Lovelace | 42 |"
+/// );
+/// assert_eq!(
+///     contributors(&list, ContributorLayout::AvatarGrid(64)),
+///     "<img src=\"https://avatars.githubusercontent.com/u/e?email=ada%40example.com&s=64\" \
+///      width=\"64\" height=\"64\" alt=\"Ada Lovelace\" title=\"Ada Lovelace (42 commits)\">"
+/// );
+/// ```
+pub fn contributors(contributors: &[Contributor], layout: ContributorLayout) -> String {
+    match layout {
+        ContributorLayout::Table => {
+            let headers = ["Name", "Commits"].map(String::from);
+            let rows: Vec<Vec<String>> = contributors
+                .iter()
+                .map(|contributor| vec![contributor.name.clone(), contributor.commits.to_string()])
+                .collect();
+
+            pipe_table(&headers, &rows)
+        }
+        ContributorLayout::AvatarGrid(size) => contributors
+            .iter()
+            .map(|contributor| {
+                let name = escape_html_attribute(&contributor.name);
+                let noun = if contributor.commits == 1 { "commit" } else { "commits" };
+
+                format!(
+                    "<img src=\"{}\" width=\"{size}\" height=\"{size}\" alt=\"{name}\" \
+                     title=\"{name} ({} {noun})\">",
+                    contributor.avatar_url(size),
+                    contributor.commits
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_shortlog_skips_malformed_lines() {
+        let contributors = parse_shortlog("garbage\n  1\tBot <bot@example.com>\n x\tNo <no@x>");
+
+        assert_eq!(
+            contributors,
+            [Contributor {
+                name: "Bot".to_owned(),
+                email: "bot@example.com".to_owned(),
+                commits: 1,
+            }]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Coverage summaries, e.g. for CI bots commenting on pull requests.
+
+use crate::diagnostics::collapsed;
+use crate::table::pipe_table;
+use crate::xml::{nodes, Node};
+use crate::LF;
+use std::collections::BTreeMap;
+
+/// The line coverage of a source file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileCoverage {
+    /// The path of the file.
+    pub path: String,
+    /// The number of instrumented lines.
+    pub lines: usize,
+    /// The number of instrumented lines which were executed.
+    pub covered: usize,
+}
+
+impl FileCoverage {
+    /// The percentage of instrumented lines which were executed, 100 when there are none.
+    pub fn percentage(&self) -> f64 {
+        percentage(self.covered, self.lines)
+    }
+}
+
+/// The line coverage of a project, file by file.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::coverage::CoverageReport;
+///
+/// let lcov = "SF:src/lib.rs\nDA:1,1\nDA:2,0\nend_of_record\nSF:src/main.rs\nDA:1,3";
+/// let summary = CoverageReport::from_lcov(lcov).render(75.0);
+///
+/// assert!(summary.starts_with("**Total coverage: 66.7%** 🟡\n\n| File | Lines | Coverage |"));
+/// assert!(summary.contains("| src/lib.rs | 1/2 | 🔴 50.0% |"));
+/// assert!(summary.contains("<summary>1 file below 75.0%</summary>"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoverageReport {
+    /// The files, in the order they were reported.
+    pub files: Vec<FileCoverage>,
+}
+
+impl CoverageReport {
+    /// Import an lcov tracefile, as written by `cargo llvm-cov --lcov` or `grcov -t lcov`.
+    ///
+    /// Reference
+    /// =========
+    /// - <https://manpages.debian.org/unstable/lcov/geninfo.1.en.html#TRACEFILE_FORMAT>
+    pub fn from_lcov(lcov: &str) -> CoverageReport {
+        let mut files = Vec::new();
+        let mut current: Option<(String, BTreeMap<usize, bool>)> = None;
+
+        for line in lcov.lines().map(str::trim) {
+            if let Some(path) = line.strip_prefix("SF:") {
+                current = Some((path.to_owned(), BTreeMap::new()));
+            } else if let Some(record) = line.strip_prefix("DA:") {
+                let mut fields = record.split(',');
+                let number = fields.next().and_then(|number| number.parse().ok());
+                let hits = fields.next().and_then(|hits| hits.parse::<u64>().ok());
+
+                if let (Some((_, lines)), Some(number), Some(hits)) = (&mut current, number, hits) {
+                    *lines.entry(number).or_default() |= hits > 0;
+                }
+            } else if line == "end_of_record" {
+                files.extend(current.take().map(|(path, lines)| file_coverage(path, &lines)));
+            }
+        }
+
+        files.extend(current.map(|(path, lines)| file_coverage(path, &lines)));
+        CoverageReport { files }
+    }
+
+    /// Import a Cobertura XML report, as written by `cargo tarpaulin --out Xml`.
+    ///
+    /// Classes sharing a file name are merged into one file.
+    ///
+    /// Reference
+    /// =========
+    /// - <https://github.com/cobertura/cobertura/blob/master/cobertura/src/site/htdocs/xml/coverage-04.dtd>
+    pub fn from_cobertura(xml: &str) -> CoverageReport {
+        let mut order = Vec::new();
+        let mut lines_by_file: BTreeMap<String, BTreeMap<usize, bool>> = BTreeMap::new();
+        let mut current = None;
+
+        for node in nodes(xml) {
+            match node {
+                Node::Start { name: "class", .. } => {
+                    current = node.attribute("filename").map(str::to_owned);
+
+                    if let Some(path) = &current {
+                        if !lines_by_file.contains_key(path) {
+                            order.push(path.clone());
+                            lines_by_file.insert(path.clone(), BTreeMap::new());
+                        }
+                    }
+                }
+                Node::End("class") => current = None,
+                Node::Start { name: "line", .. } => {
+                    let lines = current.as_ref().and_then(|path| lines_by_file.get_mut(path));
+                    let number = node.attribute("number").and_then(|number| number.parse().ok());
+                    let hits = node.attribute("hits").and_then(|hits| hits.parse::<u64>().ok());
+
+                    if let (Some(lines), Some(number), Some(hits)) = (lines, number, hits) {
+                        *lines.entry(number).or_default() |= hits > 0;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let files = order
+            .into_iter()
+            .map(|path| {
+                let lines = &lines_by_file[&path];
+
+                file_coverage(path, lines)
+            })
+            .collect();
+
+        CoverageReport { files }
+    }
+
+    /// The number of instrumented lines and the number of them executed across every file.
+    pub fn totals(&self) -> (usize, usize) {
+        self.files
+            .iter()
+            .fold((0, 0), |(lines, covered), file| (lines + file.lines, covered + file.covered))
+    }
+
+    /// The percentage of instrumented lines executed across every file.
+    pub fn percentage(&self) -> f64 {
+        let (lines, covered) = self.totals();
+
+        percentage(covered, lines)
+    }
+
+    /// Render the total coverage followed by a table of the coverage of every file, and a
+    /// collapsible table of the files whose coverage is below the threshold percentage.
+    ///
+    /// Coverages at or above the threshold are marked 🟢, those within 10 percentage points
+    /// below it 🟡 and the rest 🔴.
+    pub fn render(&self, threshold: f64) -> String {
+        let headers = ["File", "Lines", "Coverage"].map(String::from);
+        let rows = |files: &[&FileCoverage]| -> Vec<Vec<String>> {
+            files
+                .iter()
+                .map(|file| {
+                    let percentage = file.percentage();
+
+                    vec![
+                        file.path.clone(),
+                        format!("{}/{}", file.covered, file.lines),
+                        format!("{} {:.1}%", marker(percentage, threshold), percentage),
+                    ]
+                })
+                .collect()
+        };
+        let files: Vec<&FileCoverage> = self.files.iter().collect();
+        let mut below: Vec<&FileCoverage> = files
+            .iter()
+            .copied()
+            .filter(|file| file.percentage() < threshold)
+            .collect();
+        let mut blocks = vec![
+            format!(
+                "**Total coverage: {:.1}%** {}",
+                self.percentage(),
+                marker(self.percentage(), threshold)
+            ),
+            pipe_table(&headers, &rows(&files)),
+        ];
+
+        if !below.is_empty() {
+            let noun = if below.len() == 1 { "file" } else { "files" };
+
+            below.sort_by(|a, b| a.percentage().total_cmp(&b.percentage()));
+            blocks.push(collapsed(
+                &format!("{} {noun} below {threshold:.1}%", below.len()),
+                &pipe_table(&headers, &rows(&below)),
+            ));
+        }
+
+        blocks.join(&LF.to_string().repeat(2))
+    }
+}
+
+fn file_coverage(path: String, lines: &BTreeMap<usize, bool>) -> FileCoverage {
+    let covered = lines.values().filter(|hit| **hit).count();
+
+    FileCoverage { path, lines: lines.len(), covered }
+}
+
+fn marker(percentage: f64, threshold: f64) -> &'static str {
+    if percentage >= threshold {
+        "🟢"
+    } else if percentage >= threshold - 10.0 {
+        "🟡"
+    } else {
+        "🔴"
+    }
+}
+
+fn percentage(covered: usize, lines: usize) -> f64 {
+    if lines == 0 {
+        100.0
+    } else {
+        covered as f64 / lines as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_cobertura_merges_classes_and_ignores_method_duplicates() {
+        let xml = r#"<coverage><packages><package name="p"><classes>
+            <class name="A" filename="src/a.rs"><methods><method name="f"><lines>
+                <line number="1" hits="1"/></lines></method></methods>
+                <lines><line number="1" hits="1"/><line number="2" hits="0"/></lines></class>
+            <class name="B" filename="src/a.rs"><lines><line number="3" hits="2"/></lines></class>
+        </classes></package></packages></coverage>"#;
+
+        assert_eq!(
+            CoverageReport::from_cobertura(xml).files,
+            [FileCoverage { path: "src/a.rs".to_owned(), lines: 3, covered: 2 }]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Previews of Polars data frames, e.g. for data analysis reports.
+
+use crate::escape::{escape_text, EscapeMode};
+use crate::table::pipe_table;
+use polars::prelude::{AnyValue, DataFrame};
+
+/// How a data frame is rendered as a table.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DataFrameOptions {
+    /// The maximum number of rows rendered, if any. A truncated table ends with a row of
+    /// ellipses.
+    pub max_rows: Option<usize>,
+    /// The number of digits after the decimal point floats are rounded to, if any.
+    pub float_precision: Option<usize>,
+    /// The content of cells whose values are null.
+    pub null: String,
+}
+
+/// Render a data frame as a GFM pipe table with its column names as the headers. Column names
+/// and strings are escaped as plain text.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::dataframe::{table_from_dataframe, DataFrameOptions};
+/// use polars::prelude::{Column, DataFrame};
+///
+/// let frame = DataFrame::new_infer_height(vec![
+///     Column::new("city".into(), ["Oslo", "Lima", "Pune"]),
+///     Column::new("rain".into(), [Some(0.7631), None, Some(0.5)]),
+/// ])
+/// .unwrap();
+/// let options = DataFrameOptions {
+///     max_rows: Some(2),
+///     float_precision: Some(2),
+///     null: "—".to_owned(),
+/// };
+///
+/// assert_eq!(
+///     table_from_dataframe(&frame, &options),
+///     "| city | rain |\n| --- | --- |\n| Oslo | 0.76 |\n| Lima | — |\n| … | … |"
+/// );
+/// ```
+pub fn table_from_dataframe(frame: &DataFrame, options: &DataFrameOptions) -> String {
+    let columns = frame.columns();
+    let height = options.max_rows.map_or(frame.height(), |max_rows| max_rows.min(frame.height()));
+    let headers: Vec<String> = columns
+        .iter()
+        .map(|column| escape_text(column.name(), EscapeMode::default()).into_owned())
+        .collect();
+    let mut rows: Vec<Vec<String>> = (0..height)
+        .map(|index| {
+            columns
+                .iter()
+                .map(|column| match column.get(index) {
+                    Ok(value) => cell(&value, options),
+                    Err(_) => String::new(),
+                })
+                .collect()
+        })
+        .collect();
+
+    if height < frame.height() {
+        rows.push(vec!["…".to_owned(); columns.len()]);
+    }
+
+    pipe_table(&headers, &rows)
+}
+
+fn cell(value: &AnyValue, options: &DataFrameOptions) -> String {
+    match (value, options.float_precision) {
+        (AnyValue::Null, _) => options.null.clone(),
+        (AnyValue::Float32(float), Some(precision)) => format!("{float:.precision$}"),
+        (AnyValue::Float64(float), Some(precision)) => format!("{float:.precision$}"),
+        _ => value.get_str().map_or_else(
+            || value.to_string(),
+            |text| escape_text(text, EscapeMode::default()).into_owned(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::Column;
+
+    #[test]
+    fn null_cells_are_rendered_as_the_null_option() {
+        let frame = DataFrame::new_infer_height(vec![Column::new("a".into(), [None, Some(1)])])
+            .unwrap();
+        let options = DataFrameOptions { null: "n/a".to_owned(), ..DataFrameOptions::default() };
+
+        assert_eq!(table_from_dataframe(&frame, &options), "| a |\n| --- |\n| n/a |\n| 1 |");
+    }
+
+    #[test]
+    fn only_floats_are_rounded() {
+        let frame = DataFrame::new_infer_height(vec![
+            Column::new("int".into(), [12_i64, -3]),
+            Column::new("float".into(), [1.0_f64, 2.345]),
+        ])
+        .unwrap();
+        let options = DataFrameOptions { float_precision: Some(1), ..DataFrameOptions::default() };
+
+        assert_eq!(
+            table_from_dataframe(&frame, &options),
+            "| int | float |\n| --- | --- |\n| 12 | 1.0 |\n| -3 | 2.3 |"
+        );
+    }
+
+    #[test]
+    fn strings_and_column_names_are_escaped() {
+        let frame = DataFrame::new_infer_height(vec![Column::new(
+            "*name*".into(),
+            ["a|b", "[link](x)", "line\nbreak"],
+        )])
+        .unwrap();
+
+        assert_eq!(
+            table_from_dataframe(&frame, &DataFrameOptions::default()),
+            "| \\*name\\* |\n| --- |\n| a\\|b |\n| \\[link\\](x) |\n| line<br>break |"
+        );
+    }
+}
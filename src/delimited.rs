@@ -0,0 +1,204 @@
+//! Conversion of delimited text (CSV, TSV and friends) into tables.
+
+use crate::table::pipe_table;
+use std::io::{self, Read};
+
+/// How delimited text is split into fields.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::delimited::DelimitedOptions;
+///
+/// let options = DelimitedOptions { delimiter: '|', quote: None, ..DelimitedOptions::csv() };
+///
+/// assert_eq!(options.parse("a|b\n1|2"), [["a", "b"], ["1", "2"]]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DelimitedOptions {
+    /// The character separating fields.
+    pub delimiter: char,
+    /// The character fields may be quoted with, if any. Quoted fields may contain delimiters
+    /// and line feeds.
+    pub quote: Option<char>,
+    /// The character escaping a quote inside a quoted field. When `None` a quote is escaped by
+    /// doubling it, as in RFC 4180.
+    pub escape: Option<char>,
+    /// Whether the first record is the header row of the table.
+    pub has_header: bool,
+}
+
+impl Default for DelimitedOptions {
+    fn default() -> DelimitedOptions {
+        DelimitedOptions::csv()
+    }
+}
+
+impl DelimitedOptions {
+    /// Comma separated values with double quotes, per RFC 4180.
+    pub fn csv() -> DelimitedOptions {
+        DelimitedOptions {
+            delimiter: ',',
+            quote: Some('"'),
+            escape: None,
+            has_header: true,
+        }
+    }
+
+    /// Tab separated values, as exported by spreadsheets.
+    pub fn tsv() -> DelimitedOptions {
+        DelimitedOptions { delimiter: '\t', ..DelimitedOptions::csv() }
+    }
+
+    /// Semicolon separated values, as exported by spreadsheets in locales which use a decimal
+    /// comma.
+    pub fn semicolon() -> DelimitedOptions {
+        DelimitedOptions { delimiter: ';', ..DelimitedOptions::csv() }
+    }
+
+    /// Split delimited text into records of fields.
+    ///
+    /// Both LF and CRLF line endings are accepted and blank lines are skipped.
+    pub fn parse(&self, text: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = text.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            if quoted {
+                if Some(char) == self.escape && chars.peek().is_some() {
+                    field.extend(chars.next());
+                } else if Some(char) == self.quote {
+                    if self.escape.is_none() && chars.peek() == Some(&char) {
+                        field.push(char);
+                        chars.next();
+                    } else {
+                        quoted = false;
+                    }
+                } else {
+                    field.push(char);
+                }
+            } else if Some(char) == self.quote && field.is_empty() {
+                quoted = true;
+            } else if char == self.delimiter {
+                record.push(std::mem::take(&mut field));
+            } else if char == '\n' || char == '\r' {
+                if char == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+
+                if !record.is_empty() || !field.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+            } else {
+                field.push(char);
+            }
+        }
+
+        if !record.is_empty() || !field.is_empty() {
+            record.push(field);
+            records.push(record);
+        }
+
+        records
+    }
+}
+
+/// Convert delimited text into a GFM pipe table.
+///
+/// Pipes in fields are escaped and line feeds in quoted fields become `<br>` elements. Without a
+/// header row the table gets an empty one, since pipe tables require one.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::delimited::{table_from_delimited, DelimitedOptions};
+///
+/// let tsv = "Name\tScore\nAda\t10\n\"Grace \"\"Amazing\"\" Hopper\"\t9";
+///
+/// assert_eq!(
+///     table_from_delimited(tsv, &DelimitedOptions::tsv()),
+///     "| Name | Score |\n| --- | --- |\n| Ada | 10 |\n| Grace \"Amazing\" Hopper | 9 |"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+/// - <https://www.rfc-editor.org/rfc/rfc4180>
+pub fn table_from_delimited(text: &str, options: &DelimitedOptions) -> String {
+    let mut records = options.parse(text);
+    let headers = if options.has_header && !records.is_empty() {
+        records.remove(0)
+    } else {
+        Vec::new()
+    };
+
+    pipe_table(&headers, &records)
+}
+
+/// Read delimited text, e.g. a CSV file or the output of a command, and convert it into a GFM
+/// pipe table in the same manner as [`table_from_delimited`].
+///
+/// Errors
+/// ======
+/// Returns an error when reading fails or the text isn't valid UTF-8.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::delimited::{table_from_csv, DelimitedOptions};
+///
+/// let csv = "crate,version\nmd-writer,0.1.0\n";
+///
+/// assert_eq!(
+///     table_from_csv(csv.as_bytes(), &DelimitedOptions::csv()).unwrap(),
+///     "| crate | version |\n| --- | --- |\n| md-writer | 0.1.0 |"
+/// );
+/// ```
+pub fn table_from_csv(mut reader: impl Read, options: &DelimitedOptions) -> io::Result<String> {
+    let mut text = String::new();
+
+    reader.read_to_string(&mut text)?;
+
+    Ok(table_from_delimited(&text, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_handles_quoted_delimiters_and_line_feeds() {
+        let records = DelimitedOptions::semicolon().parse("a;\"b;c\"\r\n\"d\ne\";f\r\n");
+
+        assert_eq!(records, [["a", "b;c"], ["d\ne", "f"]]);
+    }
+
+    #[test]
+    fn parse_supports_escape_characters() {
+        let options = DelimitedOptions { escape: Some('\\'), ..DelimitedOptions::csv() };
+
+        assert_eq!(options.parse(r#""say \"hi\"",x"#), [[r#"say "hi""#, "x"]]);
+    }
+
+    #[test]
+    fn table_from_csv_rejects_invalid_utf8() {
+        let error = table_from_csv(&[0xff, b','][..], &DelimitedOptions::csv()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn table_from_delimited_without_header() {
+        let options = DelimitedOptions { has_header: false, ..DelimitedOptions::csv() };
+
+        assert_eq!(
+            table_from_delimited("1,2", &options),
+            "|  |  |\n| --- | --- |\n| 1 | 2 |"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Utilities for rendering errors as Markdown, e.g. for automatically filed issue bodies.
+
+use crate::labels::Labels;
+use crate::{details, fenced_code_block, LF};
+use std::backtrace::Backtrace;
+use std::error::Error;
+
+/// Symbol prefixes of backtrace frames belonging to the runtime, panic machinery or backtrace
+/// capturing itself rather than to the code under inspection. The C `main` shim is matched
+/// exactly rather than by prefix.
+const NOISY_FRAME_PREFIXES: [&str; 13] = [
+    "__libc_start",
+    "__rust_",
+    "_start",
+    "core::ops::function::",
+    "core::panicking::",
+    "rust_begin_unwind",
+    "std::backtrace::",
+    "std::backtrace_rs::",
+    "std::panic::",
+    "std::panicking::",
+    "std::rt::",
+    "std::sys::",
+    "std::sys_common::",
+];
+
+/// Render an error and its chain of sources as a nested list followed by its debug
+/// representation in a collapsed fenced code block.
+///
+/// Examples
+/// ========
+/// ```
+/// use std::io;
+///
+/// let source = io::Error::new(io::ErrorKind::NotFound, "config.toml not found");
+/// let error = io::Error::new(io::ErrorKind::Other, source);
+/// let markdown = md_writer::diagnostics::format_error(&error);
+///
+/// assert!(markdown.starts_with("- config.toml not found\n"));
+/// assert!(markdown.contains("<summary>Debug</summary>"));
+/// ```
+pub fn format_error(error: &dyn Error) -> String {
+    format_error_with_labels(error, &Labels::default())
+}
+
+/// Render an error like [`format_error`], taking the summary of its debug representation from
+/// the provided labels.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{diagnostics::format_error_with_labels, labels::Labels};
+/// use std::fmt;
+///
+/// let error = fmt::Error;
+/// let labels = Labels { debug: "Débogage".into(), ..Labels::default() };
+///
+/// assert!(format_error_with_labels(&error, &labels).contains("<summary>Débogage</summary>"));
+/// ```
+pub fn format_error_with_labels(error: &dyn Error, labels: &Labels) -> String {
+    let chain = error_chain(error);
+    let debug = fenced_code_block(&format!("{error:#?}"), Some("text"));
+
+    [chain, collapsed(&labels.debug, &debug)].join(&LF.to_string().repeat(2))
+}
+
+/// Render an [`anyhow::Error`] and its chain of sources in the same manner as [`format_error`].
+///
+/// The debug representation of an [`anyhow::Error`] includes its backtrace when one was
+/// captured.
+///
+/// Examples
+/// ========
+/// ```
+/// use anyhow::Context;
+///
+/// let error = Err::<(), _>(anyhow::anyhow!("connection refused"))
+///     .context("failed to fetch release")
+///     .unwrap_err();
+/// let markdown = md_writer::diagnostics::format_anyhow_error(&error);
+///
+/// assert!(markdown.starts_with("- failed to fetch release\n  - connection refused\n"));
+/// ```
+#[cfg(feature = "anyhow")]
+pub fn format_anyhow_error(error: &anyhow::Error) -> String {
+    format_anyhow_error_with_labels(error, &Labels::default())
+}
+
+/// Render an [`anyhow::Error`] like [`format_anyhow_error`], taking the summary of its debug
+/// representation from the provided labels.
+#[cfg(feature = "anyhow")]
+pub fn format_anyhow_error_with_labels(error: &anyhow::Error, labels: &Labels) -> String {
+    let chain = error_chain(error.as_ref());
+    let debug = fenced_code_block(&format!("{error:?}"), Some("text"));
+
+    [chain, collapsed(&labels.debug, &debug)].join(&LF.to_string().repeat(2))
+}
+
+/// Render a backtrace inside a collapsed `text` fenced code block with frames belonging to the
+/// runtime, panic machinery and backtrace capturing trimmed away.
+///
+/// Examples
+/// ========
+/// ```
+/// use std::backtrace::Backtrace;
+///
+/// let backtrace = Backtrace::force_capture();
+/// let markdown = md_writer::diagnostics::backtrace_block(&backtrace);
+///
+/// assert!(markdown.starts_with("<details>\n<summary>Backtrace</summary>\n\n```text\n"));
+/// assert!(!markdown.contains("std::backtrace::Backtrace::force_capture"));
+/// ```
+pub fn backtrace_block(backtrace: &Backtrace) -> String {
+    backtrace_block_with_labels(backtrace, &Labels::default())
+}
+
+/// Render a backtrace like [`backtrace_block`], taking the summary from the provided labels.
+pub fn backtrace_block_with_labels(backtrace: &Backtrace, labels: &Labels) -> String {
+    let frames = trim_backtrace(&backtrace.to_string());
+
+    collapsed(&labels.backtrace, &fenced_code_block(&frames, Some("text")))
+}
+
+/// Remove noisy frames from the display representation of a backtrace.
+///
+/// Each frame begins with a line of the form `  7: symbol` which is followed by any number of
+/// `at file:line:column` lines; a frame is dropped along with its location lines.
+fn trim_backtrace(backtrace: &str) -> String {
+    let mut lines = Vec::new();
+    let mut keep = true;
+
+    for line in backtrace.lines() {
+        let frame = line
+            .trim_start()
+            .split_once(": ")
+            .filter(|(index, _)| index.chars().all(|char| char.is_ascii_digit()));
+
+        if let Some((_, symbol)) = frame {
+            keep = symbol != "main"
+                && !NOISY_FRAME_PREFIXES
+                    .iter()
+                    .any(|prefix| symbol.starts_with(prefix));
+        }
+
+        if keep {
+            lines.push(line);
+        }
+    }
+
+    lines.join(&LF.to_string())
+}
+
+fn error_chain(error: &dyn Error) -> String {
+    let mut items = Vec::new();
+    let mut current = Some(error);
+    let mut depth = 0;
+
+    while let Some(error) = current {
+        let indent = "  ".repeat(depth);
+        let message = error
+            .to_string()
+            .lines()
+            .collect::<Vec<&str>>()
+            .join(&format!("{LF}{indent}  "));
+
+        items.push(format!("{indent}- {message}"));
+        current = error.source();
+        depth += 1;
+    }
+
+    items.join(&LF.to_string())
+}
+
+/// Wrap a body in a collapsed HTML `<details>` element with the provided summary.
+pub(crate) fn collapsed(summary: &str, body: &str) -> String {
+    details(summary, body, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Wrapped(&'static str, Option<Box<Wrapped>>);
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl Error for Wrapped {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.1.as_deref().map(|source| source as &(dyn Error + 'static))
+        }
+    }
+
+    #[test]
+    fn trim_backtrace_drops_noisy_frames_and_their_locations() {
+        let backtrace = "   0: std::backtrace::Backtrace::force_capture\n\
+                         \x20            at /rustc/library/std/src/backtrace.rs:312:13\n\
+                         \x20  1: app::run\n\
+                         \x20            at ./src/main.rs:10:5\n\
+                         \x20  2: std::rt::lang_start\n\
+                         \x20            at /rustc/library/std/src/rt.rs:165:17\n\
+                         \x20  3: main";
+
+        assert_eq!(
+            trim_backtrace(backtrace),
+            "   1: app::run\n             at ./src/main.rs:10:5"
+        );
+    }
+
+    #[test]
+    fn error_chain_is_a_nested_list() {
+        let inner = Wrapped("inner", None);
+        let middle = Wrapped("middle", Some(Box::new(inner)));
+        let error = Wrapped("outer", Some(Box::new(middle)));
+
+        assert_eq!(error_chain(&error), "- outer\n  - middle\n    - inner");
+    }
+
+    #[test]
+    fn backtrace_summary_comes_from_the_labels() {
+        let labels = Labels { backtrace: "Rückverfolgung".into(), ..Labels::default() };
+
+        assert_eq!(
+            backtrace_block_with_labels(&Backtrace::disabled(), &labels),
+            "<details>\n<summary>Rückverfolgung</summary>\n\n\
+             ```text\ndisabled backtrace\n```\n\n</details>"
+        );
+    }
+
+    #[test]
+    fn format_error_collapses_the_debug_representation() {
+        let error = Wrapped("outer", None);
+
+        assert_eq!(
+            format_error(&error),
+            "- outer\n\n<details>\n<summary>Debug</summary>\n\n\
+             ```text\nWrapped(\n    \"outer\",\n    None,\n)\n```\n\n</details>"
+        );
+    }
+}
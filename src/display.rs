@@ -0,0 +1,138 @@
+//! Elements which render on demand through [`Display`], e.g. when embedded in `format!` or
+//! `write!`.
+//!
+//! Each type renders the same Markdown as its namesake in the crate root (e.g. [`H1`] and
+//! [`crate::h1`]) straight into the formatter, without an intermediate `String`, so elements
+//! can be built up front and rendered only if and when they're needed.
+//!
+//! Examples
+//! ========
+//! ```
+//! use md_writer::display::{CodeSpan, FencedCodeBlock, H3};
+//!
+//! let command = "cargo add md-writer";
+//! let usage = FencedCodeBlock { code: "use md_writer::h1;", info_string: Some("rust") };
+//! let section = format!("{}\n\nRun {}, then:\n\n{usage}", H3("Install"), CodeSpan(command));
+//!
+//! assert_eq!(
+//!     section,
+//!     "### Install\n\nRun `cargo add md-writer`, then:\n\n```rust\nuse md_writer::h1;\n```"
+//! );
+//! ```
+
+use crate::write::{
+    write_blockquote, write_bold, write_bold_italic, write_code_span, write_fenced_code_block,
+    write_h1, write_h2, write_h3, write_h4, write_h5, write_h6, write_image, write_italic,
+    write_link, write_paragraph, write_strikethrough,
+};
+use std::fmt::{self, Display};
+
+macro_rules! text_element {
+    ($($(#[$doc:meta])* $name:ident => $write:ident,)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            pub struct $name<'a>(pub &'a str);
+
+            impl Display for $name<'_> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    $write(f, self.0)
+                }
+            }
+        )*
+    };
+}
+
+text_element!(
+    /// A blockquote, see [`crate::blockquote`].
+    Blockquote => write_blockquote,
+    /// Text in strong emphasis, see [`crate::bold`].
+    Bold => write_bold,
+    /// Text in both emphasis and strong emphasis, see [`crate::bold_italic`].
+    BoldItalic => write_bold_italic,
+    /// A code span, see [`crate::code_span`].
+    CodeSpan => write_code_span,
+    /// A level 1 setext header, see [`crate::h1`].
+    H1 => write_h1,
+    /// A level 2 setext header, see [`crate::h2`].
+    H2 => write_h2,
+    /// A level 3 ATX header, see [`crate::h3`].
+    H3 => write_h3,
+    /// A level 4 ATX header, see [`crate::h4`].
+    H4 => write_h4,
+    /// A level 5 ATX header, see [`crate::h5`].
+    H5 => write_h5,
+    /// A level 6 ATX header, see [`crate::h6`].
+    H6 => write_h6,
+    /// Text in emphasis, see [`crate::italic`].
+    Italic => write_italic,
+    /// A paragraph, see [`crate::paragraph`].
+    Paragraph => write_paragraph,
+    /// Struck through text, see [`crate::strikethrough`].
+    Strikethrough => write_strikethrough,
+);
+
+/// A fenced code block, see [`crate::fenced_code_block`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FencedCodeBlock<'a> {
+    /// The code.
+    pub code: &'a str,
+    /// The info string, typically the language of the code.
+    pub info_string: Option<&'a str>,
+}
+
+impl Display for FencedCodeBlock<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_fenced_code_block(f, self.code, self.info_string)
+    }
+}
+
+/// An image, see [`crate::image()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Image<'a> {
+    /// The alternative text.
+    pub alt: &'a str,
+    /// The URL of the image.
+    pub source: &'a str,
+    /// The title, typically shown as a tooltip.
+    pub title: Option<&'a str>,
+}
+
+impl Display for Image<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_image(f, self.alt, self.source, self.title)
+    }
+}
+
+/// A link, see [`crate::link`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Link<'a> {
+    /// The text of the link.
+    pub text: &'a str,
+    /// The URL the link points to.
+    pub destination: &'a str,
+    /// The title, typically shown as a tooltip.
+    pub title: Option<&'a str>,
+}
+
+impl Display for Link<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_link(f, self.text, self.destination, self.title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elements_render_as_their_string_functions() {
+        assert_eq!(H1("Title").to_string(), crate::h1("Title"));
+        assert_eq!(Blockquote("a\n\nb").to_string(), crate::blockquote("a\n\nb"));
+        assert_eq!(CodeSpan("a`b").to_string(), crate::code_span("a`b"));
+        assert_eq!(
+            Link { text: "docs", destination: "a b", title: Some("Docs") }.to_string(),
+            crate::link("docs", "a b", Some("Docs"))
+        );
+    }
+}
@@ -0,0 +1,1132 @@
+//! A model of Markdown documents, which can be rendered to Markdown or converted to other
+//! formats.
+
+use crate::escape::{escape_closing_sequence, one_line, EscapeMode};
+use crate::list::{clamp_start, list_item, MAX_ORDERED_NUMBER};
+use crate::options::RenderOptions;
+use crate::table::pipe_table;
+use crate::toc::toc;
+use crate::url::{is_relative, rebase};
+use crate::{blockquote, code_span, image, link, thematic_break, LF};
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// An inline element of a document, e.g. text or a link.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Inline {
+    /// Plain text, which is escaped when rendered.
+    Text(String),
+    /// A code span.
+    Code(String),
+    /// Emphasized content, typically rendered in italics.
+    Emphasis(Vec<Inline>),
+    /// Strongly emphasized content, typically rendered in bold.
+    Strong(Vec<Inline>),
+    /// Struck through content.
+    Strikethrough(Vec<Inline>),
+    /// A link.
+    Link {
+        /// The text of the link.
+        text: Vec<Inline>,
+        /// The URL the link points to.
+        destination: String,
+        /// The title of the link, typically shown as a tooltip.
+        title: Option<String>,
+    },
+    /// An image.
+    Image {
+        /// The alternative text of the image.
+        alt: String,
+        /// The URL of the image.
+        source: String,
+        /// The title of the image, typically shown as a tooltip.
+        title: Option<String>,
+    },
+    /// Raw inline HTML, which is rendered verbatim.
+    Html(String),
+    /// A hard line break.
+    LineBreak,
+}
+
+impl Inline {
+    /// Create plain text.
+    pub fn text(text: impl Into<String>) -> Inline {
+        Inline::Text(text.into())
+    }
+
+    /// Render the element as Markdown.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        match self {
+            Inline::Text(text) => options.escape(text).into_owned(),
+            Inline::Code(code) => code_span(code),
+            Inline::Emphasis(content) => options.emphasis.italic(&render_inlines(content, options)),
+            Inline::Strong(content) => options.emphasis.bold(&render_inlines(content, options)),
+            Inline::Strikethrough(content) if !options.flavor.supports_strikethrough() => {
+                format!("<del>{}</del>", render_inlines(content, options))
+            }
+            Inline::Strikethrough(content) => format!("~~{}~~", render_inlines(content, options)),
+            Inline::Link { text, destination, title } => {
+                link(&render_inlines(text, options), destination, title.as_deref())
+            }
+            Inline::Image { alt, source, title } => image(alt, source, title.as_deref()),
+            Inline::Html(html) if options.strip_html => strip_tags(html),
+            Inline::Html(html) => html.clone(),
+            Inline::LineBreak => options.hard_break.render().to_owned(),
+        }
+    }
+}
+
+/// A block of a document, e.g. a heading or a paragraph.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Block {
+    /// A heading of level 1 through 6.
+    Heading {
+        /// The level of the heading, clamped to 1 through 6 when rendered.
+        level: u8,
+        /// The content of the heading.
+        content: Vec<Inline>,
+    },
+    /// A paragraph.
+    Paragraph(Vec<Inline>),
+    /// A fenced code block.
+    CodeBlock {
+        /// The info string of the code block, typically its language.
+        info: Option<String>,
+        /// The code.
+        code: String,
+    },
+    /// A blockquote of other blocks.
+    BlockQuote(Vec<Block>),
+    /// A bullet list, or an ordered list when it has a start number.
+    List {
+        /// The number of the first item of an ordered list, lowered when rendered so no item is
+        /// numbered above [`MAX_ORDERED_NUMBER`].
+        start: Option<u64>,
+        /// The items of the list, each made up of blocks.
+        items: Vec<Vec<Block>>,
+    },
+    /// A table.
+    Table {
+        /// The content of the header cells.
+        headers: Vec<Vec<Inline>>,
+        /// The content of the cells of each row.
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    /// A thematic break, typically rendered as a horizontal rule.
+    ThematicBreak,
+    /// A raw HTML block, which is rendered verbatim.
+    Html(String),
+    /// A block which is only produced when the document is rendered.
+    Lazy(LazyBlock),
+}
+
+impl Block {
+    /// Create a heading of the provided level containing plain text.
+    pub fn heading(level: u8, text: impl Into<String>) -> Block {
+        Block::Heading { level, content: vec![Inline::text(text)] }
+    }
+
+    /// Create a paragraph containing plain text.
+    pub fn paragraph(text: impl Into<String>) -> Block {
+        Block::Paragraph(vec![Inline::text(text)])
+    }
+
+    /// Render the block as Markdown.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        match self {
+            Block::Heading { level, content } => {
+                // As `escape_heading` does, the text is kept on one line and a trailing run of
+                // `#` is escaped so it isn't taken for a closing sequence.
+                let text = one_line(&render_inlines(content, options));
+                let text = match options.escape_mode {
+                    EscapeMode::Off => text,
+                    _ => escape_closing_sequence(text),
+                };
+                let level = (*level).clamp(1, 6);
+
+                options.heading.heading(level, &options.isolate(&text)).unwrap_or_default()
+            }
+            Block::Paragraph(content) => render_inlines(content, options),
+            Block::CodeBlock { info, code } => {
+                options.fence.fenced_code_block(code, info.as_deref())
+            }
+            Block::BlockQuote(blocks) => blockquote(&render_blocks(blocks, options)),
+            Block::List { start, items } => {
+                let tight = items.iter().all(|item| item.len() <= 1);
+                let separator = if tight { LF.to_string() } else { LF.to_string().repeat(2) };
+                let start = start.map(|start| clamp_start(start, items.len()));
+
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        let marker = match start {
+                            Some(start) => format!(
+                                "{}. ",
+                                start.checked_add(index as u64).unwrap_or(MAX_ORDERED_NUMBER)
+                            ),
+                            None => options.bullet.marker().to_owned(),
+                        };
+
+                        list_item(&marker, &render_blocks(item, options), None)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(&separator)
+            }
+            Block::Table { headers, rows } => {
+                let cells = |cells: &[Vec<Inline>]| -> Vec<String> {
+                    cells
+                        .iter()
+                        .map(|cell| options.isolate(&render_inlines(cell, options)).into_owned())
+                        .collect()
+                };
+                let rows: Vec<Vec<String>> = rows.iter().map(|row| cells(row)).collect();
+
+                if options.flavor.supports_tables() {
+                    pipe_table(&cells(headers), &rows)
+                } else {
+                    html_table(&cells(headers), &rows)
+                }
+            }
+            Block::ThematicBreak => thematic_break(),
+            Block::Html(html) if options.strip_html => {
+                // Dropped tags leave blank lines behind, which are collapsed.
+                let mut lines: Vec<&str> = Vec::new();
+                let stripped = strip_tags(html);
+
+                for line in stripped.lines().map(str::trim_end) {
+                    if !line.is_empty() || lines.last().is_some_and(|last| !last.is_empty()) {
+                        lines.push(line);
+                    }
+                }
+
+                lines.join(&LF.to_string()).trim_end().to_owned()
+            }
+            Block::Html(html) => html.trim_end().to_owned(),
+            Block::Lazy(lazy) => lazy.evaluate(options).render(options),
+        }
+    }
+
+    /// Call `visit` on the block and then on each of its nested blocks. Lazy blocks aren't
+    /// evaluated.
+    pub(crate) fn visit_blocks_mut(&mut self, visit: &mut impl FnMut(&mut Block)) {
+        visit(self);
+
+        match self {
+            Block::BlockQuote(blocks) => {
+                blocks.iter_mut().for_each(|block| block.visit_blocks_mut(visit));
+            }
+            Block::List { items, .. } => items
+                .iter_mut()
+                .flatten()
+                .for_each(|block| block.visit_blocks_mut(visit)),
+            _ => {}
+        }
+    }
+
+    /// Call `visit` on every inline of the block and its nested blocks, including those nested
+    /// in other inlines.
+    pub(crate) fn visit_inlines_mut(&mut self, visit: &mut impl FnMut(&mut Inline)) {
+        self.visit_blocks_mut(&mut |block| match block {
+            Block::Heading { content, .. } | Block::Paragraph(content) => {
+                visit_inlines_mut(content, visit);
+            }
+            Block::Table { headers, rows } => headers
+                .iter_mut()
+                .chain(rows.iter_mut().flatten())
+                .for_each(|cell| visit_inlines_mut(cell, visit)),
+            _ => {}
+        });
+    }
+}
+
+/// A block which is only produced when the document is rendered, e.g. because it's expensive
+/// to compute or depends on the flavor rendered.
+///
+/// Lazy blocks are evaluated each time they're rendered. Clones share the same closure, and
+/// lazy blocks are only equal to their clones.
+#[derive(Clone)]
+pub struct LazyBlock(Arc<dyn Fn(&RenderOptions) -> Block + Send + Sync>);
+
+impl LazyBlock {
+    /// Create a lazy block from a closure producing the block for the options being rendered.
+    pub fn new(block: impl Fn(&RenderOptions) -> Block + Send + Sync + 'static) -> LazyBlock {
+        LazyBlock(Arc::new(block))
+    }
+
+    /// Produce the block for the provided options.
+    pub fn evaluate(&self, options: &RenderOptions) -> Block {
+        (self.0)(options)
+    }
+}
+
+impl fmt::Debug for LazyBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyBlock").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for LazyBlock {
+    fn eq(&self, other: &LazyBlock) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LazyBlock {}
+
+impl Hash for LazyBlock {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+/// How the headings of a document are numbered.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HeadingNumbering {
+    /// Headings aren't numbered.
+    #[default]
+    Off,
+    /// Headings of the provided level and deeper are prefixed with hierarchical numbers, e.g.
+    /// `1`, `1.1` and `1.2.3`. Shallower headings, e.g. the title, aren't numbered and restart
+    /// the numbering, and skipped levels are numbered `0`.
+    FromLevel(u8),
+}
+
+/// A Markdown document made up of blocks.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::document::{Block, Document, Inline};
+///
+/// let mut document = Document::new();
+///
+/// document.push(Block::heading(3, "Usage"));
+/// document.push(Block::Paragraph(vec![
+///     Inline::text("Run "),
+///     Inline::Code("cargo test".to_owned()),
+///     Inline::text("."),
+/// ]));
+///
+/// assert_eq!(document.to_string(), "### Usage\n\nRun `cargo test`.");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Document {
+    blocks: Vec<Block>,
+    numbering: HeadingNumbering,
+}
+
+impl Document {
+    /// Create an empty document.
+    pub fn new() -> Document {
+        Document::default()
+    }
+
+    /// Append a block to the document.
+    pub fn push(&mut self, block: Block) {
+        self.blocks.push(block);
+    }
+
+    /// Insert a block at the provided index, shifting the blocks after it.
+    ///
+    /// Panics
+    /// ======
+    /// Panics when the index is greater than the number of blocks.
+    pub fn insert(&mut self, index: usize, block: Block) {
+        self.blocks.insert(index, block);
+    }
+
+    /// Number the headings of the document. The numbers are computed whenever the document is
+    /// rendered, so they stay correct as sections are inserted, and
+    /// [`toc`](Document::toc) links to the numbered headings.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::document::{Block, Document, HeadingNumbering};
+    ///
+    /// let mut document = Document::new()
+    ///     .h1("Spec")
+    ///     .h2("Scope")
+    ///     .h2("Requirements")
+    ///     .h3("Performance")
+    ///     .with_heading_numbering(HeadingNumbering::FromLevel(2));
+    ///
+    /// document.insert(2, Block::heading(2, "Terms"));
+    ///
+    /// assert_eq!(
+    ///     document.to_string(),
+    ///     "Spec\n====\n\n1 Scope\n-------\n\n2 Terms\n-------\n\n\
+    ///      3 Requirements\n--------------\n\n### 3.1 Performance"
+    /// );
+    /// assert!(document.toc(6).ends_with("- [3.1 Performance](#31-performance)"));
+    /// ```
+    pub fn with_heading_numbering(self, numbering: HeadingNumbering) -> Document {
+        Document { numbering, ..self }
+    }
+
+    /// Append a block to the document, for chaining.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::document::{Block, Document};
+    /// use md_writer::options::RenderOptions;
+    ///
+    /// let document = Document::new()
+    ///     .h1("md-writer")
+    ///     .paragraph("Write *Markdown* from Rust.")
+    ///     .h3("Usage")
+    ///     .fenced_code_block("cargo add md-writer", Some("sh"))
+    ///     .table(&["Feature", "Default"], &[vec!["strict", "no"]])
+    ///     .block(Block::ThematicBreak);
+    ///
+    /// assert_eq!(
+    ///     document.render(&RenderOptions::github()),
+    ///     "md-writer\n=========\n\n\
+    ///      Write \\*Markdown\\* from Rust.\n\n### Usage\n\n\
+    ///      ```sh\ncargo add md-writer\n```\n\n\
+    ///      | Feature | Default |\n| --- | --- |\n| strict | no |\n\n\
+    ///      ---"
+    /// );
+    /// ```
+    pub fn block(mut self, block: Block) -> Document {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Append a heading of the provided level containing plain text.
+    pub fn heading(self, level: u8, text: impl Into<String>) -> Document {
+        self.block(Block::heading(level, text))
+    }
+
+    /// Append a level 1 heading containing plain text.
+    pub fn h1(self, text: impl Into<String>) -> Document {
+        self.heading(1, text)
+    }
+
+    /// Append a level 2 heading containing plain text.
+    pub fn h2(self, text: impl Into<String>) -> Document {
+        self.heading(2, text)
+    }
+
+    /// Append a level 3 heading containing plain text.
+    pub fn h3(self, text: impl Into<String>) -> Document {
+        self.heading(3, text)
+    }
+
+    /// Append a level 4 heading containing plain text.
+    pub fn h4(self, text: impl Into<String>) -> Document {
+        self.heading(4, text)
+    }
+
+    /// Append a level 5 heading containing plain text.
+    pub fn h5(self, text: impl Into<String>) -> Document {
+        self.heading(5, text)
+    }
+
+    /// Append a level 6 heading containing plain text.
+    pub fn h6(self, text: impl Into<String>) -> Document {
+        self.heading(6, text)
+    }
+
+    /// Append a paragraph containing plain text.
+    pub fn paragraph(self, text: impl Into<String>) -> Document {
+        self.block(Block::paragraph(text))
+    }
+
+    /// Append a fenced code block.
+    pub fn fenced_code_block(self, code: impl Into<String>, info: Option<&str>) -> Document {
+        self.block(Block::CodeBlock { info: info.map(str::to_owned), code: code.into() })
+    }
+
+    /// Append a table of plain text cells. Tables are rendered as raw HTML for flavors without
+    /// tables, see [`Flavor::supports_tables`](crate::flavor::Flavor::supports_tables).
+    pub fn table<S: AsRef<str>>(self, headers: &[S], rows: &[Vec<S>]) -> Document {
+        let cells = |cells: &[S]| -> Vec<Vec<Inline>> {
+            cells.iter().map(|cell| vec![Inline::text(cell.as_ref())]).collect()
+        };
+
+        self.block(Block::Table {
+            headers: cells(headers),
+            rows: rows.iter().map(|row| cells(row)).collect(),
+        })
+    }
+
+    /// Append a block which is only produced when the document is rendered, e.g. a section
+    /// which is expensive to compute or differs per flavor. The closure is called each time the
+    /// document is rendered; an empty paragraph is skipped.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::document::{Block, Document};
+    /// use md_writer::flavor::Flavor;
+    /// use md_writer::options::RenderOptions;
+    ///
+    /// let mut document = Document::new();
+    ///
+    /// document.push(Block::paragraph("Intro"));
+    /// document.push_lazy(|options| match options.flavor {
+    ///     Flavor::Gfm => Block::paragraph("> [!NOTE]"),
+    ///     _ => Block::Paragraph(Vec::new()),
+    /// });
+    ///
+    /// let gfm = RenderOptions { flavor: Flavor::Gfm, ..RenderOptions::default() };
+    ///
+    /// assert_eq!(document.to_string(), "Intro");
+    /// assert!(document.render(&gfm).starts_with("Intro\n\n"));
+    /// ```
+    pub fn push_lazy(
+        &mut self,
+        block: impl Fn(&RenderOptions) -> Block + Send + Sync + 'static,
+    ) {
+        self.blocks.push(Block::Lazy(LazyBlock::new(block)));
+    }
+
+    /// The blocks of the document.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Create a table of contents of the document's headings, see [`crate::toc::toc`].
+    /// Headings produced by lazy blocks aren't listed.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::document::Document;
+    ///
+    /// let document = Document::new().h1("Guide").h2("Getting Started").h3("Features");
+    ///
+    /// assert_eq!(document.toc(2), "- [Guide](#guide)\n  - [Getting Started](#getting-started)");
+    /// ```
+    pub fn toc(&self, max_depth: u8) -> String {
+        let headings: Vec<(u8, String)> = self
+            .numbered_blocks()
+            .iter()
+            .filter_map(|block| match block {
+                Block::Heading { level, content } => {
+                    Some(((*level).clamp(1, 6), plain_text(content)))
+                }
+                _ => None,
+            })
+            .collect();
+        let headings: Vec<(u8, &str)> =
+            headings.iter().map(|(level, text)| (*level, text.as_str())).collect();
+
+        toc(&headings, max_depth)
+    }
+
+    /// Render the document as Markdown, separating its blocks with blank lines.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        options.line_ending.apply(&render_blocks(&self.numbered_blocks(), options)).into_owned()
+    }
+
+    /// The blocks with their headings numbered per the document's heading numbering.
+    fn numbered_blocks(&self) -> Cow<'_, [Block]> {
+        let HeadingNumbering::FromLevel(top) = self.numbering else {
+            return Cow::Borrowed(&self.blocks);
+        };
+        let top = top.clamp(1, 6);
+        let mut counters: Vec<usize> = Vec::new();
+
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                Block::Heading { level, content } if (*level).clamp(1, 6) >= top => {
+                    let depth = usize::from((*level).clamp(1, 6) - top);
+
+                    counters.resize(depth + 1, 0);
+                    counters[depth] += 1;
+
+                    let number: Vec<String> = counters.iter().map(ToString::to_string).collect();
+                    let mut content = content.clone();
+
+                    content.insert(0, Inline::text(format!("{} ", number.join("."))));
+                    Block::Heading { level: *level, content }
+                }
+                Block::Heading { .. } => {
+                    counters.clear();
+                    block.clone()
+                }
+                _ => block.clone(),
+            })
+            .collect()
+    }
+
+    /// Rewrite relative link destinations against `link_base` and relative image sources
+    /// against `image_base`, so the document renders correctly off-site, e.g. as a README on
+    /// crates.io or npm. The `href` and `src` attributes of raw HTML are rewritten as well.
+    ///
+    /// Destinations with a scheme (e.g. `https:` or `mailto:`), protocol-relative URLs and
+    /// `#fragment` links are left as is. Paths beginning with `/` are resolved against the root
+    /// of the base. Lazy blocks are rewritten once evaluated.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::document::{Block, Document, Inline};
+    ///
+    /// let mut document = Document::from(vec![Block::Paragraph(vec![
+    ///     Inline::Image { alt: "Logo".to_owned(), source: "./logo.png".to_owned(), title: None },
+    ///     Inline::text(" See the "),
+    ///     Inline::Link {
+    ///         text: vec![Inline::text("guide")],
+    ///         destination: "docs/guide.md".to_owned(),
+    ///         title: None,
+    ///     },
+    ///     Inline::text("."),
+    /// ])]);
+    ///
+    /// document.rebase_urls(
+    ///     "https://github.com/owner/repo/blob/main",
+    ///     "https://raw.githubusercontent.com/owner/repo/main",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     document.to_string(),
+    ///     "![Logo](https://raw.githubusercontent.com/owner/repo/main/logo.png) See the \
+    ///      [guide](https://github.com/owner/repo/blob/main/docs/guide.md)."
+    /// );
+    /// ```
+    pub fn rebase_urls(&mut self, link_base: &str, image_base: &str) {
+        for block in &mut self.blocks {
+            rebase_block(block, link_base, image_base);
+        }
+    }
+}
+
+/// Build a [`Document`] from a compact description of its blocks.
+///
+/// Each block is an element name followed by its parts in parentheses:
+///
+/// - `h1(..)` through `h6(..)` and `p(..)` contain text made up of string literals, variables
+///   and parenthesized expressions, e.g. `p("Built at " (timestamp))`.
+/// - `code_block(lang: code)` or `code_block(code)` contain a code expression, optionally
+///   preceded by its language, e.g. `code_block(rust: source)`.
+/// - `quote(..)` contains other blocks.
+/// - `hr()` is a thematic break.
+///
+/// Text is rendered as [`Inline::Text`], so interpolated values are escaped per the render
+/// options, and values may be anything which implements [`Display`](fmt::Display).
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::md;
+///
+/// let name = "md-writer";
+/// let downloads = 1_024;
+/// let source = "fn main() {}";
+/// let document = md! {
+///     h3("About " name)
+///     p("Downloaded " (downloads * 2) " times, *so far*.")
+///     code_block(rust: source)
+///     quote(p("Quoted"))
+///     hr()
+/// };
+///
+/// assert_eq!(
+///     document.to_string(),
+///     "### About md-writer\n\nDownloaded 2048 times, \\*so far\\*.\n\n\
+///      ```rust\nfn main() {}\n```\n\n> Quoted\n\n---"
+/// );
+/// ```
+#[macro_export]
+macro_rules! md {
+    (@block hr ()) => {
+        $crate::document::Block::ThematicBreak
+    };
+    (@block p ($($part:tt)*)) => {
+        $crate::document::Block::Paragraph(::std::vec![$($crate::md!(@inline $part)),*])
+    };
+    (@block code_block ($info:ident : $code:expr)) => {
+        $crate::md!(@code ::std::stringify!($info), $code)
+    };
+    (@block code_block ($info:literal : $code:expr)) => {
+        $crate::md!(@code $info, $code)
+    };
+    (@block code_block ($code:expr)) => {
+        $crate::document::Block::CodeBlock {
+            info: ::std::option::Option::None,
+            code: ::std::string::ToString::to_string(&$code),
+        }
+    };
+    (@block quote ($($element:ident $parts:tt)*)) => {
+        $crate::document::Block::BlockQuote(::std::vec![$($crate::md!(@block $element $parts)),*])
+    };
+    (@block h1 $parts:tt) => { $crate::md!(@heading 1, $parts) };
+    (@block h2 $parts:tt) => { $crate::md!(@heading 2, $parts) };
+    (@block h3 $parts:tt) => { $crate::md!(@heading 3, $parts) };
+    (@block h4 $parts:tt) => { $crate::md!(@heading 4, $parts) };
+    (@block h5 $parts:tt) => { $crate::md!(@heading 5, $parts) };
+    (@block h6 $parts:tt) => { $crate::md!(@heading 6, $parts) };
+    (@heading $level:literal, ($($part:tt)*)) => {
+        $crate::document::Block::Heading {
+            level: $level,
+            content: ::std::vec![$($crate::md!(@inline $part)),*],
+        }
+    };
+    (@code $info:expr, $code:expr) => {
+        $crate::document::Block::CodeBlock {
+            info: ::std::option::Option::Some(::std::string::ToString::to_string($info)),
+            code: ::std::string::ToString::to_string(&$code),
+        }
+    };
+    (@inline $part:tt) => {
+        $crate::document::Inline::Text(::std::string::ToString::to_string(&$part))
+    };
+    ($($element:ident $parts:tt)*) => {
+        $crate::document::Document::from(::std::vec![$($crate::md!(@block $element $parts)),*])
+    };
+}
+
+impl From<Vec<Block>> for Document {
+    fn from(blocks: Vec<Block>) -> Document {
+        Document { blocks, ..Document::default() }
+    }
+}
+
+impl FromIterator<Block> for Document {
+    fn from_iter<I: IntoIterator<Item = Block>>(blocks: I) -> Document {
+        Document { blocks: blocks.into_iter().collect(), ..Document::default() }
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(&RenderOptions::default()))
+    }
+}
+
+/// Render a table as raw HTML for flavors without tables. The cells are separated from their
+/// tags by blank lines so their content is still parsed as Markdown.
+fn html_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let row = |tag: &str, cells: &[String]| -> String {
+        let cells: String = cells
+            .iter()
+            .map(|cell| {
+                if cell.is_empty() {
+                    format!("<{tag}></{tag}>{LF}")
+                } else {
+                    format!("<{tag}>{LF}{LF}{cell}{LF}{LF}</{tag}>{LF}")
+                }
+            })
+            .collect();
+
+        format!("<tr>{LF}{cells}</tr>{LF}")
+    };
+    let body: String = rows.iter().map(|cells| row("td", cells)).collect();
+
+    format!(
+        "<table>{LF}<thead>{LF}{}</thead>{LF}<tbody>{LF}{body}</tbody>{LF}</table>",
+        row("th", headers)
+    )
+}
+
+fn render_blocks(blocks: &[Block], options: &RenderOptions) -> String {
+    blocks
+        .iter()
+        .map(|block| block.render(options))
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<String>>()
+        .join(&LF.to_string().repeat(2))
+}
+
+fn render_inlines(inlines: &[Inline], options: &RenderOptions) -> String {
+    inlines.iter().map(|inline| inline.render(options)).collect()
+}
+
+/// The text of inlines without their formatting, as shown when rendered.
+fn plain_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) | Inline::Code(text) => text.clone(),
+            Inline::Emphasis(content)
+            | Inline::Strong(content)
+            | Inline::Strikethrough(content)
+            | Inline::Link { text: content, .. } => plain_text(content),
+            Inline::Image { alt, .. } => alt.clone(),
+            Inline::Html(_) => String::new(),
+            Inline::LineBreak => " ".to_owned(),
+        })
+        .collect()
+}
+
+fn visit_inlines_mut(inlines: &mut [Inline], visit: &mut impl FnMut(&mut Inline)) {
+    for inline in inlines {
+        visit(inline);
+
+        match inline {
+            Inline::Emphasis(content)
+            | Inline::Strong(content)
+            | Inline::Strikethrough(content)
+            | Inline::Link { text: content, .. } => visit_inlines_mut(content, visit),
+            _ => {}
+        }
+    }
+}
+
+fn rebase_block(block: &mut Block, link_base: &str, image_base: &str) {
+    block.visit_inlines_mut(&mut |inline| match inline {
+        Inline::Link { destination, .. } if is_relative(destination) => {
+            *destination = rebase(destination, link_base);
+        }
+        Inline::Image { source, .. } if is_relative(source) => {
+            *source = rebase(source, image_base);
+        }
+        Inline::Html(html) => *html = rebase_html(html, link_base, image_base),
+        _ => {}
+    });
+    block.visit_blocks_mut(&mut |block| match block {
+        Block::Html(html) => *html = rebase_html(html, link_base, image_base),
+        Block::Lazy(lazy) => {
+            let (lazy, link_base, image_base) =
+                (lazy.clone(), link_base.to_owned(), image_base.to_owned());
+
+            *block = Block::Lazy(LazyBlock::new(move |options| {
+                let mut block = lazy.evaluate(options);
+
+                rebase_block(&mut block, &link_base, &image_base);
+                block
+            }));
+        }
+        _ => {}
+    });
+}
+
+/// Rewrite the relative URLs of `href` and `src` attributes in raw HTML.
+fn rebase_html(html: &str, link_base: &str, image_base: &str) -> String {
+    let mut rebased = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(index) = rest.find(['h', 's']) {
+        let (before, after) = rest.split_at(index);
+        let attribute = ["href=\"", "src=\""].into_iter().find(|name| {
+            after.starts_with(name)
+                && !before.ends_with(|char: char| char.is_ascii_alphanumeric() || char == '-')
+        });
+
+        rebased.push_str(before);
+
+        let Some(attribute) = attribute else {
+            rebased.push_str(&after[..1]);
+            rest = &after[1..];
+            continue;
+        };
+        let value_start = attribute.len();
+        let Some(value_len) = after[value_start..].find('"') else {
+            rebased.push_str(after);
+            return rebased;
+        };
+        let value = &after[value_start..value_start + value_len];
+        let base = if attribute == "src=\"" { image_base } else { link_base };
+
+        rebased.push_str(attribute);
+
+        if is_relative(value) {
+            rebased.push_str(&rebase(value, base));
+        } else {
+            rebased.push_str(value);
+        }
+
+        rest = &after[value_start + value_len..];
+    }
+
+    rebased.push_str(rest);
+    rebased
+}
+
+/// Convert raw HTML to Markdown: `<summary>` becomes a bold paragraph, `<br>` a hard line break
+/// and any other tag or comment is dropped, keeping the text in between.
+fn strip_tags(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        let name = tag
+            .trim_start_matches('/')
+            .split(|char: char| char.is_whitespace() || char == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        rest = &rest[end + 1..];
+
+        match name.as_str() {
+            "br" => {
+                stripped.push_str(&format!("\\{LF}"));
+                rest = rest.strip_prefix(LF).unwrap_or(rest);
+            }
+            "summary" if tag.starts_with('/') => stripped.push_str(&format!("**{LF}{LF}")),
+            "summary" => stripped.push_str("**"),
+            _ => {}
+        }
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flavor::Flavor;
+
+    #[test]
+    fn heading_numbering_restarts_and_numbers_skipped_levels_zero() {
+        let document = Document::new()
+            .h3("Detail")
+            .h2("Part")
+            .h1("Appendix")
+            .h2("Part")
+            .with_heading_numbering(HeadingNumbering::FromLevel(2));
+        let headings: Vec<String> = document
+            .numbered_blocks()
+            .iter()
+            .map(|block| block.render(&RenderOptions::obsidian()))
+            .collect();
+
+        assert_eq!(headings, ["### 0.1 Detail", "## 1 Part", "# Appendix", "## 1 Part"]);
+    }
+
+    #[test]
+    fn list_start_is_lowered_to_nine_digits() {
+        let list = Block::List {
+            start: Some(u64::MAX),
+            items: vec![vec![Block::paragraph("a")], vec![Block::paragraph("b")]],
+        };
+
+        assert_eq!(list.render(&RenderOptions::default()), "999999998. a\n999999999. b");
+    }
+
+    #[test]
+    fn headings_stay_on_one_line_and_keep_trailing_hashes() {
+        use crate::options::HeadingStyle;
+
+        let document = Document::from(vec![Block::heading(3, "Fix\nissue #")]);
+        let render =
+            |heading| document.render(&RenderOptions { heading, ..RenderOptions::default() });
+
+        assert_eq!(render(HeadingStyle::Atx), "### Fix issue \\#");
+        assert_eq!(render(HeadingStyle::AtxClosed), "### Fix issue \\# ###");
+    }
+
+    #[test]
+    fn toc_lists_the_plain_text_of_headings() {
+        let document = Document::from(vec![Block::Heading {
+            level: 2,
+            content: vec![
+                Inline::Strong(vec![Inline::text("Run ")]),
+                Inline::Code("cargo test".to_owned()),
+            ],
+        }]);
+
+        assert_eq!(document.toc(6), "- [Run cargo test](#run-cargo-test)");
+    }
+
+    #[test]
+    fn toc_escapes_the_plain_text_of_headings() {
+        let document = Document::from(vec![Block::Heading {
+            level: 2,
+            content: vec![Inline::Code("*ptr".to_owned()), Inline::text(" and <T>")],
+        }]);
+
+        assert_eq!(document.toc(6), "- [\\*ptr and \\<T>](#ptr-and-t)");
+    }
+
+    #[test]
+    fn render_uses_the_bullet_and_line_ending_options() {
+        use crate::options::{BulletChar, LineEnding};
+
+        let document = Document::from(vec![Block::List {
+            start: None,
+            items: vec![vec![Block::paragraph("a")], vec![Block::paragraph("b")]],
+        }]);
+        let options = RenderOptions {
+            bullet: BulletChar::Plus,
+            line_ending: LineEnding::CrLf,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(document.render(&options), "+ a\r\n+ b");
+    }
+
+    #[test]
+    fn commonmark_falls_back_to_html_for_tables_and_strikethrough() {
+        let document = Document::new()
+            .table(&["*A*", ""], &[vec!["1", "2"]])
+            .block(Block::Paragraph(vec![Inline::Strikethrough(vec![Inline::text("old")])]));
+
+        assert_eq!(
+            document.to_string(),
+            "<table>\n<thead>\n<tr>\n<th>\n\n\\*A\\*\n\n</th>\n<th></th>\n</tr>\n</thead>\n\
+             <tbody>\n<tr>\n<td>\n\n1\n\n</td>\n<td>\n\n2\n\n</td>\n</tr>\n</tbody>\n\
+             </table>\n\n<del>old</del>"
+        );
+    }
+
+    #[test]
+    fn md_macro_builds_code_blocks_with_and_without_languages() {
+        let code = "a | b";
+        let document = crate::md! {
+            code_block(code)
+            code_block("shell-session": code)
+            h1()
+        };
+
+        assert_eq!(
+            document.blocks(),
+            [
+                Block::CodeBlock { info: None, code: code.to_owned() },
+                Block::CodeBlock { info: Some("shell-session".to_owned()), code: code.to_owned() },
+                Block::Heading { level: 1, content: Vec::new() },
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_lists_and_blockquotes_are_indented() {
+        let list = Block::List {
+            start: Some(1),
+            items: vec![
+                vec![
+                    Block::paragraph("First"),
+                    Block::List { start: None, items: vec![vec![Block::paragraph("Nested")]] },
+                ],
+                vec![Block::BlockQuote(vec![Block::paragraph("a"), Block::paragraph("b")])],
+            ],
+        };
+
+        assert_eq!(
+            Document::from(vec![list]).to_string(),
+            "1. First\n\n   - Nested\n\n2. > a\n   >\n   > b"
+        );
+    }
+
+    #[test]
+    fn links_with_spaces_get_angle_brackets() {
+        let link = Inline::Link {
+            text: vec![Inline::text("Docs")],
+            destination: "my docs/index.md".to_owned(),
+            title: Some("The \"docs\"".to_owned()),
+        };
+
+        assert_eq!(
+            link.render(&RenderOptions::default()),
+            "[Docs](<my docs/index.md> \"The \\\"docs\\\"\")"
+        );
+    }
+
+    #[test]
+    fn strip_html_converts_details_and_line_breaks() {
+        let options = RenderOptions { strip_html: true, ..RenderOptions::default() };
+        let document = Document::from(vec![
+            Block::Html("<p align=\"center\">\n  <img src=\"logo.png\">\n</p>".to_owned()),
+            Block::Html(
+                "<details>\n<summary>Output</summary>\n\n<!-- generated -->\nDone\n</details>"
+                    .to_owned(),
+            ),
+            Block::Paragraph(vec![
+                Inline::text("a"),
+                Inline::Html("<br/>".to_owned()),
+                Inline::Html("<kbd>".to_owned()),
+                Inline::text("b"),
+                Inline::Html("</kbd>".to_owned()),
+            ]),
+        ]);
+
+        assert_eq!(document.render(&options), "**Output**\n\nDone\n\na\\\nb");
+    }
+
+    #[test]
+    fn isolate_rtl_wraps_rtl_headings_and_cells() {
+        let document = Document::new()
+            .h1("שלום")
+            .table(&["Name", "שם"], &[vec!["Dana", "דנה"]]);
+        let isolated = |flavor| {
+            let options = RenderOptions { flavor, isolate_rtl: true, ..RenderOptions::default() };
+
+            document.render(&options)
+        };
+
+        assert_eq!(
+            isolated(Flavor::Gfm),
+            "\u{2067}שלום\u{2069}\n====\n\n\
+             | Name | \u{2067}שם\u{2069} |\n| --- | --- |\n| Dana | \u{2067}דנה\u{2069} |"
+        );
+        assert!(isolated(Flavor::CommonMark).contains("<td>\n\n\u{2067}דנה\u{2069}\n\n</td>"));
+        assert!(!document.to_string().contains('\u{2067}'));
+    }
+
+    #[test]
+    fn lazy_blocks_are_evaluated_per_render_and_rebased() {
+        let mut document = Document::new();
+
+        document.push_lazy(|options| {
+            Block::Paragraph(vec![Inline::Image {
+                alt: format!("{:?}", options.flavor),
+                source: "logo.png".to_owned(),
+                title: None,
+            }])
+        });
+        document.rebase_urls("https://example.com", "https://example.com/raw");
+
+        let gfm = RenderOptions { flavor: Flavor::Gfm, ..RenderOptions::default() };
+
+        assert_eq!(document.to_string(), "![CommonMark](https://example.com/raw/logo.png)");
+        assert_eq!(document.render(&gfm), "![Gfm](https://example.com/raw/logo.png)");
+        assert_eq!(document.clone(), document);
+    }
+
+    #[test]
+    fn rebase_urls_rewrites_nested_and_html_urls_only_when_relative() {
+        let mut document = Document::from(vec![
+            Block::List {
+                start: None,
+                items: vec![vec![Block::Paragraph(vec![Inline::Strong(vec![Inline::Link {
+                    text: vec![Inline::text("Changelog")],
+                    destination: "/CHANGELOG.md".to_owned(),
+                    title: None,
+                }])])]],
+            },
+            Block::Paragraph(vec![Inline::Link {
+                text: vec![Inline::text("Top")],
+                destination: "#top".to_owned(),
+                title: None,
+            }]),
+            Block::Html("<a href=\"LICENSE\"><img data-src=\"x.png\" src=\"logo.svg\"></a>\n\
+                         <img src=\"https://example.com/a.png\">"
+                .to_owned()),
+        ]);
+
+        document.rebase_urls("https://example.com/blob/", "https://example.com/raw");
+
+        assert_eq!(
+            document.to_string(),
+            "- **[Changelog](https://example.com/blob/CHANGELOG.md)**\n\n\
+             [Top](#top)\n\n\
+             <a href=\"https://example.com/blob/LICENSE\">\
+             <img data-src=\"x.png\" src=\"https://example.com/raw/logo.svg\"></a>\n\
+             <img src=\"https://example.com/a.png\">"
+        );
+    }
+}
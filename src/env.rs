@@ -0,0 +1,77 @@
+//! Environment variable reference documentation.
+
+use crate::code_span;
+use crate::escape::{escape_text, EscapeMode};
+use crate::table::pipe_table;
+
+/// A builder of the reference table of the environment variables an application reads.
+///
+/// Names are rendered as code spans and default values are escaped so they render literally.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::env::EnvVarTable;
+///
+/// let table = EnvVarTable::new()
+///     .var("DATABASE_URL", true, None, "The database to connect to.")
+///     .var("LOG_LEVEL", false, Some("*info*"), "The minimum level logged.");
+///
+/// assert_eq!(
+///     table.render(),
+///     "| Name | Required | Default | Description |\n\
+///      | --- | --- | --- | --- |\n\
+///      | `DATABASE_URL` | Yes |  | The database to connect to. |\n\
+///      | `LOG_LEVEL` | No | \\*info\\* | The minimum level logged. |"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EnvVarTable {
+    rows: Vec<Vec<String>>,
+}
+
+impl EnvVarTable {
+    /// Create an empty table.
+    pub fn new() -> EnvVarTable {
+        EnvVarTable::default()
+    }
+
+    /// Add an environment variable, whether it must be set, its default value if any and a
+    /// Markdown description.
+    pub fn var(
+        mut self,
+        name: &str,
+        required: bool,
+        default: Option<&str>,
+        description: &str,
+    ) -> EnvVarTable {
+        self.rows.push(vec![
+            code_span(name),
+            if required { "Yes" } else { "No" }.to_owned(),
+            default.map_or_else(String::new, |default| {
+                escape_text(default, EscapeMode::Minimal).into_owned()
+            }),
+            description.to_owned(),
+        ]);
+        self
+    }
+
+    /// Render the table.
+    pub fn render(&self) -> String {
+        let headers = ["Name", "Required", "Default", "Description"].map(String::from);
+
+        pipe_table(&headers, &self.rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_escaped_for_table_cells() {
+        let table = EnvVarTable::new().var("SEPARATOR", false, Some("`|`"), "");
+
+        assert!(table.render().ends_with("| `SEPARATOR` | No | \\`\\|\\` |  |"));
+    }
+}
@@ -0,0 +1,57 @@
+//! Errors of the functions which validate their input rather than emit broken Markdown.
+
+use std::error::Error;
+use std::fmt;
+
+/// Input which can't be rendered as the requested Markdown construct.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MdError {
+    /// An info string which would break its code fence: a backtick fence's info string may not
+    /// contain backticks, and no info string may contain a line break.
+    InvalidInfoString(String),
+    /// A heading level outside of 1–6.
+    InvalidHeadingLevel(u8),
+    /// A URL or email address which can't be written as an autolink, see
+    /// [`crate::url::autolink`].
+    InvalidAutolink(String),
+    /// A thematic break of a marker other than `-`, `*` or `_`, or shorter than 3, see
+    /// [`crate::thematic_break_with`].
+    InvalidThematicBreak {
+        /// The marker character.
+        marker: char,
+        /// The number of marker characters.
+        length: usize,
+    },
+    /// A failure of the writer an element was written into, see [`crate::write`].
+    Write(fmt::Error),
+}
+
+impl fmt::Display for MdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MdError::InvalidInfoString(info_string) => {
+                write!(f, "invalid code fence info string {info_string:?}")
+            }
+            MdError::InvalidHeadingLevel(level) => {
+                write!(f, "invalid heading level {level}, expected 1-6")
+            }
+            MdError::InvalidAutolink(input) => {
+                write!(f, "`{input}` can't be written as an autolink")
+            }
+            MdError::InvalidThematicBreak { marker, length } => write!(
+                f,
+                "a thematic break needs at least 3 of `-`, `*` or `_`, not {length} of `{marker}`"
+            ),
+            MdError::Write(error) => write!(f, "failed to write Markdown: {error}"),
+        }
+    }
+}
+
+impl Error for MdError {}
+
+impl From<fmt::Error> for MdError {
+    fn from(error: fmt::Error) -> MdError {
+        MdError::Write(error)
+    }
+}
@@ -0,0 +1,343 @@
+//! Escaping of text so it renders literally.
+
+use crate::write::{write_destination, written};
+use std::borrow::Cow;
+
+/// How aggressively text is escaped.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EscapeMode {
+    /// Escape only the characters which would change the meaning of the text in context, e.g.
+    /// a `#` beginning a line or a `*` anywhere. This keeps the Markdown source readable.
+    #[default]
+    Minimal,
+    /// Escape every ASCII punctuation character, which is always safe but noisy.
+    Aggressive,
+    /// Don't escape anything; the caller is trusted to provide valid Markdown.
+    Off,
+}
+
+/// Escape plain text per the provided mode so that it renders literally.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::escape::{escape_text, EscapeMode};
+///
+/// let text = "# Use *args, not snake_case.";
+///
+/// assert_eq!(escape_text(text, EscapeMode::Minimal), r"\# Use \*args, not snake_case.");
+/// assert_eq!(escape_text(text, EscapeMode::Aggressive), r"\# Use \*args\, not snake\_case\.");
+/// assert_eq!(escape_text(text, EscapeMode::Off), text);
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#backslash-escapes>
+pub fn escape_text(text: &str, mode: EscapeMode) -> Cow<'_, str> {
+    match mode {
+        EscapeMode::Minimal => Cow::Owned(escape_minimal(text)),
+        EscapeMode::Aggressive => Cow::Owned(escape_aggressive(text)),
+        EscapeMode::Off => Cow::Borrowed(text),
+    }
+}
+
+/// Escape heading text so it renders literally and stays on one line.
+///
+/// The text is escaped like [`EscapeMode::Minimal`] plain text, line feeds become spaces, and
+/// a trailing run of `#` preceded by a space is escaped so it isn't taken for the optional
+/// closing sequence of an ATX heading.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::escape::escape_heading;
+///
+/// assert_eq!(escape_heading("Fix issue #"), r"Fix issue \#");
+/// assert_eq!(escape_heading("C# *and*\nF#"), r"C# \*and\* F#");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#atx-headings>
+pub fn escape_heading(text: &str) -> String {
+    escape_closing_sequence(escape_minimal(&one_line(text)))
+}
+
+/// Join the lines of text with spaces, as a line feed would end a heading.
+pub(crate) fn one_line(text: &str) -> String {
+    text.lines().map(str::trim).collect::<Vec<&str>>().join(" ")
+}
+
+/// Escape a trailing run of `#` preceded by a space so it isn't taken for the optional closing
+/// sequence of an ATX heading.
+pub(crate) fn escape_closing_sequence(mut text: String) -> String {
+    let closing = text.len() - text.trim_end_matches('#').len();
+    let before = text[..text.len() - closing].chars().next_back();
+
+    if closing > 0 && matches!(before, Some(' ' | '\t')) {
+        text.insert(text.len() - closing, '\\');
+    }
+
+    text
+}
+
+/// Escape the content of a GFM table cell: pipes would end the cell and line feeds the row, so
+/// they're escaped and replaced with `<br>` elements respectively. The content is otherwise
+/// left as is, so it may contain inline Markdown; escape plain text with [`escape_text`] first.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::escape::escape_table_cell;
+///
+/// assert_eq!(escape_table_cell(" a | b\nc "), r"a \| b<br>c");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+pub fn escape_table_cell(text: &str) -> String {
+    text.trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Escape a link destination, wrapping it in angle brackets when it's empty or contains spaces
+/// or parentheses, which would otherwise end it. Line feeds, which no destination may contain,
+/// are percent-encoded.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::escape::escape_link_destination;
+///
+/// assert_eq!(escape_link_destination("docs/a.md"), "docs/a.md");
+/// assert_eq!(escape_link_destination("my docs/(a).md"), "<my docs/(a).md>");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#link-destination>
+pub fn escape_link_destination(destination: &str) -> String {
+    written(|out| write_destination(out, destination))
+}
+
+/// Escape the value of a double-quoted HTML attribute.
+pub(crate) fn escape_html_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+/// Escape the first character of a trimmed line when it would start another block, e.g. a list
+/// item or a heading, or underline the line before it as a setext heading or the header of a
+/// table. The rest of the line is left as is, so it may contain inline Markdown.
+pub(crate) fn escape_block_start<'a>(line: &'a str, previous: Option<&str>) -> Cow<'a, str> {
+    let chars: Vec<char> = line.chars().collect();
+    let digits = chars.iter().take_while(|char| char.is_ascii_digit()).count();
+    let is_spacing = |char: Option<&char>| char.is_none_or(|char| *char == ' ' || *char == '\t');
+    let marker = match chars.first() {
+        Some('#' | '>') => Some(0),
+        Some('-' | '+' | '*') if is_spacing(chars.get(1)) => Some(0),
+        Some('-' | '=' | '*' | '_') if is_underline_or_break(&chars) => Some(0),
+        Some(fence @ ('`' | '~')) if chars.starts_with(&[*fence; 3]) => Some(0),
+        Some(_)
+            if (1..=9).contains(&digits)
+                && matches!(chars.get(digits), Some('.' | ')'))
+                && is_spacing(chars.get(digits + 1)) =>
+        {
+            // The digits are ASCII, so their count is also the byte index of the delimiter.
+            Some(digits)
+        }
+        _ if is_delimiter_row(line, previous) => line.find(['-', ':']),
+        _ => None,
+    };
+
+    match marker {
+        Some(index) => Cow::Owned(format!("{}\\{}", &line[..index], &line[index..])),
+        None => Cow::Borrowed(line),
+    }
+}
+
+fn escape_aggressive(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if char.is_ascii_punctuation() {
+            escaped.push('\\');
+        }
+
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+fn escape_minimal(text: &str) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let previous = index.checked_sub(1).map(|index| lines[index]);
+
+            if is_delimiter_row(line, previous) {
+                escape_block_start(line, previous).into_owned()
+            } else {
+                escape_minimal_line(line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn escape_minimal_line(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let indent = chars.iter().take_while(|char| **char == ' ').count();
+    let mut escaped = String::with_capacity(line.len());
+
+    for (index, char) in chars.iter().copied().enumerate() {
+        let previous = index.checked_sub(1).map(|index| chars[index]);
+        let next = chars.get(index + 1).copied();
+        let at_start = index == indent;
+        let escape = match char {
+            '*' | '`' | '[' | ']' | '<' => true,
+            '\\' => next.is_none_or(|next| next.is_ascii_punctuation()),
+            // Intraword underscores never delimit emphasis.
+            '_' => !(is_word(previous) && is_word(next)),
+            '&' => is_entity(&chars[index + 1..]),
+            '#' | '>' => at_start,
+            '+' | '-' => {
+                at_start && (next.is_none_or(|next| next == ' ') || is_break(&chars[index..]))
+            }
+            '=' => at_start && chars[index..].iter().all(|char| *char == '=' || *char == ' '),
+            // Runs of two or more tildes delimit strikethrough, and of three or more at the start
+            // of a line, a code fence.
+            '~' => previous == Some('~') || next == Some('~'),
+            '.' | ')' => {
+                let digits = &chars[indent..index];
+
+                !digits.is_empty()
+                    && digits.len() <= 9
+                    && digits.iter().all(char::is_ascii_digit)
+                    && next.is_none_or(|next| next == ' ')
+            }
+            _ => false,
+        };
+
+        if escape {
+            escaped.push('\\');
+        }
+
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+fn is_word(char: Option<char>) -> bool {
+    char.is_some_and(char::is_alphanumeric)
+}
+
+/// Whether the characters after a `&` would be parsed as an entity or numeric character
+/// reference.
+fn is_entity(rest: &[char]) -> bool {
+    let name = rest
+        .iter()
+        .take_while(|char| char.is_ascii_alphanumeric() || **char == '#')
+        .count();
+
+    name > 0 && rest.get(name) == Some(&';')
+}
+
+/// Whether a line is a setext heading underline or a thematic break, i.e. a run of `-` or `=`
+/// characters, or at least three `*` or `_` characters, optionally separated by spaces.
+fn is_underline_or_break(chars: &[char]) -> bool {
+    let marker = chars[0];
+    let count = chars.iter().filter(|char| **char == marker).count();
+
+    chars.iter().all(|char| *char == marker || *char == ' ' || *char == '\t')
+        && (matches!(marker, '-' | '=') || count >= 3)
+}
+
+/// Whether a line is a table delimiter row, e.g. `| --- | :-: |`, following a line containing a
+/// pipe, which it would turn into the header of a table.
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+fn is_delimiter_row(line: &str, previous: Option<&str>) -> bool {
+    previous.is_some_and(|previous| previous.contains('|'))
+        && line.contains('-')
+        && line.chars().all(|char| matches!(char, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Whether the rest of the line is a thematic break of `-` characters.
+fn is_break(rest: &[char]) -> bool {
+    rest.iter().filter(|char| **char == '-').count() >= 3
+        && rest.iter().all(|char| *char == '-' || *char == ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_escapes_block_markers_only_at_line_start() {
+        let text = "- a - b\n1. c 2. d\n---\n> e > f";
+
+        assert_eq!(
+            escape_text(text, EscapeMode::Minimal),
+            "\\- a - b\n1\\. c 2. d\n\\---\n\\> e > f"
+        );
+    }
+
+    #[test]
+    fn escape_heading_only_escapes_a_closing_sequence() {
+        assert_eq!(escape_heading("Issue ##"), r"Issue \##");
+        assert_eq!(escape_heading("###"), r"\###");
+        assert_eq!(escape_heading("C#"), "C#");
+    }
+
+    #[test]
+    fn escape_block_start_only_escapes_block_markers() {
+        assert_eq!(escape_block_start("- a", None), r"\- a");
+        assert_eq!(escape_block_start("# a", None), r"\# a");
+        assert_eq!(escape_block_start("> a", None), r"\> a");
+        assert_eq!(escape_block_start("12. a", None), r"12\. a");
+        assert_eq!(escape_block_start("1)", None), r"1\)");
+        assert_eq!(escape_block_start("===", None), r"\===");
+        assert_eq!(escape_block_start("* * *", None), r"\* * *");
+        assert_eq!(escape_block_start("```rust", None), r"\```rust");
+        assert_eq!(escape_block_start("-a *b* 1. c", None), "-a *b* 1. c");
+        assert_eq!(escape_block_start("2024. A year", None), r"2024\. A year");
+        assert_eq!(escape_block_start("1234567890. a", None), "1234567890. a");
+        assert_eq!(escape_block_start("__init__", None), "__init__");
+    }
+
+    #[test]
+    fn delimiter_rows_after_a_pipe_are_escaped() {
+        assert_eq!(escape_block_start("|---|:-:|", Some("a | b")), r"|\---|:-:|");
+        assert_eq!(escape_block_start(":-- | --", Some("| a |")), r"\:-- | --");
+        assert_eq!(escape_block_start("|---|", Some("a")), "|---|");
+        assert_eq!(escape_block_start("|a-b|", Some("a | b")), "|a-b|");
+        assert_eq!(
+            escape_text("a | b\n--|--\nc | d", EscapeMode::Minimal),
+            "a | b\n\\--|--\nc | d"
+        );
+    }
+
+    #[test]
+    fn minimal_escapes_strikethrough_and_fences() {
+        assert_eq!(escape_text("~~gone~~ ~1 ~~~", EscapeMode::Minimal), r"\~\~gone\~\~ ~1 \~\~\~");
+        assert_eq!(escape_text("~~~rust", EscapeMode::Minimal), r"\~\~\~rust");
+    }
+
+    #[test]
+    fn minimal_escapes_entities_and_backslashes() {
+        assert_eq!(escape_text(r"&amp; & \* \d", EscapeMode::Minimal), r"\&amp; & \\\* \d");
+    }
+}
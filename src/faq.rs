@@ -0,0 +1,97 @@
+//! Frequently asked question sections.
+
+use crate::diagnostics::collapsed;
+use crate::slug::Slugger;
+use crate::{h3, LF};
+
+/// A builder of a section of frequently asked questions and their answers.
+///
+/// Questions are rendered as level 3 headings followed by their answers, or as collapsible
+/// `<details>` elements whose summaries are the questions. Either way a list of links to the
+/// questions may be rendered at the top.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::faq::Faq;
+///
+/// let faq = Faq::new()
+///     .question("Is it fast?", "Yes.")
+///     .question("Why?", "Rust.")
+///     .table_of_contents(true);
+///
+/// let toc = "- [Is it fast?](#is-it-fast)\n- [Why?](#why)";
+///
+/// assert_eq!(faq.render(), format!("{toc}\n\n### Is it fast?\n\nYes.\n\n### Why?\n\nRust."));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Faq {
+    entries: Vec<(String, String)>,
+    collapsible: bool,
+    table_of_contents: bool,
+}
+
+impl Faq {
+    /// Create an empty FAQ.
+    pub fn new() -> Faq {
+        Faq::default()
+    }
+
+    /// Add a question and its Markdown answer.
+    pub fn question(mut self, question: &str, answer: &str) -> Faq {
+        self.entries.push((question.to_owned(), answer.trim().to_owned()));
+        self
+    }
+
+    /// Set whether answers are hidden in `<details>` elements until their question is clicked.
+    pub fn collapsible(self, collapsible: bool) -> Faq {
+        Faq { collapsible, ..self }
+    }
+
+    /// Set whether a list of links to the questions is rendered at the top.
+    pub fn table_of_contents(self, table_of_contents: bool) -> Faq {
+        Faq { table_of_contents, ..self }
+    }
+
+    /// Render the FAQ.
+    ///
+    /// Collapsible questions get HTML anchors so the table of contents can link to them, since
+    /// `<summary>` elements don't get anchors of their own.
+    pub fn render(&self) -> String {
+        let mut slugger = Slugger::default();
+        let mut toc = Vec::new();
+        let mut blocks = Vec::new();
+
+        for (question, answer) in &self.entries {
+            let slug = slugger.slug(question);
+
+            toc.push(format!("- [{question}](#{slug})"));
+            blocks.push(if self.collapsible {
+                collapsed(&format!("<a id=\"{slug}\"></a>{question}"), answer)
+            } else {
+                [h3(question), answer.clone()].join(&LF.to_string().repeat(2))
+            });
+        }
+
+        if self.table_of_contents && !toc.is_empty() {
+            blocks.insert(0, toc.join(&LF.to_string()));
+        }
+
+        blocks.join(&LF.to_string().repeat(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapsible_questions_get_anchors() {
+        let faq = Faq::new().question("Why?", "Because.").collapsible(true);
+
+        assert_eq!(
+            faq.render(),
+            "<details>\n<summary><a id=\"why\"></a>Why?</summary>\n\nBecause.\n\n</details>"
+        );
+    }
+}
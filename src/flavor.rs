@@ -0,0 +1,192 @@
+//! Markdown flavors.
+
+/// The flavor of Markdown which output is generated for.
+///
+/// Constructs which the targeted flavor doesn't support are rendered with a fallback, typically
+/// raw HTML.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Flavor {
+    /// Plain [CommonMark](https://spec.commonmark.org/).
+    #[default]
+    CommonMark,
+    /// [Azure DevOps wiki Markdown](https://learn.microsoft.com/en-us/azure/devops/project/wiki/markdown-guidance).
+    AzureDevOps,
+    /// [GitHub Flavored Markdown](https://github.github.com/gfm/).
+    Gfm,
+    /// [GitLab Flavored Markdown](https://docs.gitlab.com/ee/user/markdown.html).
+    GitLab,
+    /// [Pandoc's Markdown](https://pandoc.org/MANUAL.html#pandocs-markdown).
+    Pandoc,
+    /// [Obsidian Flavored Markdown](https://help.obsidian.md/Editing+and+formatting/Obsidian+Flavored+Markdown).
+    Obsidian,
+    /// [Python-Markdown](https://python-markdown.github.io/) as configured by
+    /// [Material for MkDocs](https://squidfunk.github.io/mkdocs-material/), including the
+    /// PyMdown extensions.
+    MkDocs,
+}
+
+impl Flavor {
+    /// Whether ordered lists may be numbered with letters and roman numerals, e.g. `a.` and
+    /// `iv.`.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Pandoc.supports_fancy_lists());
+    /// assert!(!Flavor::Gfm.supports_fancy_lists());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://pandoc.org/MANUAL.html#extension-fancy_lists>
+    pub fn supports_fancy_lists(self) -> bool {
+        self == Flavor::Pandoc
+    }
+
+    /// Whether task list items may have states besides to do and done, e.g. `[/]` for in
+    /// progress.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Obsidian.supports_extended_task_states());
+    /// assert!(!Flavor::Gfm.supports_extended_task_states());
+    /// ```
+    pub fn supports_extended_task_states(self) -> bool {
+        self == Flavor::Obsidian
+    }
+
+    /// Whether content may be grouped into tabs, e.g. with `=== "Title"` blocks.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::MkDocs.supports_content_tabs());
+    /// assert!(!Flavor::Gfm.supports_content_tabs());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://facelessuser.github.io/pymdown-extensions/extensions/tabbed/>
+    pub fn supports_content_tabs(self) -> bool {
+        self == Flavor::MkDocs
+    }
+
+    /// Whether terms may be followed by `:   definition` lines forming a definition list.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Pandoc.supports_definition_lists());
+    /// assert!(!Flavor::Gfm.supports_definition_lists());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://pandoc.org/MANUAL.html#definition-lists>
+    /// - <https://python-markdown.github.io/extensions/definition_lists/>
+    pub fn supports_definition_lists(self) -> bool {
+        matches!(self, Flavor::Pandoc | Flavor::MkDocs)
+    }
+
+    /// Whether emoji are expected to render, as opposed to e.g. a PDF built with LaTeX where
+    /// they typically don't.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Gfm.renders_emoji());
+    /// assert!(!Flavor::Pandoc.renders_emoji());
+    /// ```
+    pub fn renders_emoji(self) -> bool {
+        matches!(self, Flavor::Gfm | Flavor::Obsidian | Flavor::MkDocs)
+    }
+
+    /// Whether elements may be followed by `{#id .class key=value}` attributes.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Pandoc.supports_attributes());
+    /// assert!(!Flavor::Gfm.supports_attributes());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://pandoc.org/MANUAL.html#extension-link_attributes>
+    /// - <https://python-markdown.github.io/extensions/attr_list/>
+    pub fn supports_attributes(self) -> bool {
+        matches!(self, Flavor::Pandoc | Flavor::MkDocs)
+    }
+
+    /// Whether pipe tables are supported. Documents render tables as raw HTML otherwise.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Gfm.supports_tables());
+    /// assert!(!Flavor::CommonMark.supports_tables());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://github.github.com/gfm/#tables-extension->
+    /// - <https://pandoc.org/MANUAL.html#extension-pipe_tables>
+    pub fn supports_tables(self) -> bool {
+        self != Flavor::CommonMark
+    }
+
+    /// Whether text may be struck through with `~~`. Documents render struck through text as
+    /// `<del>` elements otherwise.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Gfm.supports_strikethrough());
+    /// assert!(!Flavor::CommonMark.supports_strikethrough());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://github.github.com/gfm/#strikethrough-extension->
+    /// - <https://pandoc.org/MANUAL.html#extension-strikeout>
+    pub fn supports_strikethrough(self) -> bool {
+        self != Flavor::CommonMark
+    }
+
+    /// Whether list items may be tasks, e.g. `- [x] Done`.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    ///
+    /// assert!(Flavor::Gfm.supports_task_lists());
+    /// assert!(!Flavor::CommonMark.supports_task_lists());
+    /// ```
+    ///
+    /// Reference
+    /// =========
+    /// - <https://github.github.com/gfm/#task-list-items-extension->
+    /// - <https://pandoc.org/MANUAL.html#extension-task_lists>
+    pub fn supports_task_lists(self) -> bool {
+        self != Flavor::CommonMark
+    }
+}
@@ -0,0 +1,233 @@
+//! Footnotes collected while generating a document.
+
+use crate::LF;
+
+/// Where collected footnote definitions are emitted.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum FootnotePlacement {
+    /// After the last block of the document.
+    #[default]
+    EndOfDocument,
+    /// After the last block of each top-level section.
+    EndOfSection,
+    /// Immediately after the block which first references the footnote.
+    AfterFirstUse,
+}
+
+/// A collector of footnotes which numbers them and emits their definitions per a
+/// [`FootnotePlacement`].
+///
+/// A generator calls [`Footnotes::reference`] while writing a block and then the hook matching
+/// each boundary it passes: [`Footnotes::after_block`], [`Footnotes::after_section`] and finally
+/// [`Footnotes::at_end`]. Only the hook matching the placement returns the pending definitions,
+/// so a generator can call all of them unconditionally.
+///
+/// Footnotes are numbered in the order they're referenced, and their definitions are emitted in
+/// that order. Named footnotes (see [`Footnotes::reference_named`]) never share a label with
+/// another footnote: numbers taken by a named footnote are skipped and conflicting names are
+/// given a `-2`, `-3`, … suffix.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::footnote::{FootnotePlacement, Footnotes};
+///
+/// let mut footnotes = Footnotes::new(FootnotePlacement::EndOfSection);
+/// let paragraph = format!("Rust is fast.{}", footnotes.reference("Citation needed."));
+///
+/// assert_eq!(paragraph, "Rust is fast.[^1]");
+/// assert_eq!(footnotes.after_block(), None);
+/// assert_eq!(footnotes.after_section().as_deref(), Some("[^1]: Citation needed."));
+/// assert_eq!(footnotes.at_end(), None);
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#footnotes>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Footnotes {
+    placement: FootnotePlacement,
+    count: usize,
+    pending: Vec<(String, String)>,
+    // The label and text of every footnote registered so far.
+    registered: Vec<(String, String)>,
+}
+
+impl Footnotes {
+    /// Create a collector emitting definitions per the provided placement.
+    pub fn new(placement: FootnotePlacement) -> Footnotes {
+        Footnotes {
+            placement,
+            ..Footnotes::default()
+        }
+    }
+
+    /// The placement of this collector's definitions.
+    pub fn placement(&self) -> FootnotePlacement {
+        self.placement
+    }
+
+    /// Register a footnote and get back the marker referencing it.
+    pub fn reference(&mut self, text: &str) -> String {
+        let label = loop {
+            self.count += 1;
+
+            let label = self.count.to_string();
+
+            if !self.is_registered(&label) {
+                break label;
+            }
+        };
+
+        self.register(label, text)
+    }
+
+    /// Register a footnote under a label and get back the marker referencing it. Referencing a
+    /// label again with the same text reuses its footnote, while a label already taken by
+    /// another footnote is given a `-2`, `-3`, … suffix. Whitespace in the label is replaced
+    /// with hyphens and brackets and carets are removed, and a label left empty falls back to
+    /// a number as with [`Footnotes::reference`].
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::footnote::Footnotes;
+    ///
+    /// let mut footnotes = Footnotes::default();
+    ///
+    /// assert_eq!(footnotes.reference_named("rust book", "The Book."), "[^rust-book]");
+    /// assert_eq!(footnotes.reference_named("rust book", "The Book."), "[^rust-book]");
+    /// assert_eq!(footnotes.reference_named("rust book", "Nomicon."), "[^rust-book-2]");
+    /// assert_eq!(
+    ///     footnotes.at_end().as_deref(),
+    ///     Some("[^rust-book]: The Book.\n[^rust-book-2]: Nomicon.")
+    /// );
+    /// ```
+    pub fn reference_named(&mut self, label: &str, text: &str) -> String {
+        let label = label
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join("-")
+            .replace(['[', ']', '^'], "");
+
+        if label.is_empty() {
+            return self.reference(text);
+        }
+
+        let mut assigned = label.clone();
+        let mut count = 1;
+
+        while let Some((_, registered)) = self.find(&assigned) {
+            if registered == text {
+                return format!("[^{assigned}]");
+            }
+
+            count += 1;
+            assigned = format!("{label}-{count}");
+        }
+
+        self.register(assigned, text)
+    }
+
+    /// The pending definitions, if footnotes are placed after the block which uses them.
+    pub fn after_block(&mut self) -> Option<String> {
+        self.take_if(FootnotePlacement::AfterFirstUse)
+    }
+
+    /// The pending definitions, if footnotes are placed at the end of each section.
+    pub fn after_section(&mut self) -> Option<String> {
+        self.take_if(FootnotePlacement::EndOfSection)
+    }
+
+    /// The pending definitions, if footnotes are placed at the end of the document.
+    pub fn at_end(&mut self) -> Option<String> {
+        self.take_if(FootnotePlacement::EndOfDocument)
+    }
+
+    fn register(&mut self, label: String, text: &str) -> String {
+        let marker = format!("[^{label}]");
+
+        self.registered.push((label.clone(), text.to_owned()));
+        self.pending.push((label, text.to_owned()));
+
+        marker
+    }
+
+    fn find(&self, label: &str) -> Option<&(String, String)> {
+        self.registered
+            .iter()
+            .find(|(registered, _)| registered.eq_ignore_ascii_case(label))
+    }
+
+    fn is_registered(&self, label: &str) -> bool {
+        self.find(label).is_some()
+    }
+
+    fn take_if(&mut self, placement: FootnotePlacement) -> Option<String> {
+        if self.placement != placement || self.pending.is_empty() {
+            return None;
+        }
+
+        let definitions = self
+            .pending
+            .drain(..)
+            .map(|(label, text)| definition(&label, &text))
+            .collect::<Vec<String>>()
+            .join(&LF.to_string());
+
+        Some(definitions)
+    }
+}
+
+/// Create a footnote definition, indenting any continuation lines so they remain part of it.
+fn definition(label: &str, text: &str) -> String {
+    let text = text.lines().collect::<Vec<&str>>().join(&format!("{LF}    "));
+
+    format!("[^{label}]: {text}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbering_continues_across_flushes() {
+        let mut footnotes = Footnotes::new(FootnotePlacement::AfterFirstUse);
+
+        footnotes.reference("One.");
+        assert_eq!(footnotes.after_block().as_deref(), Some("[^1]: One."));
+        footnotes.reference("Two.");
+        assert_eq!(footnotes.after_block().as_deref(), Some("[^2]: Two."));
+    }
+
+    #[test]
+    fn numbers_skip_labels_taken_by_named_footnotes() {
+        let mut footnotes = Footnotes::default();
+
+        assert_eq!(footnotes.reference_named("2", "Named."), "[^2]");
+        assert_eq!(footnotes.reference("One."), "[^1]");
+        assert_eq!(footnotes.reference("Three."), "[^3]");
+        assert_eq!(
+            footnotes.at_end().as_deref(),
+            Some("[^2]: Named.\n[^1]: One.\n[^3]: Three.")
+        );
+    }
+
+    #[test]
+    fn labels_left_empty_fall_back_to_numbers() {
+        let mut footnotes = Footnotes::default();
+
+        assert_eq!(footnotes.reference_named("[^]", "Empty."), "[^1]");
+        assert_eq!(footnotes.reference_named(" ", "Blank."), "[^2]");
+        assert_eq!(footnotes.at_end().as_deref(), Some("[^1]: Empty.\n[^2]: Blank."));
+    }
+
+    #[test]
+    fn multi_line_definitions_are_indented() {
+        let mut footnotes = Footnotes::default();
+
+        footnotes.reference("First line.\nSecond line.");
+
+        assert_eq!(footnotes.at_end().as_deref(), Some("[^1]: First line.\n    Second line."));
+    }
+}
@@ -0,0 +1,104 @@
+//! Front matter of static site generators' content files, e.g. Hugo, Zola or Jekyll.
+
+use crate::LF;
+use serde::Serialize;
+
+/// Create a YAML front matter block delimited by `---` lines from a serializable value, e.g. a
+/// struct of the page's metadata.
+///
+/// Errors
+/// ======
+/// Returns an error when the value can't be serialized as YAML, e.g. when it isn't a map or a
+/// struct.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::front_matter::yaml_front_matter;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Page<'a> {
+///     title: &'a str,
+///     draft: bool,
+///     tags: Vec<&'a str>,
+/// }
+///
+/// let page = Page { title: "Release 1.0", draft: false, tags: vec!["release"] };
+///
+/// assert_eq!(
+///     yaml_front_matter(&page).unwrap(),
+///     "---\ntitle: Release 1.0\ndraft: false\ntags:\n- release\n---"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://gohugo.io/content-management/front-matter/>
+/// - <https://jekyllrb.com/docs/front-matter/>
+#[cfg(feature = "yaml")]
+pub fn yaml_front_matter<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_yaml::Error> {
+    let yaml = serde_yaml::to_string(value)?;
+
+    Ok(delimit("---", &yaml))
+}
+
+/// Create a TOML front matter block delimited by `+++` lines from a serializable value, e.g. a
+/// struct of the page's metadata.
+///
+/// Errors
+/// ======
+/// Returns an error when the value can't be serialized as TOML, e.g. when it isn't a map or a
+/// struct, or contains `None` values in a sequence.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::front_matter::toml_front_matter;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Page<'a> {
+///     title: &'a str,
+///     weight: u32,
+/// }
+///
+/// let page = Page { title: "Install", weight: 10 };
+///
+/// assert_eq!(
+///     toml_front_matter(&page).unwrap(),
+///     "+++\ntitle = \"Install\"\nweight = 10\n+++"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://www.getzola.org/documentation/content/page/#front-matter>
+/// - <https://gohugo.io/content-management/front-matter/>
+#[cfg(feature = "toml")]
+pub fn toml_front_matter<T: Serialize + ?Sized>(value: &T) -> Result<String, toml::ser::Error> {
+    let toml = toml::to_string(value)?;
+
+    Ok(delimit("+++", &toml))
+}
+
+fn delimit(delimiter: &str, body: &str) -> String {
+    let body = body.trim_end_matches(LF);
+
+    if body.is_empty() {
+        format!("{delimiter}{LF}{delimiter}")
+    } else {
+        format!("{delimiter}{LF}{body}{LF}{delimiter}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delimit_keeps_empty_front_matter_on_two_lines() {
+        assert_eq!(delimit("---", "{}\n"), "---\n{}\n---");
+        assert_eq!(delimit("+++", ""), "+++\n+++");
+    }
+}
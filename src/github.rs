@@ -0,0 +1,124 @@
+//! References to GitHub users, issues and commits, e.g. for bots' comments.
+//!
+//! GitHub autolinks these references when rendering its own Markdown. For other flavors, e.g.
+//! when mirroring a comment to a changelog or another forge, they're written as explicit links.
+
+use crate::flavor::Flavor;
+use crate::link;
+
+const GITHUB: &str = "https://github.com";
+
+/// Create a mention of a user or team, e.g. `@octocat` or `@github/docs`, with or without
+/// the leading `@`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::flavor::Flavor;
+/// use md_writer::github::mention;
+///
+/// assert_eq!(mention("octocat", Flavor::Gfm), "@octocat");
+/// assert_eq!(mention("@octocat", Flavor::GitLab), "[@octocat](https://github.com/octocat)");
+/// assert_eq!(
+///     mention("github/docs", Flavor::CommonMark),
+///     "[@github/docs](https://github.com/orgs/github/teams/docs)"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#mentioning-people-and-teams>
+pub fn mention(user: &str, flavor: Flavor) -> String {
+    let user = user.strip_prefix('@').unwrap_or(user);
+    let text = format!("@{user}");
+
+    if flavor == Flavor::Gfm {
+        return text;
+    }
+
+    let url = match user.split_once('/') {
+        Some((organization, team)) => format!("{GITHUB}/orgs/{organization}/teams/{team}"),
+        None => format!("{GITHUB}/{user}"),
+    };
+
+    link(&text, &url, None)
+}
+
+/// Create a reference to an issue or pull request, e.g. `rust-lang/rust#123`.
+///
+/// The explicit link points at the issue, which GitHub redirects to the pull request when the
+/// number is one.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::flavor::Flavor;
+/// use md_writer::github::issue_ref;
+///
+/// assert_eq!(issue_ref("rust-lang", "rust", 123, Flavor::Gfm), "rust-lang/rust#123");
+/// assert_eq!(
+///     issue_ref("rust-lang", "rust", 123, Flavor::CommonMark),
+///     "[rust-lang/rust#123](https://github.com/rust-lang/rust/issues/123)"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls#issues-and-pull-requests>
+pub fn issue_ref(owner: &str, repository: &str, number: u64, flavor: Flavor) -> String {
+    let text = format!("{owner}/{repository}#{number}");
+
+    if flavor == Flavor::Gfm {
+        text
+    } else {
+        link(&text, &format!("{GITHUB}/{owner}/{repository}/issues/{number}"), None)
+    }
+}
+
+/// Create a reference to a commit, e.g. `rust-lang/rust@a1b2c3d`, shown with the SHA shortened
+/// to 7 characters as GitHub does.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::flavor::Flavor;
+/// use md_writer::github::commit_ref;
+///
+/// let sha = "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678";
+///
+/// assert_eq!(commit_ref("rust-lang", "rust", sha, Flavor::Gfm), format!("rust-lang/rust@{sha}"));
+/// assert_eq!(
+///     commit_ref("rust-lang", "rust", sha, Flavor::CommonMark),
+///     format!("[rust-lang/rust@a1b2c3d](https://github.com/rust-lang/rust/commit/{sha})")
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls#commit-shas>
+pub fn commit_ref(owner: &str, repository: &str, sha: &str, flavor: Flavor) -> String {
+    if flavor == Flavor::Gfm {
+        return format!("{owner}/{repository}@{sha}");
+    }
+
+    let short: String = sha.chars().take(7).collect();
+
+    link(
+        &format!("{owner}/{repository}@{short}"),
+        &format!("{GITHUB}/{owner}/{repository}/commit/{sha}"),
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_shas_are_kept_whole() {
+        assert_eq!(
+            commit_ref("o", "r", "abc", Flavor::Pandoc),
+            "[o/r@abc](https://github.com/o/r/commit/abc)"
+        );
+    }
+}
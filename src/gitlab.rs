@@ -0,0 +1,82 @@
+//! GitLab Flavored Markdown constructs.
+
+use crate::LF;
+
+/// The directive GitLab replaces with a table of contents of the headings of the document.
+///
+/// Reference
+/// =========
+/// - <https://docs.gitlab.com/ee/user/markdown.html#table-of-contents>
+pub const TABLE_OF_CONTENTS: &str = "[[_TOC_]]";
+
+/// Create a multiline blockquote, which is fenced by `>>>` rather than prefixing every line.
+///
+/// This keeps long quotes, e.g. of pasted emails or logs, readable in the Markdown source.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::gitlab::multiline_blockquote;
+///
+/// assert_eq!(multiline_blockquote("Line one\n\nLine two"), ">>>\nLine one\n\nLine two\n>>>");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.gitlab.com/ee/user/markdown.html#multiline-blockquote>
+pub fn multiline_blockquote(text: &str) -> String {
+    format!(">>>{LF}{}{LF}>>>", text.trim_end())
+}
+
+/// Create a reference to a merge request, optionally in another project, e.g. `!123` or
+/// `group/project!123`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::gitlab::merge_request_ref;
+///
+/// assert_eq!(merge_request_ref(None, 123), "!123");
+/// assert_eq!(merge_request_ref(Some("gitlab-org/gitlab"), 123), "gitlab-org/gitlab!123");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.gitlab.com/ee/user/markdown.html#gitlab-specific-references>
+pub fn merge_request_ref(project: Option<&str>, number: u64) -> String {
+    format!("{}!{number}", project.unwrap_or_default())
+}
+
+/// Create a reference to an issue, optionally in another project, e.g. `#123` or
+/// `group/project#123`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::gitlab::issue_ref;
+///
+/// assert_eq!(issue_ref(None, 123), "#123");
+/// assert_eq!(issue_ref(Some("gitlab-org/gitlab"), 123), "gitlab-org/gitlab#123");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.gitlab.com/ee/user/markdown.html#gitlab-specific-references>
+pub fn issue_ref(project: Option<&str>, number: u64) -> String {
+    format!("{}#{number}", project.unwrap_or_default())
+}
+
+/// Create a reference to a snippet, e.g. `$123`.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::gitlab::snippet_ref(123), "$123");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.gitlab.com/ee/user/markdown.html#gitlab-specific-references>
+pub fn snippet_ref(number: u64) -> String {
+    format!("${number}")
+}
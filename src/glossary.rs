@@ -0,0 +1,87 @@
+//! Glossaries of terms and their definitions.
+
+use crate::flavor::Flavor;
+use crate::slug::Slugger;
+use crate::LF;
+
+/// Render a glossary from pairs of terms and definitions, sorted case-insensitively by term.
+///
+/// Every term gets an HTML anchor (its slug) so it can be linked to, e.g. `[MSRV](#msrv)`.
+/// Flavors which support definition lists get one; everything else gets a paragraph per term
+/// beginning with the term in bold.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, glossary::glossary};
+///
+/// let entries = [("MSRV", "Minimum supported Rust version."), ("crate", "A compilation unit.")];
+///
+/// assert_eq!(
+///     glossary(entries, Flavor::Pandoc),
+///     "<a id=\"crate\"></a>crate\n:   A compilation unit.\n\n\
+///      <a id=\"msrv\"></a>MSRV\n:   Minimum supported Rust version."
+/// );
+/// assert_eq!(
+///     glossary(entries, Flavor::Gfm),
+///     "<a id=\"crate\"></a>**crate**: A compilation unit.\n\n\
+///      <a id=\"msrv\"></a>**MSRV**: Minimum supported Rust version."
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#definition-lists>
+pub fn glossary<T, D>(entries: impl IntoIterator<Item = (T, D)>, flavor: Flavor) -> String
+where
+    T: AsRef<str>,
+    D: AsRef<str>,
+{
+    let mut entries: Vec<(T, D)> = entries.into_iter().collect();
+    let mut slugger = Slugger::default();
+
+    entries.sort_by_cached_key(|(term, _)| term.as_ref().to_lowercase());
+    entries
+        .iter()
+        .map(|(term, definition)| {
+            let (term, definition) = (term.as_ref(), definition.as_ref().trim());
+            let anchor = format!("<a id=\"{}\"></a>", slugger.slug(term));
+
+            if flavor.supports_definition_lists() {
+                let definition = definition
+                    .lines()
+                    .map(|line| if line.is_empty() { String::new() } else { format!("    {line}") })
+                    .collect::<Vec<String>>()
+                    .join(&LF.to_string());
+
+                format!("{anchor}{term}{LF}:{}", definition.get(1..).unwrap_or_default())
+            } else {
+                format!("{anchor}**{term}**: {definition}")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(&LF.to_string().repeat(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glossary_indents_multi_paragraph_definitions() {
+        let entries = vec![("Edition".to_owned(), "A set of language changes.\n\nSee 2021.")];
+
+        assert_eq!(
+            glossary(entries, Flavor::MkDocs),
+            "<a id=\"edition\"></a>Edition\n:   A set of language changes.\n\n    See 2021."
+        );
+    }
+
+    #[test]
+    fn glossary_deduplicates_anchors() {
+        let markdown = glossary([("C++", "A language."), ("C", "Another language.")], Flavor::Gfm);
+
+        assert!(markdown.contains("<a id=\"c\"></a>**C**"));
+        assert!(markdown.contains("<a id=\"c-1\"></a>**C++**"));
+    }
+}
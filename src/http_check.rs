@@ -0,0 +1,216 @@
+//! Validation of the external links in a document over HTTP.
+
+use futures_util::stream::{self, StreamExt};
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use reqwest::{redirect, Client, Method, StatusCode};
+use std::time::Duration;
+
+/// The outcome of checking an external link.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// The URL responded with a success status.
+    Ok(u16),
+    /// The URL redirected to another URL which responded with a success status.
+    Redirected {
+        /// The status of the final response.
+        status: u16,
+        /// The URL which was redirected to.
+        location: String,
+    },
+    /// The URL (or the URL it redirected to) responded with an error status.
+    Broken(u16),
+    /// The request failed, e.g. because the host couldn't be resolved or the request timed out.
+    Failed(String),
+    /// The URL matched the allowlist and wasn't requested.
+    Skipped,
+}
+
+impl Outcome {
+    /// Whether the link should fail a docs pipeline.
+    pub fn is_broken(&self) -> bool {
+        matches!(self, Outcome::Broken(_) | Outcome::Failed(_))
+    }
+}
+
+/// The outcome of checking the external link on a line of a document.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LinkStatus {
+    /// The 1-based line the link is on.
+    pub line: usize,
+    /// The URL of the link.
+    pub url: String,
+    /// The outcome of checking the link.
+    pub outcome: Outcome,
+}
+
+/// An asynchronous checker of the external (`http` and `https`) links in a document.
+///
+/// Links are requested with `HEAD`, falling back to `GET` for servers which don't support it,
+/// with at most a limited number of requests in flight at once. URLs beginning with a prefix on
+/// the allowlist aren't requested, e.g. for hosts which reject bots.
+///
+/// Examples
+/// ========
+/// ```no_run
+/// use md_writer::http_check::LinkChecker;
+///
+/// # async fn example() {
+/// let checker = LinkChecker::new().concurrency(4).allow("https://twitter.com/");
+/// let statuses = checker.check("[Docs](https://docs.rs/md-writer)").await;
+///
+/// assert!(statuses.iter().all(|status| !status.outcome.is_broken()));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinkChecker {
+    client: Client,
+    concurrency: usize,
+    allowlist: Vec<String>,
+}
+
+impl Default for LinkChecker {
+    fn default() -> LinkChecker {
+        LinkChecker::new()
+    }
+}
+
+impl LinkChecker {
+    /// Create a checker making up to 8 concurrent requests, following up to 10 redirects and
+    /// timing requests out after 30 seconds.
+    pub fn new() -> LinkChecker {
+        let client = Client::builder()
+            .redirect(redirect::Policy::limited(10))
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("md-writer/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+
+        LinkChecker::with_client(client)
+    }
+
+    /// Create a checker using the provided client, e.g. to configure proxies or timeouts.
+    pub fn with_client(client: Client) -> LinkChecker {
+        LinkChecker {
+            client,
+            concurrency: 8,
+            allowlist: Vec::new(),
+        }
+    }
+
+    /// Set the maximum number of requests in flight at once.
+    pub fn concurrency(self, concurrency: usize) -> LinkChecker {
+        LinkChecker {
+            concurrency: concurrency.max(1),
+            ..self
+        }
+    }
+
+    /// Skip URLs beginning with the provided prefix.
+    pub fn allow(mut self, prefix: impl Into<String>) -> LinkChecker {
+        self.allowlist.push(prefix.into());
+        self
+    }
+
+    /// Check every external link and image in a Markdown document, returning their statuses in
+    /// document order.
+    pub async fn check(&self, markdown: &str) -> Vec<LinkStatus> {
+        let links = external_links(markdown);
+
+        stream::iter(links)
+            .map(|(line, url)| async move {
+                let outcome = if self.allowlist.iter().any(|prefix| url.starts_with(prefix)) {
+                    Outcome::Skipped
+                } else {
+                    self.request(&url).await
+                };
+
+                LinkStatus { line, url, outcome }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    async fn request(&self, url: &str) -> Outcome {
+        let mut response = self.client.request(Method::HEAD, url).send().await;
+
+        if let Ok(head) = &response {
+            let status = head.status();
+
+            if status == StatusCode::METHOD_NOT_ALLOWED || status == StatusCode::NOT_IMPLEMENTED {
+                response = self.client.get(url).send().await;
+            }
+        }
+
+        match response {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let location = response.url().as_str();
+
+                if !response.status().is_success() {
+                    Outcome::Broken(status)
+                } else if location.trim_end_matches('/') != url.trim_end_matches('/') {
+                    Outcome::Redirected { status, location: location.to_owned() }
+                } else {
+                    Outcome::Ok(status)
+                }
+            }
+            Err(error) => Outcome::Failed(error.to_string()),
+        }
+    }
+}
+
+/// The line and URL of every `http` and `https` link and image in a Markdown document.
+fn external_links(markdown: &str) -> Vec<(usize, String)> {
+    Parser::new_ext(markdown, Options::ENABLE_TABLES)
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                Some((range, dest_url))
+            }
+            _ => None,
+        })
+        .filter(|(_, url)| url.starts_with("http://") || url.starts_with("https://"))
+        .map(|(range, url)| (markdown[..range.start].matches('\n').count() + 1, url.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn external_links_skips_relative_links() {
+        let markdown = "[a](https://a.example)\n[b](docs/b.md) ![c](http://c.example/c.png)";
+
+        assert_eq!(
+            external_links(markdown),
+            [(1, "https://a.example".to_owned()), (2, "http://c.example/c.png".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_reports_error_statuses_and_skips_the_allowlist() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(1) {
+                let mut stream = stream.unwrap();
+                let mut buffer = [0; 1024];
+
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n");
+            }
+        });
+
+        let markdown = format!("[gone](http://{address}/gone)\n[ok](https://allowed.example/)");
+        let checker = LinkChecker::new().allow("https://allowed.example/");
+        let statuses = checker.check(&markdown).await;
+
+        assert_eq!(statuses[0].outcome, Outcome::Broken(404));
+        assert_eq!(statuses[1].outcome, Outcome::Skipped);
+    }
+}
@@ -0,0 +1,116 @@
+//! Images with explicit dimensions.
+
+use crate::escape::escape_html_attribute;
+use crate::flavor::Flavor;
+use crate::image;
+
+/// The dimensions of an image, each a number of pixels (`"200"` or `"200px"`) or a percentage
+/// (`"50%"`). A missing dimension is scaled to keep the aspect ratio of the image.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ImageSize<'a> {
+    /// The width of the image.
+    pub width: Option<&'a str>,
+    /// The height of the image.
+    pub height: Option<&'a str>,
+}
+
+impl<'a> ImageSize<'a> {
+    /// Size an image by its width.
+    pub fn width(width: &'a str) -> ImageSize<'a> {
+        ImageSize { width: Some(width), height: None }
+    }
+
+    /// Size an image by its height.
+    pub fn height(height: &'a str) -> ImageSize<'a> {
+        ImageSize { width: None, height: Some(height) }
+    }
+}
+
+/// Create an image with explicit dimensions.
+///
+/// Flavors which support attributes get them (`{width=50%}`), Obsidian gets its `|200x100`
+/// alt text suffix for pixel dimensions, and everything else gets an HTML `<img>` element.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, image::{sized_image, ImageSize}};
+///
+/// let size = ImageSize::width("50%");
+///
+/// assert_eq!(
+///     sized_image("Logo", "logo.png", size, Flavor::Pandoc),
+///     "![Logo](logo.png){width=50%}"
+/// );
+/// assert_eq!(
+///     sized_image("Logo", "logo.png", size, Flavor::Gfm),
+///     "<img src=\"logo.png\" alt=\"Logo\" width=\"50%\">"
+/// );
+/// assert_eq!(
+///     sized_image("Logo", "logo.png", ImageSize::width("200px"), Flavor::Obsidian),
+///     "![Logo|200](logo.png)"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#extension-link_attributes>
+/// - <https://help.obsidian.md/Linking+notes+and+files/Embed+files#Embed+an+image+in+a+note>
+pub fn sized_image(alt: &str, source: &str, size: ImageSize, flavor: Flavor) -> String {
+    let dimensions = [("width", size.width), ("height", size.height)];
+    let pixels = |dimension: &str| {
+        let number = dimension.strip_suffix("px").unwrap_or(dimension);
+
+        number.parse::<u32>().ok().map(|_| number.to_owned())
+    };
+
+    if flavor.supports_attributes() {
+        let attributes: Vec<String> = dimensions
+            .iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{name}={value}")))
+            .collect();
+
+        return format!("{}{{{}}}", image(alt, source, None), attributes.join(" "));
+    }
+
+    if flavor == Flavor::Obsidian {
+        let width = size.width.map(pixels);
+        let height = size.height.map(pixels);
+
+        match (width, height) {
+            (Some(Some(width)), Some(Some(height))) => {
+                return format!("![{alt}|{width}x{height}]({source})");
+            }
+            (Some(Some(width)), None) => return format!("![{alt}|{width}]({source})"),
+            _ => {}
+        }
+    }
+
+    let attributes: String = dimensions
+        .iter()
+        .filter_map(|(name, value)| {
+            value.map(|value| format!(" {name}=\"{}\"", pixels(value).unwrap_or(value.to_owned())))
+        })
+        .collect();
+
+    format!(
+        "<img src=\"{}\" alt=\"{}\"{attributes}>",
+        escape_html_attribute(source),
+        escape_html_attribute(alt)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sized_image_falls_back_to_html_for_obsidian_percentages() {
+        let size = ImageSize { width: Some("120px"), height: Some("50%") };
+
+        assert_eq!(
+            sized_image("A \"chart\"", "chart.svg", size, Flavor::Obsidian),
+            "<img src=\"chart.svg\" alt=\"A &quot;chart&quot;\" width=\"120\" height=\"50%\">"
+        );
+    }
+}
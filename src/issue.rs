@@ -0,0 +1,298 @@
+//! Bodies of GitHub issues and pull requests, e.g. for bots and error reporters.
+
+use crate::diagnostics::collapsed;
+use crate::flavor::Flavor;
+use crate::labels::Labels;
+use crate::list::{fancy_ordered_list, task_list, Numbering, TaskState};
+use crate::options::HeadingStyle;
+use crate::table::pipe_table;
+use crate::{fenced_code_block, LF};
+
+/// The sections shared by issue and pull request bodies.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+struct Sections {
+    summary: Option<String>,
+    checklist: Vec<(TaskState, String)>,
+    logs: Option<String>,
+    heading_style: HeadingStyle,
+    labels: Labels,
+}
+
+impl Sections {
+    fn checklist(&self) -> Option<String> {
+        let items: Vec<(TaskState, &str)> = self
+            .checklist
+            .iter()
+            .map(|(state, item)| (*state, item.as_str()))
+            .collect();
+
+        (!items.is_empty()).then(|| task_list(&items, Flavor::Gfm))
+    }
+
+    fn logs(&self) -> Option<String> {
+        self.logs
+            .as_ref()
+            .map(|logs| collapsed(&self.labels.logs, &fenced_code_block(logs, Some("text"))))
+    }
+
+    /// Join the non-empty sections, each under a level 2 heading unless its heading is empty.
+    fn render<const N: usize>(&self, sections: [(&str, Option<String>); N]) -> String {
+        sections
+            .into_iter()
+            .filter_map(|(heading, body)| {
+                let body = body?;
+
+                Some(if heading.is_empty() {
+                    body
+                } else {
+                    format!("{}{LF}{LF}{body}", self.heading_style.header(2, heading))
+                })
+            })
+            .collect::<Vec<String>>()
+            .join(&LF.to_string().repeat(2))
+    }
+}
+
+/// A builder of the body of an issue, e.g. a bug report filed by an error reporter.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::issue::IssueBody;
+///
+/// let body = IssueBody::new()
+///     .summary("The export crashes on empty projects.")
+///     .step("Create a project")
+///     .step("Run `export`")
+///     .environment("OS", "Linux")
+///     .checklist_item("Searched for duplicates", true)
+///     .logs("thread 'main' panicked");
+///
+/// assert_eq!(
+///     body.render(),
+///     "Summary\n-------\n\nThe export crashes on empty projects.\n\n\
+///      Steps to Reproduce\n------------------\n\n1. Create a project\n2. Run `export`\n\n\
+///      Environment\n-----------\n\n| Name | Value |\n| --- | --- |\n| OS | Linux |\n\n\
+///      Checklist\n---------\n\n- [x] Searched for duplicates\n\n\
+///      <details>\n<summary>Logs</summary>\n\n```text\nthread 'main' panicked\n```\n\n</details>"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IssueBody {
+    sections: Sections,
+    steps: Vec<String>,
+    environment: Vec<Vec<String>>,
+}
+
+impl IssueBody {
+    /// Create an empty issue body.
+    pub fn new() -> IssueBody {
+        IssueBody::default()
+    }
+
+    /// Set the summary of the issue.
+    pub fn summary(mut self, summary: &str) -> IssueBody {
+        self.sections.summary = Some(summary.to_owned());
+        self
+    }
+
+    /// Add a step to reproduce the issue.
+    pub fn step(mut self, step: &str) -> IssueBody {
+        self.steps.push(step.to_owned());
+        self
+    }
+
+    /// Add a detail of the environment the issue occurred in, e.g. the OS or a version.
+    pub fn environment(mut self, name: &str, value: &str) -> IssueBody {
+        self.environment.push(vec![name.to_owned(), value.to_owned()]);
+        self
+    }
+
+    /// Add an item to the checklist, e.g. a step of triage.
+    pub fn checklist_item(mut self, item: &str, done: bool) -> IssueBody {
+        let state = if done { TaskState::Done } else { TaskState::Todo };
+
+        self.sections.checklist.push((state, item.to_owned()));
+        self
+    }
+
+    /// Set the logs, which are collapsed so they don't overwhelm the issue.
+    pub fn logs(mut self, logs: &str) -> IssueBody {
+        self.sections.logs = Some(logs.to_owned());
+        self
+    }
+
+    /// Set the style of the sections' headings, setext by default.
+    pub fn heading_style(mut self, heading_style: HeadingStyle) -> IssueBody {
+        self.sections.heading_style = heading_style;
+        self
+    }
+
+    /// Set the labels the titles of the sections are taken from.
+    pub fn with_labels(mut self, labels: Labels) -> IssueBody {
+        self.sections.labels = labels;
+        self
+    }
+
+    /// Render the body, omitting empty sections.
+    pub fn render(&self) -> String {
+        let steps: Vec<&str> = self.steps.iter().map(String::as_str).collect();
+        let labels = &self.sections.labels;
+        let environment = (!self.environment.is_empty()).then(|| {
+            pipe_table(&[labels.name.clone(), labels.value.clone()], &self.environment)
+        });
+
+        self.sections.render([
+            (&labels.summary, self.sections.summary.clone()),
+            (
+                &labels.steps_to_reproduce,
+                (!steps.is_empty())
+                    .then(|| fancy_ordered_list(&steps, Numbering::Decimal, Flavor::Gfm)),
+            ),
+            (&labels.environment, environment),
+            (&labels.checklist, self.sections.checklist()),
+            ("", self.sections.logs()),
+        ])
+    }
+}
+
+/// A builder of the body of a pull request, e.g. one opened by a dependency update bot.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::issue::PullRequestBody;
+///
+/// let body = PullRequestBody::new()
+///     .summary("Bump serde to 1.0.200.")
+///     .change("Update `Cargo.toml`")
+///     .checklist_item("Tests pass", false);
+///
+/// assert_eq!(
+///     body.render(),
+///     "Summary\n-------\n\nBump serde to 1.0.200.\n\n\
+///      Changes\n-------\n\n- Update `Cargo.toml`\n\n\
+///      Checklist\n---------\n\n- [ ] Tests pass"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PullRequestBody {
+    sections: Sections,
+    changes: Vec<String>,
+}
+
+impl PullRequestBody {
+    /// Create an empty pull request body.
+    pub fn new() -> PullRequestBody {
+        PullRequestBody::default()
+    }
+
+    /// Set the summary of the pull request.
+    pub fn summary(mut self, summary: &str) -> PullRequestBody {
+        self.sections.summary = Some(summary.to_owned());
+        self
+    }
+
+    /// Add a change made by the pull request.
+    pub fn change(mut self, change: &str) -> PullRequestBody {
+        self.changes.push(change.to_owned());
+        self
+    }
+
+    /// Add an item to the checklist, e.g. a step of review.
+    pub fn checklist_item(mut self, item: &str, done: bool) -> PullRequestBody {
+        let state = if done { TaskState::Done } else { TaskState::Todo };
+
+        self.sections.checklist.push((state, item.to_owned()));
+        self
+    }
+
+    /// Set the logs, which are collapsed so they don't overwhelm the pull request.
+    pub fn logs(mut self, logs: &str) -> PullRequestBody {
+        self.sections.logs = Some(logs.to_owned());
+        self
+    }
+
+    /// Set the style of the sections' headings, setext by default.
+    pub fn heading_style(mut self, heading_style: HeadingStyle) -> PullRequestBody {
+        self.sections.heading_style = heading_style;
+        self
+    }
+
+    /// Set the labels the titles of the sections are taken from.
+    pub fn with_labels(mut self, labels: Labels) -> PullRequestBody {
+        self.sections.labels = labels;
+        self
+    }
+
+    /// Render the body, omitting empty sections.
+    pub fn render(&self) -> String {
+        let changes = (!self.changes.is_empty()).then(|| {
+            self.changes
+                .iter()
+                .map(|change| format!("- {change}"))
+                .collect::<Vec<String>>()
+                .join(&LF.to_string())
+        });
+
+        let labels = &self.sections.labels;
+
+        self.sections.render([
+            (&labels.summary, self.sections.summary.clone()),
+            (&labels.changes, changes),
+            (&labels.checklist, self.sections.checklist()),
+            ("", self.sections.logs()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_omits_empty_sections() {
+        assert_eq!(IssueBody::new().render(), "");
+        assert_eq!(
+            IssueBody::new().environment("Version", "1.2.3").render(),
+            "Environment\n-----------\n\n| Name | Value |\n| --- | --- |\n| Version | 1.2.3 |"
+        );
+    }
+
+    #[test]
+    fn titles_come_from_the_labels() {
+        let labels = Labels {
+            steps_to_reproduce: "Reproduktion".into(),
+            environment: "Umgebung".into(),
+            value: "Wert".into(),
+            logs: "Protokolle".into(),
+            ..Labels::default()
+        };
+        let body = IssueBody::new()
+            .step("Run `export`")
+            .environment("OS", "Linux")
+            .logs("panicked")
+            .heading_style(HeadingStyle::Atx)
+            .with_labels(labels);
+
+        assert_eq!(
+            body.render(),
+            "## Reproduktion\n\n1. Run `export`\n\n\
+             ## Umgebung\n\n| Name | Wert |\n| --- | --- |\n| OS | Linux |\n\n\
+             <details>\n<summary>Protokolle</summary>\n\n```text\npanicked\n```\n\n</details>"
+        );
+    }
+
+    #[test]
+    fn headings_follow_the_heading_style() {
+        let body = PullRequestBody::new()
+            .summary("Bump serde.")
+            .change("Update `Cargo.toml`")
+            .heading_style(HeadingStyle::Atx);
+
+        assert_eq!(
+            body.render(),
+            "## Summary\n\nBump serde.\n\n## Changes\n\n- Update `Cargo.toml`"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! GitHub Actions job summaries.
+
+use crate::LF;
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// The maximum size of the summary of a step in bytes, beyond which GitHub rejects it.
+pub const SIZE_LIMIT: u64 = 1024 * 1024;
+
+/// The note appended to a summary in place of the content which didn't fit.
+const TRUNCATION_NOTE: &str = "**Note:** The rest of this summary was truncated.";
+
+/// A writer of the summary of a GitHub Actions step, which is shown on the summary page of the
+/// workflow run.
+///
+/// Markdown is appended to the summary file, keeping it within [`SIZE_LIMIT`]: content which
+/// doesn't fit is truncated and replaced by a note saying so, after which further content is
+/// dropped.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::job_summary::JobSummary;
+///
+/// let path = std::env::temp_dir().join("md-writer-job-summary-example.md");
+/// let mut summary = JobSummary::create(&path)?;
+///
+/// assert!(summary.append(&md_writer::h3("Benchmarks"))?);
+/// assert_eq!(std::fs::read_to_string(&path)?, "### Benchmarks\n\n");
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/actions/writing-workflows/choosing-what-your-workflow-does/workflow-commands-for-github-actions#adding-a-job-summary>
+#[derive(Debug)]
+pub struct JobSummary {
+    file: File,
+    size: u64,
+    limit: u64,
+    truncated: bool,
+}
+
+impl JobSummary {
+    /// Open the summary file named by the `GITHUB_STEP_SUMMARY` environment variable.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error of kind [`io::ErrorKind::NotFound`] when not running in GitHub Actions,
+    /// or if the file cannot be opened.
+    pub fn from_env() -> io::Result<JobSummary> {
+        let path = env::var_os("GITHUB_STEP_SUMMARY").ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "GITHUB_STEP_SUMMARY is not set")
+        })?;
+
+        JobSummary::create(path)
+    }
+
+    /// Open the summary file at the provided path for appending, creating it if it doesn't
+    /// exist. Content already in the file counts toward the size limit.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error if the file cannot be opened.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<JobSummary> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+
+        Ok(JobSummary { file, size, limit: SIZE_LIMIT, truncated: false })
+    }
+
+    /// Set the maximum size of the summary in bytes, lowering [`SIZE_LIMIT`] e.g. to leave
+    /// room for other steps' tools writing to the same file.
+    pub fn limit(self, limit: u64) -> JobSummary {
+        JobSummary { limit: limit.min(SIZE_LIMIT), ..self }
+    }
+
+    /// Append a block of Markdown to the summary, followed by a blank line.
+    ///
+    /// Returns whether the block was written in full.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error if the file cannot be written to.
+    pub fn append(&mut self, markdown: &str) -> io::Result<bool> {
+        if self.truncated {
+            return Ok(false);
+        }
+
+        let block = format!("{}{LF}{LF}", markdown.trim_end());
+        let remaining = self.limit.saturating_sub(self.size);
+
+        if block.len() as u64 <= remaining {
+            return self.write(&block).map(|_| true);
+        }
+
+        let note = format!("{LF}{LF}{TRUNCATION_NOTE}{LF}");
+        let mut end = (remaining as usize).saturating_sub(note.len()).min(block.len());
+
+        while !block.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.truncated = true;
+        self.write(&format!("{}{note}", &block[..end]))?;
+
+        Ok(false)
+    }
+
+    fn write(&mut self, text: &str) -> io::Result<()> {
+        self.file.write_all(text.as_bytes())?;
+        self.file.flush()?;
+        self.size += text.len() as u64;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn append_truncates_at_the_limit() {
+        let path = env::temp_dir().join("md-writer-job-summary-truncation-test.md");
+        let _ = fs::remove_file(&path);
+        let mut summary = JobSummary::create(&path).unwrap().limit(80);
+
+        assert!(summary.append("a").unwrap());
+        assert!(!summary.append(&"é".repeat(100)).unwrap());
+        assert!(!summary.append("dropped").unwrap());
+
+        let contents = fs::read_to_string(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+        assert!(contents.len() <= 80);
+        assert!(contents.starts_with("a\n\néé"));
+        assert!(contents.ends_with(&format!("é\n\n{TRUNCATION_NOTE}\n")));
+    }
+}
@@ -0,0 +1,116 @@
+//! Rendering of arbitrary JSON values, e.g. API responses, as Markdown.
+
+use crate::escape::{escape_text, EscapeMode};
+use crate::list::BulletList;
+use crate::table::{json_cell, pipe_table, table_from_values};
+use crate::{bold, LF};
+use serde_json::{Map, Value};
+
+/// Render a JSON value as Markdown.
+///
+/// - Objects become a Key | Value table of their scalar entries, escaped like strings, followed
+///   by a section per nested entry: the key in bold and the rendered value below it.
+/// - Arrays of objects become a table with a column per key.
+/// - Any other array becomes a bullet list of its rendered items.
+/// - Strings are escaped and any other scalar is rendered as JSON.
+///
+/// Entries are rendered in the order of the object, which is the order of the source when
+/// parsed with serde_json's `preserve_order` feature.
+///
+/// Examples
+/// ========
+/// ```
+/// use serde_json::json;
+///
+/// let response = json!({
+///     "id": 7,
+///     "name": "md-writer",
+///     "tags": ["markdown", "docs"],
+///     "owners": [{ "login": "ana", "admin": true }],
+/// });
+///
+/// assert_eq!(
+///     md_writer::json::value_to_markdown(&response),
+///     "| Key | Value |\n| --- | --- |\n| id | 7 |\n| name | md-writer |\n\n\
+///      **tags**\n\n- markdown\n- docs\n\n\
+///      **owners**\n\n| login | admin |\n| --- | --- |\n| ana | true |"
+/// );
+/// ```
+pub fn value_to_markdown(value: &Value) -> String {
+    match value {
+        Value::Object(object) => object_to_markdown(object),
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object) => {
+            table_from_values(items)
+        }
+        Value::Array(items) => items
+            .iter()
+            .fold(BulletList::new(), |list, item| list.item(&value_to_markdown(item)))
+            .render(),
+        Value::String(string) => escape_text(string, EscapeMode::default()).into_owned(),
+        scalar => scalar.to_string(),
+    }
+}
+
+fn object_to_markdown(object: &Map<String, Value>) -> String {
+    let (nested, scalar): (Vec<_>, Vec<_>) =
+        object.iter().partition(|(_, value)| is_nested(value));
+    let mut blocks = Vec::new();
+
+    if !scalar.is_empty() {
+        let headers = ["Key".to_owned(), "Value".to_owned()];
+        let rows: Vec<Vec<String>> = scalar
+            .iter()
+            .map(|(key, value)| {
+                [key.as_str(), &json_cell(value)]
+                    .map(|cell| escape_text(cell, EscapeMode::default()).into_owned())
+                    .to_vec()
+            })
+            .collect();
+
+        blocks.push(pipe_table(&headers, &rows));
+    }
+
+    for (key, value) in nested {
+        blocks.push(bold(&escape_text(key, EscapeMode::default())));
+        blocks.push(value_to_markdown(value));
+    }
+
+    blocks.join(&LF.to_string().repeat(2))
+}
+
+/// Whether a value is rendered as a block of its own rather than inline in a table cell.
+fn is_nested(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => !object.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn nested_values_render_recursively() {
+        let value = json!([{ "a": 1 }, [1, { "b": null, "c": [] }], "*x*"]);
+
+        assert_eq!(
+            value_to_markdown(&value),
+            "- | Key | Value |\n  | --- | --- |\n  | a | 1 |\n\
+             - - 1\n  - | Key | Value |\n    | --- | --- |\n    | b |  |\n    | c | \\[\\] |\n\
+             - \\*x\\*"
+        );
+    }
+
+    #[test]
+    fn object_keys_and_values_are_escaped() {
+        let value = json!({ "<b>": "*bold*", "link": "[a](b)" });
+
+        assert_eq!(
+            value_to_markdown(&value),
+            "| Key | Value |\n| --- | --- |\n| \\<b> | \\*bold\\* |\n| link | \\[a\\](b) |"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Documentation of JSON Schemas, e.g. of configuration files.
+
+use crate::slug::Slugger;
+use crate::table::pipe_table;
+use crate::options::HeadingStyle;
+use crate::{code_span, LF};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A section documenting an object schema: the root, a definition or a nested object.
+struct Section<'a> {
+    key: String,
+    path: String,
+    heading: String,
+    schema: &'a Value,
+}
+
+/// Render a JSON Schema as tables of the properties of its objects.
+///
+/// The root schema gets a level 2 heading (its title, or "Schema") and every definition (in
+/// `$defs` or `definitions`) and nested object a level 3 heading, so that types can link to
+/// them. Tables list the name, type, whether it's required, default and description of every
+/// property.
+///
+/// Examples
+/// ========
+/// ```
+/// let schema = serde_json::json!({
+///     "title": "Config",
+///     "type": "object",
+///     "required": ["server"],
+///     "properties": {
+///         "server": {
+///             "type": "object",
+///             "properties": { "port": { "type": "integer", "default": 8080 } }
+///         },
+///         "users": { "type": "array", "items": { "$ref": "#/$defs/User" } }
+///     },
+///     "$defs": {
+///         "User": { "type": "object", "properties": { "name": { "type": "string" } } }
+///     }
+/// });
+/// let markdown = md_writer::json_schema::schema_tables(&schema);
+///
+/// assert!(markdown.contains("| `server` | [object](#server) | Yes |  |  |"));
+/// assert!(markdown.contains("| `users` | array of [User](#user) | No |  |  |"));
+/// assert!(markdown.contains("### `server`\n\n| Name | Type | Required | Default |"));
+/// assert!(markdown.contains("| `port` | integer | No | `8080` |  |"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://json-schema.org/understanding-json-schema/reference/object>
+pub fn schema_tables(schema: &Value) -> String {
+    schema_tables_with_style(schema, HeadingStyle::default())
+}
+
+/// Render a JSON Schema as tables like [`schema_tables`], with headings in the provided style.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{json_schema::schema_tables_with_style, options::HeadingStyle};
+///
+/// let schema = serde_json::json!({ "title": "Config", "type": "object" });
+///
+/// assert!(schema_tables_with_style(&schema, HeadingStyle::Atx).starts_with("## Config\n\n"));
+/// ```
+pub fn schema_tables_with_style(schema: &Value, heading_style: HeadingStyle) -> String {
+    let title = schema["title"].as_str().unwrap_or("Schema");
+    let mut sections = vec![Section {
+        key: String::new(),
+        path: String::new(),
+        heading: title.to_owned(),
+        schema,
+    }];
+
+    for keyword in ["$defs", "definitions"] {
+        for (name, definition) in schema[keyword].as_object().into_iter().flatten() {
+            sections.push(Section {
+                key: format!("#/{keyword}/{name}"),
+                path: name.clone(),
+                heading: name.clone(),
+                schema: definition,
+            });
+        }
+    }
+
+    let mut index = 0;
+
+    while index < sections.len() {
+        let parent = &sections[index];
+        let children: Vec<Section> = properties(parent.schema)
+            .filter_map(|(name, property)| {
+                let nested = nested(property)?;
+                let suffix = if nested == property { "" } else { "[]" };
+                let path = if parent.path.is_empty() {
+                    format!("{name}{suffix}")
+                } else {
+                    format!("{}.{name}{suffix}", parent.path)
+                };
+
+                Some(Section {
+                    key: format!("{}/{name}{suffix}", parent.key),
+                    heading: code_span(&path),
+                    path,
+                    schema: nested,
+                })
+            })
+            .collect();
+
+        sections.extend(children);
+
+        index += 1;
+    }
+
+    let mut slugger = Slugger::default();
+    let anchors: HashMap<&str, String> = sections
+        .iter()
+        .map(|section| (section.key.as_str(), slugger.slug(&section.heading)))
+        .collect();
+
+    sections
+        .iter()
+        .flat_map(|section| {
+            let level = if section.key.is_empty() { 2 } else { 3 };
+            let heading = heading_style.header(level, &section.heading);
+            let description = section.schema["description"].as_str().map(str::to_owned);
+
+            [Some(heading), description, Some(table(section, &anchors))].into_iter().flatten()
+        })
+        .collect::<Vec<String>>()
+        .join(&LF.to_string().repeat(2))
+}
+
+fn properties(schema: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    schema["properties"].as_object().into_iter().flatten()
+}
+
+/// The object schema nested in a property (or in its array items) which gets its own section.
+fn nested(property: &Value) -> Option<&Value> {
+    if property["properties"].is_object() {
+        Some(property)
+    } else if property["items"]["properties"].is_object() {
+        Some(&property["items"])
+    } else {
+        None
+    }
+}
+
+fn table(section: &Section, anchors: &HashMap<&str, String>) -> String {
+    let required: Vec<&str> = section.schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let headers = ["Name", "Type", "Required", "Default", "Description"].map(String::from);
+    let rows: Vec<Vec<String>> = properties(section.schema)
+        .map(|(name, property)| {
+            let key = format!("{}/{name}", section.key);
+            let mut description = property["description"].as_str().unwrap_or_default().to_owned();
+
+            if let Some(values) = property["enum"].as_array() {
+                let values: Vec<String> =
+                    values.iter().map(|value| code_span(&value.to_string())).collect();
+
+                description = format!("{description} One of {}.", values.join(", "));
+                description = description.trim().to_owned();
+            }
+
+            vec![
+                code_span(name),
+                type_name(property, &key, anchors),
+                if required.contains(&name.as_str()) { "Yes" } else { "No" }.to_owned(),
+                property
+                    .get("default")
+                    .map_or_else(String::new, |default| code_span(&default.to_string())),
+                description,
+            ]
+        })
+        .collect();
+
+    pipe_table(&headers, &rows)
+}
+
+/// The type of a property, linked to the section documenting it if there is one.
+fn type_name(property: &Value, key: &str, anchors: &HashMap<&str, String>) -> String {
+    let link = |name: &str, key: &str| match anchors.get(key) {
+        Some(anchor) => format!("[{name}](#{anchor})"),
+        None => name.to_owned(),
+    };
+
+    if let Some(reference) = property["$ref"].as_str() {
+        return link(reference.rsplit('/').next().unwrap_or(reference), reference);
+    }
+
+    match &property["type"] {
+        Value::String(name) if name == "object" => link(name, key),
+        Value::String(name) if name == "array" => {
+            format!("array of {}", type_name(&property["items"], &format!("{key}[]"), anchors))
+        }
+        Value::String(name) => name.clone(),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<&str>>()
+            .join(" or "),
+        _ if property["properties"].is_object() => link("object", key),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn schema_tables_nest_definitions_and_list_enum_values() {
+        let schema = json!({
+            "definitions": {
+                "User": {
+                    "properties": {
+                        "address": { "type": "object", "properties": { "city": {} } },
+                        "role": { "description": "The role.", "enum": ["admin", "guest"] }
+                    }
+                }
+            }
+        });
+        let markdown = schema_tables(&schema);
+
+        assert!(markdown.contains("| `address` | [object](#useraddress) | No |  |  |"));
+        assert!(markdown.contains("| The role. One of `\"admin\"`, `\"guest\"`. |"));
+        assert!(markdown.contains("### `User.address`\n\n"));
+        assert!(markdown.ends_with("| `city` |  | No |  |  |"));
+    }
+}
@@ -0,0 +1,280 @@
+//! Test reports from JUnit XML, as written by most test runners and read by most CI systems.
+
+use crate::escape::{escape_text, EscapeMode};
+use crate::table::pipe_table;
+use crate::xml::{nodes, Node};
+use crate::{fenced_code_block, h3, h4, LF};
+
+/// The outcome of a test case.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TestOutcome {
+    /// The test passed.
+    Passed,
+    /// An assertion of the test failed.
+    Failed {
+        /// The failure message, if any.
+        message: String,
+        /// The failure details, e.g. a stack trace.
+        details: String,
+    },
+    /// The test errored, e.g. by panicking outside an assertion.
+    Errored {
+        /// The error message, if any.
+        message: String,
+        /// The error details, e.g. a stack trace.
+        details: String,
+    },
+    /// The test was skipped.
+    Skipped,
+}
+
+/// A test case.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestCase {
+    /// The name of the test.
+    pub name: String,
+    /// The class (or module) of the test, if any.
+    pub classname: String,
+    /// The duration of the test in seconds.
+    pub time: f64,
+    /// The outcome of the test.
+    pub outcome: TestOutcome,
+}
+
+/// A suite of test cases.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestSuite {
+    /// The name of the suite.
+    pub name: String,
+    /// The test cases of the suite.
+    pub cases: Vec<TestCase>,
+}
+
+impl TestSuite {
+    fn count(&self, predicate: impl Fn(&TestOutcome) -> bool) -> usize {
+        self.cases.iter().filter(|case| predicate(&case.outcome)).count()
+    }
+
+    fn time(&self) -> f64 {
+        self.cases.iter().map(|case| case.time).sum()
+    }
+}
+
+/// A report of the test suites of a run.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::junit::TestReport;
+///
+/// let xml = r#"<testsuite name="unit">
+///     <testcase name="adds" classname="math" time="0.01"/>
+///     <testcase name="divides" classname="math" time="0.02">
+///         <failure message="division by zero">panicked at src/math.rs:7:5</failure>
+///     </testcase>
+/// </testsuite>"#;
+/// let report = TestReport::from_junit(xml);
+/// let markdown = report.render();
+///
+/// assert!(report.has_failures());
+/// assert!(markdown.starts_with(
+///     "| Suite | Tests | Passed | Failed | Skipped | Time |\n\
+///      | --- | --- | --- | --- | --- | --- |\n\
+///      | unit | 2 | 1 | 1 | 0 | 0.03s |"
+/// ));
+/// assert!(markdown.contains("#### ❌ math::divides\n\ndivision by zero\n\n```text\n"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TestReport {
+    /// The test suites, in the order they were reported.
+    pub suites: Vec<TestSuite>,
+}
+
+impl TestReport {
+    /// Import a JUnit XML report with either a `<testsuites>` or a `<testsuite>` root.
+    ///
+    /// Reference
+    /// =========
+    /// - <https://github.com/testmoapp/junitxml>
+    pub fn from_junit(xml: &str) -> TestReport {
+        let mut suites = Vec::new();
+        let mut case: Option<TestCase> = None;
+        let mut text = None;
+
+        for node in nodes(xml) {
+            match &node {
+                Node::Start { name: "testsuite", .. } => suites.push(TestSuite {
+                    name: node.attribute("name").unwrap_or_default().to_owned(),
+                    cases: Vec::new(),
+                }),
+                Node::Start { name: "testcase", empty, .. } => {
+                    let time = node.attribute("time").and_then(|time| time.parse().ok());
+                    let test = TestCase {
+                        name: node.attribute("name").unwrap_or_default().to_owned(),
+                        classname: node.attribute("classname").unwrap_or_default().to_owned(),
+                        time: time.unwrap_or(0.0),
+                        outcome: TestOutcome::Passed,
+                    };
+
+                    if *empty {
+                        push_case(&mut suites, test);
+                    } else {
+                        case = Some(test);
+                    }
+                }
+                Node::Start { name: "failure" | "error", empty, .. } => {
+                    let message = node.attribute("message").unwrap_or_default().to_owned();
+
+                    if let Some(case) = &mut case {
+                        case.outcome = if matches!(node, Node::Start { name: "failure", .. }) {
+                            TestOutcome::Failed { message, details: String::new() }
+                        } else {
+                            TestOutcome::Errored { message, details: String::new() }
+                        };
+                    }
+
+                    if !empty {
+                        text = Some(String::new());
+                    }
+                }
+                Node::Start { name: "skipped", .. } => {
+                    if let Some(case) = &mut case {
+                        case.outcome = TestOutcome::Skipped;
+                    }
+                }
+                Node::Text(content) => {
+                    if let Some(text) = &mut text {
+                        text.push_str(content);
+                    }
+                }
+                Node::End("failure" | "error") => {
+                    let content = text.take().unwrap_or_default();
+
+                    if let Some(
+                        TestCase { outcome: TestOutcome::Failed { details, .. }, .. }
+                        | TestCase { outcome: TestOutcome::Errored { details, .. }, .. },
+                    ) = &mut case
+                    {
+                        *details = content.trim().to_owned();
+                    }
+                }
+                Node::End("testcase") => {
+                    if let Some(case) = case.take() {
+                        push_case(&mut suites, case);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        TestReport { suites }
+    }
+
+    /// Whether any test failed or errored.
+    pub fn has_failures(&self) -> bool {
+        self.suites.iter().any(|suite| suite.count(is_failure) > 0)
+    }
+
+    /// Render a summary table of the suites, followed by a section per suite with the details
+    /// of its failed tests in fenced code blocks.
+    ///
+    /// Suites without failures get a section only saying so, to keep reports of large test
+    /// runs short.
+    pub fn render(&self) -> String {
+        let headers = ["Suite", "Tests", "Passed", "Failed", "Skipped", "Time"].map(String::from);
+        let rows: Vec<Vec<String>> = self
+            .suites
+            .iter()
+            .map(|suite| {
+                vec![
+                    suite.name.clone(),
+                    suite.cases.len().to_string(),
+                    suite.count(|outcome| *outcome == TestOutcome::Passed).to_string(),
+                    suite.count(is_failure).to_string(),
+                    suite.count(|outcome| *outcome == TestOutcome::Skipped).to_string(),
+                    format!("{:.2}s", suite.time()),
+                ]
+            })
+            .collect();
+        let mut blocks = vec![pipe_table(&headers, &rows)];
+
+        for suite in &self.suites {
+            blocks.push(h3(&suite.name));
+
+            if suite.count(is_failure) == 0 {
+                blocks.push("All tests passed.".to_owned());
+            }
+
+            for case in &suite.cases {
+                let (message, details) = match &case.outcome {
+                    TestOutcome::Failed { message, details }
+                    | TestOutcome::Errored { message, details } => (message, details),
+                    _ => continue,
+                };
+                let name = if case.classname.is_empty() {
+                    case.name.clone()
+                } else {
+                    format!("{}::{}", case.classname, case.name)
+                };
+
+                blocks.push(h4(&format!("❌ {name}")));
+
+                if !message.is_empty() {
+                    blocks.push(escape_text(message, EscapeMode::Minimal).into_owned());
+                }
+
+                if !details.is_empty() {
+                    blocks.push(fenced_code_block(details, Some("text")));
+                }
+            }
+        }
+
+        blocks.join(&LF.to_string().repeat(2))
+    }
+}
+
+fn is_failure(outcome: &TestOutcome) -> bool {
+    matches!(outcome, TestOutcome::Failed { .. } | TestOutcome::Errored { .. })
+}
+
+/// Add a test case to the current suite, creating an unnamed one for bare `<testcase>` roots.
+fn push_case(suites: &mut Vec<TestSuite>, case: TestCase) {
+    if suites.is_empty() {
+        suites.push(TestSuite { name: String::new(), cases: Vec::new() });
+    }
+
+    if let Some(suite) = suites.last_mut() {
+        suite.cases.push(case);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_junit_reads_errors_and_skips_across_suites() {
+        let xml = r#"<?xml version="1.0"?>
+            <testsuites>
+                <testsuite name="a"><testcase name="x"><skipped/></testcase></testsuite>
+                <testsuite name="b">
+                    <testcase name="y"><error message="boom"/></testcase>
+                </testsuite>
+            </testsuites>"#;
+        let report = TestReport::from_junit(xml);
+
+        assert_eq!(report.suites[0].cases[0].outcome, TestOutcome::Skipped);
+        assert_eq!(
+            report.suites[1].cases[0].outcome,
+            TestOutcome::Errored { message: "boom".to_owned(), details: String::new() }
+        );
+    }
+
+    #[test]
+    fn render_escapes_failure_messages() {
+        let xml = r##"<testcase name="t"><failure message="# expected *a* > b"/></testcase>"##;
+        let report = TestReport::from_junit(xml).render();
+
+        assert!(report.ends_with("#### ❌ t\n\n\\# expected \\*a\\* > b"), "{report}");
+    }
+}
@@ -0,0 +1,172 @@
+//! Localizable labels for generated content.
+
+/// The text of every label md-writer generates on its own, e.g. the title of a table of
+/// contents.
+///
+/// The default labels are in English; documents in other languages can override any of them.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::labels::Labels;
+///
+/// let labels = Labels {
+///     table_of_contents: "Inhaltsverzeichnis".into(),
+///     summary: "Zusammenfassung".into(),
+///     ..Labels::default()
+/// };
+///
+/// assert_eq!(labels.back_to_top, "Back to top");
+/// assert_eq!(labels.license, "License");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Labels {
+    /// The title of a table of contents.
+    pub table_of_contents: String,
+    /// The title of a summary section.
+    pub summary: String,
+    /// The text of a link back to the top of the document.
+    pub back_to_top: String,
+    /// The title of a changelog.
+    pub changelog: String,
+    /// The title of the changelog section for unreleased changes.
+    pub unreleased: String,
+    /// The title of the changelog section for new features.
+    pub added: String,
+    /// The title of the changelog section for changes in existing functionality.
+    pub changed: String,
+    /// The title of the changelog section for soon-to-be removed features.
+    pub deprecated: String,
+    /// The title of the changelog section for now removed features.
+    pub removed: String,
+    /// The title of the changelog section for bug fixes.
+    pub fixed: String,
+    /// The title of the changelog section for vulnerabilities.
+    pub security: String,
+    /// The title of a README's installation section.
+    pub installation: String,
+    /// The title of a README's usage section.
+    pub usage: String,
+    /// The title of a license section.
+    pub license: String,
+    /// The word preceding the number of a figure, e.g. "Figure" in "Figure 1".
+    pub figure: String,
+    /// The word preceding the number of a table, e.g. "Table" in "Table 1".
+    pub table: String,
+    /// The summary of an error's collapsed debug representation.
+    pub debug: String,
+    /// The summary of a collapsed backtrace.
+    pub backtrace: String,
+    /// The title of an API reference whose specification has none.
+    pub api_reference: String,
+    /// The word preceding the version of an API, e.g. "Version" in "Version 1.0.0".
+    pub version: String,
+    /// The notice of a deprecated API operation.
+    pub deprecated_operation: String,
+    /// The title of an API operation's parameters section.
+    pub parameters: String,
+    /// The title of an API operation's request body section.
+    pub request_body: String,
+    /// The title of an API operation's responses section.
+    pub responses: String,
+    /// The title of an API reference's schemas section.
+    pub schemas: String,
+    /// The header of a column of names.
+    pub name: String,
+    /// The header of a column of API parameter locations, e.g. `path` or `query`.
+    pub location: String,
+    /// The header of a column of types.
+    pub type_name: String,
+    /// The header of a column telling whether something is required.
+    pub required: String,
+    /// The header of a column of descriptions.
+    pub description: String,
+    /// The header of a column of HTTP status codes.
+    pub status: String,
+    /// The header of a column of values.
+    pub value: String,
+    /// The title of an issue's steps to reproduce section.
+    pub steps_to_reproduce: String,
+    /// The title of an issue's environment section.
+    pub environment: String,
+    /// The title of a checklist section.
+    pub checklist: String,
+    /// The title of a pull request's changes section.
+    pub changes: String,
+    /// The summary of collapsed logs.
+    pub logs: String,
+    /// An affirmative table cell.
+    pub yes: String,
+    /// A negative table cell.
+    pub no: String,
+}
+
+impl Default for Labels {
+    fn default() -> Labels {
+        Labels {
+            table_of_contents: "Table of Contents".into(),
+            summary: "Summary".into(),
+            back_to_top: "Back to top".into(),
+            changelog: "Changelog".into(),
+            unreleased: "Unreleased".into(),
+            added: "Added".into(),
+            changed: "Changed".into(),
+            deprecated: "Deprecated".into(),
+            removed: "Removed".into(),
+            fixed: "Fixed".into(),
+            security: "Security".into(),
+            installation: "Installation".into(),
+            usage: "Usage".into(),
+            license: "License".into(),
+            figure: "Figure".into(),
+            table: "Table".into(),
+            debug: "Debug".into(),
+            backtrace: "Backtrace".into(),
+            api_reference: "API Reference".into(),
+            version: "Version".into(),
+            deprecated_operation: "Deprecated.".into(),
+            parameters: "Parameters".into(),
+            request_body: "Request Body".into(),
+            responses: "Responses".into(),
+            schemas: "Schemas".into(),
+            name: "Name".into(),
+            location: "In".into(),
+            type_name: "Type".into(),
+            required: "Required".into(),
+            description: "Description".into(),
+            status: "Status".into(),
+            value: "Value".into(),
+            steps_to_reproduce: "Steps to Reproduce".into(),
+            environment: "Environment".into(),
+            checklist: "Checklist".into(),
+            changes: "Changes".into(),
+            logs: "Logs".into(),
+            yes: "Yes".into(),
+            no: "No".into(),
+        }
+    }
+}
+
+impl Labels {
+    /// Create the numbered label of a figure.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// assert_eq!(md_writer::labels::Labels::default().figure_number(2), "Figure 2");
+    /// ```
+    pub fn figure_number(&self, number: usize) -> String {
+        format!("{} {number}", self.figure)
+    }
+
+    /// Create the numbered label of a table.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// assert_eq!(md_writer::labels::Labels::default().table_number(3), "Table 3");
+    /// ```
+    pub fn table_number(&self, number: usize) -> String {
+        format!("{} {number}", self.table)
+    }
+}
@@ -1,10 +1,199 @@
 //! MD Writer
 //! =========
 //! A collection of utilities to help make writing Markdown easier.
+//!
+//! Features
+//! ========
+//! - `anyhow`: Render [`anyhow::Error`](https://docs.rs/anyhow) chains with
+//!   `diagnostics::format_anyhow_error`.
+//! - `http-check`: An asynchronous checker of the external links in a document, see the
+//!   `http_check` module.
+//! - `json`: Render arbitrary JSON values, e.g. API responses, see `json::value_to_markdown`.
+//! - `json-schema`: Document JSON Schemas as property tables, see the `json_schema` module.
+//! - `lint`: Opt-in checks of generated Markdown for accessibility problems and broken relative
+//!   links, see the `lint` module.
+//! - `openapi`: Generate API reference documents from OpenAPI specifications, see the `openapi`
+//!   module.
+//! - `polars`: Render previews of [Polars](https://pola.rs) data frames as tables, see
+//!   `dataframe::table_from_dataframe`.
+//! - `serde`: Render tables of any [`serde::Serialize`](https://docs.rs/serde) structs, see
+//!   `table::table_from_serialize`.
+//! - `strict`: In debug builds, re-parse the output of every block-producing function and panic
+//!   if it doesn't produce the intended block.
+//! - `toml`: Generate TOML front matter, e.g. for Zola or Hugo, see
+//!   `front_matter::toml_front_matter`.
+//! - `tracing`: A [`tracing`](https://docs.rs/tracing) layer which writes a Markdown execution
+//!   log, see `trace::MarkdownLayer`.
+//! - `yaml`: Generate YAML front matter, e.g. for Hugo or Jekyll, see
+//!   `front_matter::yaml_front_matter`.
+
+pub mod admonition;
+pub mod alert;
+pub mod asciidoc;
+pub mod attributes;
+pub mod azure;
+pub mod badge;
+pub mod bidi;
+pub mod case;
+pub mod changelog;
+pub mod container;
+pub mod contributors;
+pub mod coverage;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+pub mod delimited;
+pub mod diagnostics;
+pub mod display;
+pub mod document;
+pub mod env;
+pub mod error;
+pub mod escape;
+pub mod faq;
+pub mod flavor;
+pub mod footnote;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+pub mod front_matter;
+pub mod github;
+pub mod gitlab;
+pub mod glossary;
+#[cfg(feature = "http-check")]
+pub mod http_check;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "json-schema")]
+pub mod json_schema;
+pub mod image;
+pub mod issue;
+pub mod job_summary;
+pub mod junit;
+pub mod labels;
+pub mod license;
+pub mod link_reference;
+#[cfg(feature = "lint")]
+pub mod lint;
+pub mod list;
+pub mod manifest;
+pub mod map;
+pub mod math;
+pub mod matrix;
+pub mod mdx;
+pub mod mermaid;
+pub mod obsidian;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+pub mod options;
+pub mod readme;
+pub mod report;
+pub mod shortcode;
+pub mod slug;
+pub mod table;
+pub mod tabs;
+pub mod testing;
+pub mod text;
+pub mod to_markdown;
+pub mod toc;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod url;
+pub mod write;
+pub mod writer;
+
+mod strict;
+mod xml;
+
+use attributes::Attributes;
+use container::Container;
+use error::MdError;
+use flavor::Flavor;
+use options::{EmphasisChar, FenceChar, HardBreak};
+use strict::Expected;
 
 /// The line feed control character.
 pub const LF: char = '\n';
 
+/// The widest a setext header's underline gets, however wide its text.
+pub const MAX_UNDERLINE_WIDTH: usize = 80;
+
+/// Create a Markdown blockquote, prefixing every line of the text with `> `. Blank lines are
+/// prefixed with a bare `>` so multiple paragraphs, lists and code blocks stay in the quote.
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "Note:\n\n```sh\ncargo test\n```";
+///
+/// assert_eq!(md_writer::blockquote(text), "> Note:\n>\n> ```sh\n> cargo test\n> ```");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#block-quotes>
+pub fn blockquote(text: &str) -> String {
+    blockquote_with_depth(text, 1)
+}
+
+/// Create a Markdown blockquote nested `depth` levels deep, e.g. `>> ` for quoting quoted
+/// content, prefixing every line of the text. Empty text gives an empty blockquote, e.g. `>`,
+/// and a depth of 0 returns the text as is.
+///
+/// Examples
+/// ========
+/// ```
+/// let reply = format!("On Monday, Ana wrote:\n\n{}", md_writer::blockquote("Ship it?"));
+///
+/// assert_eq!(
+///     md_writer::blockquote_with_depth(&reply, 2),
+///     ">> On Monday, Ana wrote:\n>>\n>> > Ship it?"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#block-quotes>
+pub fn blockquote_with_depth(text: &str, depth: usize) -> String {
+    if depth == 0 {
+        return text.to_owned();
+    }
+
+    let blockquote = write::written(|out| write::write_blockquote_with_depth(out, text, depth));
+
+    strict::verify(&blockquote, Expected::BlockQuote);
+
+    blockquote
+}
+
+/// Wrap text in strong emphasis, typically rendered in bold, delimited with asterisks. See
+/// [`EmphasisChar`] to delimit it with underscores instead.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::bold("Warning"), "**Warning**");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis>
+pub fn bold(text: &str) -> String {
+    EmphasisChar::Asterisk.bold(text)
+}
+
+/// Wrap text in both emphasis and strong emphasis, delimited with asterisks. See
+/// [`EmphasisChar`] to delimit it with underscores instead.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::bold_italic("Warning"), "***Warning***");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis>
+pub fn bold_italic(text: &str) -> String {
+    EmphasisChar::Asterisk.bold_italic(text)
+}
+
 /// Create a Markdown code fence.
 /// 
 /// Examples
@@ -29,15 +218,14 @@ pub const LF: char = '\n';
 /// - <https://spec.commonmark.org/0.30/#code-fence>
 /// - <https://spec.commonmark.org/0.30/#info-string>
 pub fn code_fence(info_string: Option<&str>) -> String {
-    let mut code_fence = "`".repeat(3);
-    let info_string = info_string.unwrap_or("");
-
-    code_fence.push_str(info_string);
-
-    code_fence
+    FenceChar::Backtick.code_fence(info_string)
 }
 
 /// Create a Markdown code span.
+///
+/// The code is delimited by a backtick run longer than any inside of it, and padded with spaces
+/// when it begins or ends with a backtick, or with a space on both sides, so it renders
+/// verbatim. Empty code creates nothing, since there's no empty code span.
 /// 
 /// Examples
 /// ========
@@ -47,15 +235,86 @@ pub fn code_fence(info_string: Option<&str>) -> String {
 /// 
 /// assert_eq!(code_span, format!("`{code}`"));
 /// ```
+///
+/// With backticks:
+/// ```
+/// assert_eq!(md_writer::code_span("a ` b"), "``a ` b``");
+/// assert_eq!(md_writer::code_span("`code_span`"), "`` `code_span` ``");
+/// ```
 /// 
 /// Reference
 /// =========
 /// - <https://spec.commonmark.org/0.30/#code-span>
 pub fn code_span(code: &str) -> String {
-    format!("`{code}`")
+    write::written(|out| write::write_code_span(out, code))
+}
+
+/// Create a collapsible HTML `<details>` element with a summary, optionally expanded initially.
+///
+/// The body is separated from the tags by blank lines so the Markdown inside of it still
+/// renders, e.g. on GitHub.
+///
+/// Examples
+/// ========
+/// ```
+/// let logs = md_writer::fenced_code_block("error: linking failed", Some("text"));
+///
+/// assert_eq!(
+///     md_writer::details("Logs", &logs, false),
+///     "<details>\n<summary>Logs</summary>\n\n```text\nerror: linking failed\n```\n\n</details>"
+/// );
+/// assert!(md_writer::details("Logs", &logs, true).starts_with("<details open>\n"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/details>
+/// - <https://spec.commonmark.org/0.30/#html-blocks>
+pub fn details(summary: &str, body: &str, open: bool) -> String {
+    let open = if open { " open" } else { "" };
+
+    format!("<details{open}>{LF}<summary>{summary}</summary>{LF}{LF}{body}{LF}{LF}</details>")
+}
+
+/// Create an epigraph: a quoted opening passage followed by an attribution line.
+///
+/// Pandoc gets a fenced div with the `epigraph` class so it can be styled, while every other
+/// flavor gets a blockquote.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::flavor::Flavor;
+///
+/// let quote = "It was the best of times,\nit was the worst of times.";
+/// let attribution = "Charles Dickens";
+///
+/// assert_eq!(
+///     md_writer::epigraph(quote, attribution, Flavor::Gfm),
+///     "> It was the best of times,\n> it was the worst of times.\n>\n> — Charles Dickens"
+/// );
+/// assert_eq!(
+///     md_writer::epigraph(quote, attribution, Flavor::Pandoc),
+///     "::: epigraph\nIt was the best of times,\nit was the worst of times.\n\n\
+///      — Charles Dickens\n:::"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#block-quotes>
+/// - <https://pandoc.org/MANUAL.html#extension-fenced_divs>
+pub fn epigraph(quote: &str, attribution: &str, flavor: Flavor) -> String {
+    let body = format!("{quote}{LF}{LF}— {attribution}");
+
+    if flavor == Flavor::Pandoc {
+        return Container::new("epigraph").render(&body);
+    }
+
+    blockquote(&body)
 }
 
-/// Create a Markdown fenced code block.
+/// Create a Markdown fenced code block. See [`FenceChar`] for tilde fences.
 /// 
 /// Examples
 /// ========
@@ -75,17 +334,21 @@ pub fn code_span(code: &str) -> String {
 ///
 /// assert_eq!(fenced_code_block, format!("```\n{code}\n```"));
 /// ```
+///
+/// Containing a code fence, which gets a longer fence:
+/// ```
+/// let code = "```sh\ncargo test\n```";
+/// let fenced_code_block = md_writer::fenced_code_block(code, Some("markdown"));
+///
+/// assert_eq!(fenced_code_block, format!("````markdown\n{code}\n````"));
+/// ```
 /// 
 /// Reference
 /// =========
 /// - <https://spec.commonmark.org/0.30/#fenced-code-blocks>
 /// - <https://spec.commonmark.org/0.30/#info-string>
 pub fn fenced_code_block(code: &str, info_string: Option<&str>) -> String {
-    [
-        code_fence(info_string),
-        code.to_owned(),
-        code_fence(None)
-    ].join(&LF.to_string())
+    FenceChar::Backtick.fenced_code_block(code, info_string)
 }
 
 /// Create a Markdown fenced code block with a JavaScript info string.
@@ -164,6 +427,38 @@ pub fn fenced_ts_code_block(code: &str) -> String {
     fenced_code_block(code, Some("typescript"))
 }
 
+/// Create a Markdown header of the provided level, as [`h1`] through [`h6`] would. See
+/// [`HeadingStyle::heading`](options::HeadingStyle::heading) for ATX level 1 and 2 headers.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidHeadingLevel`] when the level is outside of 1–6.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{error::MdError, h};
+///
+/// assert_eq!(h(3, "Usage").unwrap(), md_writer::h3("Usage"));
+/// assert_eq!(h(7, "Usage"), Err(MdError::InvalidHeadingLevel(7)));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#atx-headings>
+/// - <https://spec.commonmark.org/0.30/#setext-headings>
+pub fn h(level: u8, text: &str) -> Result<String, MdError> {
+    match level {
+        1 => Ok(h1(text)),
+        2 => Ok(h2(text)),
+        3 => Ok(h3(text)),
+        4 => Ok(h4(text)),
+        5 => Ok(h5(text)),
+        6 => Ok(h6(text)),
+        level => Err(MdError::InvalidHeadingLevel(level)),
+    }
+}
+
 /// Create a level 1 Markdown setext header.
 ///
 /// Examples
@@ -175,22 +470,41 @@ pub fn fenced_ts_code_block(code: &str) -> String {
 /// assert_eq!(h1, format!("{text}\n============"));
 /// ```
 ///
-/// Panics
-/// ======
-/// This function utilizes the [`repeat`](https://doc.rust-lang.org/std/string/struct.String.html#method.repeat)
-/// method on [`String`](https://doc.rust-lang.org/std/string/struct.String.html) and as such will
-/// panic if provided a string with too many characters.
+/// The underline is as wide as the displayed text, so wide characters (e.g. CJK or emoji)
+/// count twice and bidirectional control characters (see [`bidi`]) don't count, up to
+/// [`MAX_UNDERLINE_WIDTH`] columns. Empty or whitespace-only text, which can't be underlined,
+/// gives an empty ATX header (`# `) instead.
 /// 
 /// Reference
 /// =========
 /// - <https://spec.commonmark.org/0.30/#setext-headings>
 pub fn h1(text: &str) -> String {
-    let mut h1 = String::from(text);
-    let char_count = text.chars().count();
-    let underline = "=".repeat(char_count);
+    let h1 = write::written(|out| write::write_h1(out, text));
+
+    strict::verify(&h1, Expected::Heading(1));
+
+    h1
+}
+
+/// Create a level 1 Markdown ATX header, for documents which don't use setext headers. See
+/// [`HeadingStyle`](options::HeadingStyle).
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "Hello world!";
+/// let h1 = md_writer::h1_atx(text);
+///
+/// assert_eq!(h1, format!("# {text}"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#atx-heading>
+pub fn h1_atx(text: &str) -> String {
+    let h1 = write::written(|out| write::write_h1_atx(out, text));
 
-    h1.push(LF);
-    h1.push_str(&underline);
+    strict::verify(&h1, Expected::Heading(1));
 
     h1
 }
@@ -206,22 +520,41 @@ pub fn h1(text: &str) -> String {
 /// assert_eq!(h2, format!("{text}\n------------"));
 /// ```
 ///
-/// Panics
-/// ======
-/// This function utilizes the [`repeat`](https://doc.rust-lang.org/std/string/struct.String.html#method.repeat)
-/// method on [`String`](https://doc.rust-lang.org/std/string/struct.String.html) and as such will
-/// panic if provided a string with too many characters.
+/// The underline is as wide as the displayed text, so wide characters (e.g. CJK or emoji)
+/// count twice and bidirectional control characters (see [`bidi`]) don't count, up to
+/// [`MAX_UNDERLINE_WIDTH`] columns. Empty or whitespace-only text, which can't be underlined,
+/// gives an empty ATX header (`## `) instead.
 ///
 /// Reference
 /// =========
 /// - <https://spec.commonmark.org/0.30/#setext-headings>
 pub fn h2(text: &str) -> String {
-    let mut h2 = String::from(text);
-    let char_count = text.chars().count();
-    let underline = "-".repeat(char_count);
+    let h2 = write::written(|out| write::write_h2(out, text));
+
+    strict::verify(&h2, Expected::Heading(2));
+
+    h2
+}
+
+/// Create a level 2 Markdown ATX header, for documents which don't use setext headers. See
+/// [`HeadingStyle`](options::HeadingStyle).
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "Hello world!";
+/// let h2 = md_writer::h2_atx(text);
+///
+/// assert_eq!(h2, format!("## {text}"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#atx-heading>
+pub fn h2_atx(text: &str) -> String {
+    let h2 = write::written(|out| write::write_h2_atx(out, text));
 
-    h2.push(LF);
-    h2.push_str(&underline);
+    strict::verify(&h2, Expected::Heading(2));
 
     h2
 }
@@ -241,7 +574,11 @@ pub fn h2(text: &str) -> String {
 /// =========
 /// - <https://spec.commonmark.org/0.30/#atx-heading>
 pub fn h3(text: &str) -> String {
-    format!("### {text}")
+    let h3 = write::written(|out| write::write_h3(out, text));
+
+    strict::verify(&h3, Expected::Heading(3));
+
+    h3
 }
 
 /// Create a level 4 Markdown ATX header.
@@ -259,7 +596,11 @@ pub fn h3(text: &str) -> String {
 /// =========
 /// - <https://spec.commonmark.org/0.30/#atx-heading>
 pub fn h4(text: &str) -> String {
-    format!("#### {text}")
+    let h4 = write::written(|out| write::write_h4(out, text));
+
+    strict::verify(&h4, Expected::Heading(4));
+
+    h4
 }
 
 /// Create a level 5 Markdown ATX header.
@@ -277,7 +618,11 @@ pub fn h4(text: &str) -> String {
 /// =========
 /// - <https://spec.commonmark.org/0.30/#atx-heading>
 pub fn h5(text: &str) -> String {
-    format!("##### {text}")
+    let h5 = write::written(|out| write::write_h5(out, text));
+
+    strict::verify(&h5, Expected::Heading(5));
+
+    h5
 }
 
 /// Create a level 6 Markdown ATX header.
@@ -295,7 +640,287 @@ pub fn h5(text: &str) -> String {
 /// =========
 /// - <https://spec.commonmark.org/0.30/#atx-heading>
 pub fn h6(text: &str) -> String {
-    format!("###### {text}")
+    let h6 = write::written(|out| write::write_h6(out, text));
+
+    strict::verify(&h6, Expected::Heading(6));
+
+    h6
+}
+
+/// Create a Markdown header of the provided level followed by an attribute list, e.g. for
+/// stable anchors and classes in Pandoc or kramdown, as [`h`] would.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidHeadingLevel`] when the level is outside of 1–6.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{attributes::Attributes, h_with_attrs};
+///
+/// let attributes = Attributes::new().id("api").class("reference");
+///
+/// assert_eq!(h_with_attrs(3, "API", &attributes).unwrap(), "### API {#api .reference}");
+/// assert_eq!(h_with_attrs(3, "API", &Attributes::new()).unwrap(), "### API");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#extension-header_attributes>
+/// - <https://kramdown.gettalong.org/syntax.html#specifying-a-header-id>
+pub fn h_with_attrs(level: u8, text: &str, attributes: &Attributes) -> Result<String, MdError> {
+    if attributes.is_empty() {
+        return h(level, text);
+    }
+
+    h(level, &format!("{text} {}", attributes.render()))
+}
+
+/// Create a Markdown hard line break: a backslash at the end of the line. See [`HardBreak`]
+/// for the trailing spaces form.
+///
+/// Examples
+/// ========
+/// ```
+/// let address = format!("1 Infinite Loop{}Cupertino", md_writer::hard_break());
+///
+/// assert_eq!(address, "1 Infinite Loop\\\nCupertino");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#hard-line-breaks>
+pub fn hard_break() -> String {
+    HardBreak::Backslash.render().to_owned()
+}
+
+/// Create a Markdown image with an optional title, typically shown as a tooltip.
+///
+/// Brackets in the alternative text are escaped, sources containing spaces or parentheses are
+/// wrapped in angle brackets and quotes in the title are escaped.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(
+///     md_writer::image("Logo [dark]", "assets/logo.png", Some("MD Writer")),
+///     r#"![Logo \[dark\]](assets/logo.png "MD Writer")"#
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#images>
+pub fn image(alt: &str, source: &str, title: Option<&str>) -> String {
+    write::written(|out| write::write_image(out, alt, source, title))
+}
+
+/// Wrap text in emphasis, typically rendered in italics, delimited with asterisks. See
+/// [`EmphasisChar`] to delimit it with underscores instead.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::italic("Note"), "*Note*");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis>
+pub fn italic(text: &str) -> String {
+    EmphasisChar::Asterisk.italic(text)
+}
+
+/// Create a Markdown inline link with an optional title, typically shown as a tooltip.
+///
+/// Destinations containing spaces or parentheses are wrapped in angle brackets and quotes in
+/// the title are escaped. The text is used as is, so it may contain other inline Markdown.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(
+///     md_writer::link("docs", "https://docs.rs/md-writer", None),
+///     "[docs](https://docs.rs/md-writer)"
+/// );
+/// assert_eq!(
+///     md_writer::link("guide", "user guide (draft).md", Some("The \"guide\"")),
+///     r#"[guide](<user guide (draft).md> "The \"guide\"")"#
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#inline-link>
+pub fn link(text: &str, destination: &str, title: Option<&str>) -> String {
+    write::written(|out| write::write_link(out, text, destination, title))
+}
+
+/// Create a Markdown paragraph. Each line is trimmed, since leading spaces could turn it into a
+/// code block, and blank lines are dropped so the text stays a single paragraph. A line which
+/// would start another block, e.g. `- ` or `1. `, or underline the line before it as a setext
+/// heading has its marker escaped; the text may otherwise contain inline Markdown. Line feeds
+/// remain soft breaks; see [`HardBreak::paragraph`] to turn them into hard line breaks.
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "    Generated by md-writer.\n\nDo not edit.  ";
+///
+/// assert_eq!(md_writer::paragraph(text), "Generated by md-writer.\nDo not edit.");
+/// assert_eq!(md_writer::paragraph("Title\n---"), "Title\n\\---");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#paragraphs>
+pub fn paragraph(text: &str) -> String {
+    write::written(|out| write::write_paragraph(out, text))
+}
+
+/// Strike text through. Strikethrough is a GitHub Flavored Markdown extension which most other
+/// flavors support as well.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::strikethrough("deprecated"), "~~deprecated~~");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#strikethrough-extension->
+pub fn strikethrough(text: &str) -> String {
+    format!("~~{text}~~")
+}
+
+/// Create a Markdown thematic break, typically rendered as a horizontal rule.
+///
+/// The break is `---`, which turns the line before it into a setext heading unless the two are
+/// separated by a blank line. See [`thematic_break_with`] for other markers.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::thematic_break(), "---");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#thematic-breaks>
+pub fn thematic_break() -> String {
+    "-".repeat(3)
+}
+
+/// Create a Markdown thematic break of the provided marker character and length.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidThematicBreak`] unless the marker is `-`, `*` or `_` and the
+/// length is at least 3.
+///
+/// Examples
+/// ========
+/// ```
+/// assert_eq!(md_writer::thematic_break_with('*', 5).unwrap(), "*****");
+/// assert!(md_writer::thematic_break_with('_', 2).is_err());
+/// assert!(md_writer::thematic_break_with('=', 3).is_err());
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#thematic-breaks>
+pub fn thematic_break_with(marker: char, length: usize) -> Result<String, MdError> {
+    validate_thematic_break(marker, length)?;
+
+    Ok(marker.to_string().repeat(length))
+}
+
+/// Create a Markdown code fence, rejecting info strings which would break it.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidInfoString`] when the info string contains a backtick, which
+/// CommonMark doesn't allow after a backtick fence, or a line break.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{error::MdError, try_code_fence};
+///
+/// assert_eq!(try_code_fence(Some("rust")).unwrap(), "```rust");
+/// assert_eq!(
+///     try_code_fence(Some("`rust`")),
+///     Err(MdError::InvalidInfoString("`rust`".to_owned()))
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#info-string>
+pub fn try_code_fence(info_string: Option<&str>) -> Result<String, MdError> {
+    validate_info_string(info_string)?;
+
+    Ok(code_fence(info_string))
+}
+
+/// Create a Markdown fenced code block, rejecting info strings which would break it.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidInfoString`] when the info string contains a backtick, which
+/// CommonMark doesn't allow after a backtick fence, or a line break.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::try_fenced_code_block;
+///
+/// assert_eq!(try_fenced_code_block("ls", Some("sh")).unwrap(), "```sh\nls\n```");
+/// assert!(try_fenced_code_block("ls", Some("sh\nrm -rf /")).is_err());
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#fenced-code-blocks>
+/// - <https://spec.commonmark.org/0.30/#info-string>
+pub fn try_fenced_code_block(code: &str, info_string: Option<&str>) -> Result<String, MdError> {
+    validate_info_string(info_string)?;
+
+    Ok(fenced_code_block(code, info_string))
+}
+
+/// Check that an info string can follow a backtick fence.
+fn validate_info_string(info_string: Option<&str>) -> Result<(), MdError> {
+    match info_string {
+        Some(info_string) if info_string.contains(['`', '\n', '\r']) => {
+            Err(MdError::InvalidInfoString(info_string.to_owned()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that CommonMark recognizes a thematic break of the provided marker and length.
+pub(crate) fn validate_thematic_break(marker: char, length: usize) -> Result<(), MdError> {
+    if !matches!(marker, '-' | '*' | '_') || length < 3 {
+        return Err(MdError::InvalidThematicBreak { marker, length });
+    }
+
+    Ok(())
+}
+
+/// The width of a setext header's underline, as wide as the widest line of its text, at least
+/// one and at most [`MAX_UNDERLINE_WIDTH`] columns.
+pub(crate) fn setext_underline_width(text: &str) -> usize {
+    let width = text.lines().map(bidi::display_width).max().unwrap_or(0);
+
+    width.clamp(1, MAX_UNDERLINE_WIDTH)
+}
+
+/// The length of the longest run of the provided character in the text.
+pub(crate) fn longest_run(text: &str, char: char) -> usize {
+    text.split(|c| c != char).map(str::len).max().unwrap_or(0)
 }
 
 #[cfg(test)]
@@ -318,6 +943,14 @@ mod tests {
         assert_eq!(result, "Hello!\n------");
     }
 
+    #[test]
+    fn h2_underline_ignores_bidi_controls() {
+        let text = "\u{2067}שלום\u{2069}";
+        let result = h2(text);
+
+        assert_eq!(result, format!("{text}\n----"));
+    }
+
     #[test]
     fn h3_returns_a_lvl3_header() {
         let text = "Hello!";
@@ -349,4 +982,117 @@ mod tests {
 
         assert_eq!(result, "###### Hello!");
     }
+
+    #[test]
+    fn blockquote_prefixes_blank_lines_without_trailing_space() {
+        assert_eq!(blockquote("a\n\n- b"), "> a\n>\n> - b");
+    }
+
+    #[test]
+    fn blockquote_with_depth_nests_every_line() {
+        assert_eq!(blockquote_with_depth("a\n\nb", 3), ">>> a\n>>>\n>>> b");
+        assert_eq!(blockquote_with_depth("a", 0), "a");
+    }
+
+    #[test]
+    fn invalid_thematic_break_describes_the_problem() {
+        let error = thematic_break_with('-', 1).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "a thematic break needs at least 3 of `-`, `*` or `_`, not 1 of `-`"
+        );
+    }
+
+    #[test]
+    fn code_span_pads_spaces_which_would_be_stripped() {
+        assert_eq!(code_span(" a "), "`  a  `");
+        assert_eq!(code_span("  "), "`  `");
+        assert_eq!(code_span("a``b`"), "``` a``b` ```");
+    }
+
+    #[test]
+    fn empty_code_span_is_empty() {
+        assert_eq!(code_span(""), "");
+        assert_eq!(write::written(|out| write::write_code_span(out, "")), "");
+    }
+
+    #[test]
+    fn fenced_code_block_outruns_backticks_in_the_code() {
+        let result = fenced_code_block("`````\n``", None);
+
+        assert_eq!(result, "``````\n`````\n``\n``````");
+    }
+
+    #[test]
+    fn setext_underlines_follow_display_width() {
+        assert_eq!(h1("日本語"), "日本語\n======");
+        assert_eq!(h2("Two\nlines"), "Two\nlines\n-----");
+        assert_eq!(h1(&"a".repeat(200)).lines().last().unwrap(), "=".repeat(80));
+    }
+
+    #[test]
+    fn empty_setext_headers_fall_back_to_atx() {
+        assert_eq!(h1(""), "# ");
+        assert_eq!(h2("  "), "## ");
+    }
+
+    #[test]
+    fn atx_headers_stay_on_one_line() {
+        assert_eq!(h3("a\nb"), "### a b");
+        assert_eq!(h1_atx("a\n  b\n"), "# a b");
+    }
+
+    #[test]
+    fn atx_headers() {
+        assert_eq!(h1_atx("A"), "# A");
+        assert_eq!(h2_atx("A"), "## A");
+    }
+
+    #[test]
+    fn h_with_attrs_underlines_setext_headers_to_the_attributes() {
+        let attributes = Attributes::new().id("a");
+
+        assert_eq!(h_with_attrs(1, "A", &attributes).unwrap(), "A {#a}\n======");
+        assert!(h_with_attrs(0, "A", &attributes).is_err());
+    }
+
+    #[test]
+    fn h_dispatches_on_level() {
+        assert_eq!(h(1, "A").unwrap(), h1("A"));
+        assert_eq!(h(6, "A").unwrap(), h6("A"));
+        assert_eq!(h(0, "A"), Err(MdError::InvalidHeadingLevel(0)));
+    }
+
+    #[test]
+    fn try_code_fence_rejects_carriage_returns() {
+        assert!(try_code_fence(Some("rust\r")).is_err());
+        assert_eq!(try_code_fence(None).unwrap(), "```");
+    }
+
+    #[test]
+    fn image_wraps_sources_with_spaces() {
+        let result = image("Screenshot", "docs/screen shot.png", None);
+
+        assert_eq!(result, "![Screenshot](<docs/screen shot.png>)");
+    }
+
+    #[test]
+    fn emphasis_helpers_nest() {
+        let text = format!("{} and {}", italic("a"), EmphasisChar::Underscore.bold("b"));
+
+        assert_eq!(bold(&text), "***a* and __b__**");
+    }
+
+    #[test]
+    fn paragraph_lines_never_start_another_block() {
+        let text = "Title\n---\n- item\n# heading\n> quote\n1. first\n*emphasis* stays";
+
+        assert_eq!(
+            paragraph(text),
+            "Title\n\\---\n\\- item\n\\# heading\n\\> quote\n1\\. first\n*emphasis* stays"
+        );
+        assert_eq!(HardBreak::Spaces.paragraph("a\n==="), "a  \n\\===");
+        assert_eq!(paragraph("a | b\n--- | ---"), "a | b\n\\--- | ---");
+    }
 }
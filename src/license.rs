@@ -0,0 +1,345 @@
+//! License sections generated from SPDX license expressions.
+
+use crate::options::{HardBreak, HeadingStyle};
+use crate::LF;
+use std::error::Error;
+use std::fmt;
+
+/// The full names of commonly used licenses, by SPDX identifier.
+const LICENSE_NAMES: &[(&str, &str)] = &[
+    ("0BSD", "BSD Zero Clause License"),
+    ("AGPL-3.0-only", "GNU Affero General Public License v3.0 only"),
+    ("AGPL-3.0-or-later", "GNU Affero General Public License v3.0 or later"),
+    ("Apache-2.0", "Apache License, Version 2.0"),
+    ("BSD-2-Clause", "BSD 2-Clause \"Simplified\" License"),
+    ("BSD-3-Clause", "BSD 3-Clause \"New\" or \"Revised\" License"),
+    ("BSL-1.0", "Boost Software License 1.0"),
+    ("CC0-1.0", "Creative Commons Zero v1.0 Universal"),
+    ("GPL-2.0-only", "GNU General Public License v2.0 only"),
+    ("GPL-2.0-or-later", "GNU General Public License v2.0 or later"),
+    ("GPL-3.0-only", "GNU General Public License v3.0 only"),
+    ("GPL-3.0-or-later", "GNU General Public License v3.0 or later"),
+    ("ISC", "ISC License"),
+    ("LGPL-2.1-only", "GNU Lesser General Public License v2.1 only"),
+    ("LGPL-2.1-or-later", "GNU Lesser General Public License v2.1 or later"),
+    ("LGPL-3.0-only", "GNU Lesser General Public License v3.0 only"),
+    ("LGPL-3.0-or-later", "GNU Lesser General Public License v3.0 or later"),
+    ("MIT", "MIT License"),
+    ("MPL-2.0", "Mozilla Public License 2.0"),
+    ("Unicode-3.0", "Unicode License v3"),
+    ("Unlicense", "The Unlicense"),
+    ("Zlib", "zlib License"),
+];
+
+/// The contribution terms conventionally accompanying `MIT OR Apache-2.0`.
+const DUAL_LICENSE_CONTRIBUTION: &str = "Unless you explicitly state otherwise, any contribution \
+    intentionally submitted for inclusion in the work by you, as defined in the Apache-2.0 \
+    license, shall be dual licensed as above, without any additional terms or conditions.";
+
+/// An SPDX license expression which couldn't be parsed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidExpression {
+    /// The expression.
+    pub expression: String,
+}
+
+impl fmt::Display for InvalidExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid SPDX license expression `{}`", self.expression)
+    }
+}
+
+impl Error for InvalidExpression {}
+
+/// A parsed SPDX license expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Expression<'a> {
+    License(&'a str),
+    With(Box<Expression<'a>>, &'a str),
+    And(Vec<Expression<'a>>),
+    Or(Vec<Expression<'a>>),
+}
+
+impl Expression<'_> {
+    /// Render the expression inline, with every license and exception linked to its text.
+    fn render(&self) -> String {
+        match self {
+            Expression::License(id) => link(id),
+            Expression::With(license, exception) => {
+                format!("{} with the {}", license.render(), link(exception))
+            }
+            Expression::And(operands) => format!("({})", join(operands, " and ")),
+            Expression::Or(operands) => format!("({})", join(operands, " or ")),
+        }
+    }
+}
+
+/// Render a License section for the provided SPDX license expression.
+///
+/// Every license and exception is linked to its text on the SPDX website. Choices between
+/// licenses (`OR`) and combinations of them (`AND`) are rendered as lists, and
+/// `MIT OR Apache-2.0` gets the contribution terms conventionally used by Rust projects.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::license::license_section;
+///
+/// assert_eq!(
+///     license_section("MIT").unwrap(),
+///     "License\n-------\n\nLicensed under the [MIT License](https://spdx.org/licenses/MIT.html)."
+/// );
+///
+/// let section = license_section("MIT OR Apache-2.0").unwrap();
+///
+/// assert!(section.contains("Licensed under either of\n\n- [Apache License, Version 2.0]("));
+/// assert!(section.contains("\n\n### Contribution\n\n"));
+/// assert!(license_section("MIT OR").is_err());
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/>
+/// - <https://rust-lang.github.io/api-guidelines/necessities.html#crate-and-its-dependencies-have-a-permissive-license-c-permissive>
+pub fn license_section(expression: &str) -> Result<String, InvalidExpression> {
+    license_section_with_copyright(expression, &[])
+}
+
+/// Render a License section like [`license_section`], with the provided copyright lines
+/// (e.g. `Copyright (c) 2024 Acme Inc.`) following the heading, each on a line of its own.
+///
+/// Errors
+/// ======
+/// Returns an [`InvalidExpression`] when the expression can't be parsed.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::license::license_section_with_copyright;
+///
+/// let copyright = ["Copyright (c) 2023 Ada Lovelace", "Copyright (c) 2024 Acme Inc."];
+/// let section = license_section_with_copyright("MIT OR Apache-2.0", &copyright).unwrap();
+///
+/// assert!(section.starts_with(
+///     "License\n-------\n\n\
+///      Copyright (c) 2023 Ada Lovelace\\\nCopyright (c) 2024 Acme Inc.\n\n\
+///      Licensed under either of\n\n"
+/// ));
+/// ```
+pub fn license_section_with_copyright(
+    expression: &str,
+    copyright: &[&str],
+) -> Result<String, InvalidExpression> {
+    render_license_section(expression, copyright, "License", HeadingStyle::default())
+}
+
+/// Render a License section, see [`license_section_with_copyright`], with the provided title
+/// and headings in the provided style.
+pub(crate) fn render_license_section(
+    expression: &str,
+    copyright: &[&str],
+    title: &str,
+    heading_style: HeadingStyle,
+) -> Result<String, InvalidExpression> {
+    let invalid = || InvalidExpression { expression: expression.to_owned() };
+    let tokens = tokenize(expression);
+    let mut position = 0;
+    let parsed = parse_or(&tokens, &mut position).ok_or_else(invalid)?;
+
+    if position != tokens.len() {
+        return Err(invalid());
+    }
+
+    let mut blocks = vec![heading_style.header(2, title)];
+
+    if !copyright.is_empty() {
+        blocks.push(HardBreak::Backslash.paragraph(&copyright.join(&LF.to_string())));
+    }
+
+    match &parsed {
+        Expression::Or(operands) => {
+            let mut operands = operands.clone();
+
+            if is_rust_dual_license(&operands) {
+                operands.reverse();
+            }
+
+            blocks.push("Licensed under either of".to_owned());
+            blocks.push(list(&operands));
+            blocks.push("at your option.".to_owned());
+
+            if is_rust_dual_license(&operands) {
+                blocks.push(heading_style.header(3, "Contribution"));
+                blocks.push(DUAL_LICENSE_CONTRIBUTION.to_owned());
+            }
+        }
+        Expression::And(operands) => {
+            blocks.push("Licensed under all of".to_owned());
+            blocks.push(list(operands));
+        }
+        Expression::License(_) | Expression::With(..) => {
+            blocks.push(format!("Licensed under the {}.", parsed.render()));
+        }
+    }
+
+    Ok(blocks.join(&LF.to_string().repeat(2)))
+}
+
+fn is_rust_dual_license(operands: &[Expression]) -> bool {
+    let mut ids: Vec<&str> = operands
+        .iter()
+        .filter_map(|operand| match operand {
+            Expression::License(id) => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    ids.sort_unstable();
+    operands.len() == 2 && ids == ["Apache-2.0", "MIT"]
+}
+
+fn join(operands: &[Expression], separator: &str) -> String {
+    operands
+        .iter()
+        .map(Expression::render)
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn link(id: &str) -> String {
+    let (base, or_later) = match id.strip_suffix('+') {
+        Some(base) => (base, " or later"),
+        None => (id, ""),
+    };
+    let name = LICENSE_NAMES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(base))
+        .map_or(base, |(_, name)| name);
+
+    format!("[{name}{or_later}](https://spdx.org/licenses/{base}.html)")
+}
+
+fn list(operands: &[Expression]) -> String {
+    operands
+        .iter()
+        .map(|operand| {
+            let rendered = operand.render();
+            let rendered = match operand {
+                Expression::And(_) | Expression::Or(_) => &rendered[1..rendered.len() - 1],
+                _ => &rendered,
+            };
+
+            format!("- {rendered}")
+        })
+        .collect::<Vec<String>>()
+        .join(&LF.to_string())
+}
+
+fn tokenize(expression: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+
+    for word in expression.split_whitespace() {
+        let mut word = word;
+
+        while let Some(rest) = word.strip_prefix('(') {
+            tokens.push("(");
+            word = rest;
+        }
+
+        let closing = word.len() - word.trim_end_matches(')').len();
+
+        if !word[..word.len() - closing].is_empty() {
+            tokens.push(&word[..word.len() - closing]);
+        }
+
+        tokens.extend(std::iter::repeat_n(")", closing));
+    }
+
+    tokens
+}
+
+fn parse_or<'a>(tokens: &[&'a str], position: &mut usize) -> Option<Expression<'a>> {
+    let mut operands = vec![parse_and(tokens, position)?];
+
+    while tokens.get(*position).is_some_and(|token| token.eq_ignore_ascii_case("OR")) {
+        *position += 1;
+        operands.push(parse_and(tokens, position)?);
+    }
+
+    Some(if operands.len() == 1 { operands.remove(0) } else { Expression::Or(operands) })
+}
+
+fn parse_and<'a>(tokens: &[&'a str], position: &mut usize) -> Option<Expression<'a>> {
+    let mut operands = vec![parse_with(tokens, position)?];
+
+    while tokens.get(*position).is_some_and(|token| token.eq_ignore_ascii_case("AND")) {
+        *position += 1;
+        operands.push(parse_with(tokens, position)?);
+    }
+
+    Some(if operands.len() == 1 { operands.remove(0) } else { Expression::And(operands) })
+}
+
+fn parse_with<'a>(tokens: &[&'a str], position: &mut usize) -> Option<Expression<'a>> {
+    let license = parse_primary(tokens, position)?;
+
+    if tokens.get(*position).is_some_and(|token| token.eq_ignore_ascii_case("WITH")) {
+        let exception = tokens.get(*position + 1).filter(|token| is_id(token))?;
+
+        *position += 2;
+        return Some(Expression::With(Box::new(license), exception));
+    }
+
+    Some(license)
+}
+
+fn parse_primary<'a>(tokens: &[&'a str], position: &mut usize) -> Option<Expression<'a>> {
+    let token = *tokens.get(*position)?;
+
+    *position += 1;
+
+    if token == "(" {
+        let expression = parse_or(tokens, position)?;
+
+        (tokens.get(*position) == Some(&")")).then(|| {
+            *position += 1;
+            expression
+        })
+    } else {
+        is_id(token).then_some(Expression::License(token))
+    }
+}
+
+/// Whether a token is a license or exception identifier rather than an operator or parenthesis.
+fn is_id(token: &str) -> bool {
+    !["AND", "OR", "WITH"].iter().any(|operator| token.eq_ignore_ascii_case(operator))
+        && token
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '.' | '+' | ':'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn license_section_renders_nested_expressions_and_exceptions() {
+        let expression = "(MIT OR Apache-2.0) AND GPL-2.0+ WITH Classpath-exception-2.0";
+        let section = license_section(expression);
+
+        assert_eq!(
+            section.unwrap(),
+            "License\n-------\n\nLicensed under all of\n\n\
+             - [MIT License](https://spdx.org/licenses/MIT.html) or \
+             [Apache License, Version 2.0](https://spdx.org/licenses/Apache-2.0.html)\n\
+             - [GPL-2.0 or later](https://spdx.org/licenses/GPL-2.0.html) with the \
+             [Classpath-exception-2.0](https://spdx.org/licenses/Classpath-exception-2.0.html)"
+        );
+    }
+
+    #[test]
+    fn license_section_rejects_unbalanced_parentheses() {
+        assert!(license_section("(MIT OR Apache-2.0").is_err());
+        assert!(license_section("MIT)").is_err());
+    }
+}
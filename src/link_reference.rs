@@ -0,0 +1,165 @@
+//! Reference-style links whose definitions are collected while generating a document.
+
+use crate::escape::escape_link_destination;
+use crate::url::link_title;
+use crate::LF;
+
+/// A collector of link reference definitions which keeps long URLs out of the body of a
+/// document.
+///
+/// Each destination is registered once under a label and referenced with `[text][label]`;
+/// the definitions are then rendered together, typically at the end of the document. A label
+/// registered again with the same destination is reused, while one registered again with a
+/// different destination is given a `-2`, `-3`, … suffix. Labels are matched
+/// case-insensitively, as CommonMark matches them.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::link_reference::LinkReferences;
+///
+/// let mut references = LinkReferences::new();
+/// let docs = "https://docs.rs/md-writer";
+/// let spec = "https://spec.commonmark.org/";
+/// let paragraph = format!(
+///     "See the {} and the {}, or the {} again.",
+///     references.link("docs", "docs", docs, None),
+///     references.link("spec", "commonmark", spec, Some("CommonMark")),
+///     references.link("Docs", "docs", docs, None),
+/// );
+///
+/// assert_eq!(paragraph, "See the [docs][] and the [spec][commonmark], or the [Docs][] again.");
+/// assert_eq!(
+///     references.definitions(),
+///     "[docs]: https://docs.rs/md-writer\n\
+///      [commonmark]: https://spec.commonmark.org/ \"CommonMark\""
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#reference-link>
+/// - <https://spec.commonmark.org/0.30/#link-reference-definitions>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LinkReferences {
+    definitions: Vec<Definition>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Definition {
+    label: String,
+    destination: String,
+    title: Option<String>,
+}
+
+impl LinkReferences {
+    /// Create an empty collector.
+    pub fn new() -> LinkReferences {
+        LinkReferences::default()
+    }
+
+    /// Register a destination under a label and get back the label to reference it with, which
+    /// differs from the provided one when it was already taken by another destination.
+    pub fn define(&mut self, label: &str, destination: &str, title: Option<&str>) -> String {
+        let label = label.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let mut assigned = label.clone();
+        let mut count = 1;
+
+        while let Some(definition) = self.find(&assigned) {
+            if definition.destination == destination {
+                return definition.label.clone();
+            }
+
+            count += 1;
+            assigned = format!("{label}-{count}");
+        }
+
+        self.definitions.push(Definition {
+            label: assigned.clone(),
+            destination: destination.to_owned(),
+            title: title.map(str::to_owned),
+        });
+
+        assigned
+    }
+
+    /// Register a destination under a label and create a reference link to it. The label is
+    /// omitted from the link (e.g. `[docs][]`) when it matches the text.
+    pub fn link(
+        &mut self,
+        text: &str,
+        label: &str,
+        destination: &str,
+        title: Option<&str>,
+    ) -> String {
+        let label = self.define(label, destination, title);
+
+        if normalize(text) == normalize(&label) {
+            format!("[{text}][]")
+        } else {
+            format!("[{text}][{}]", escape_label(&label))
+        }
+    }
+
+    /// Register an image source under a label and create a reference image to it.
+    pub fn image(&mut self, alt: &str, label: &str, source: &str, title: Option<&str>) -> String {
+        let alt = alt.replace('[', "\\[").replace(']', "\\]");
+
+        format!("!{}", self.link(&alt, label, source, title))
+    }
+
+    /// Whether no destinations have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Render the definitions of every registered destination, one per line, in the order they
+    /// were registered.
+    pub fn definitions(&self) -> String {
+        self.definitions
+            .iter()
+            .map(|definition| {
+                format!(
+                    "[{}]: {}{}",
+                    escape_label(&definition.label),
+                    escape_link_destination(&definition.destination),
+                    link_title(definition.title.as_deref())
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(&LF.to_string())
+    }
+
+    fn find(&self, label: &str) -> Option<&Definition> {
+        let label = normalize(label);
+
+        self.definitions
+            .iter()
+            .find(|definition| normalize(&definition.label) == label)
+    }
+}
+
+/// Normalize a label the way CommonMark matches them: case-insensitively and with whitespace
+/// collapsed.
+fn normalize(label: &str) -> String {
+    label.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+}
+
+fn escape_label(label: &str) -> String {
+    label.replace('[', "\\[").replace(']', "\\]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicting_labels_are_suffixed() {
+        let mut references = LinkReferences::new();
+
+        assert_eq!(references.define("Logo", "a.png", None), "Logo");
+        assert_eq!(references.define("logo", "b.png", None), "logo-2");
+        assert_eq!(references.image("[logo]", "logo", "b.png", None), "![\\[logo\\]][logo-2]");
+        assert_eq!(references.definitions(), "[Logo]: a.png\n[logo-2]: b.png");
+    }
+}
@@ -0,0 +1,363 @@
+//! Opt-in checks of generated Markdown.
+
+use crate::slug::Slugger;
+use crate::url::has_scheme;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// A problem found in a Markdown document.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Issue {
+    /// The 1-based line the problem starts on.
+    pub line: usize,
+    /// The kind of problem.
+    pub kind: IssueKind,
+}
+
+/// The kind of an [`Issue`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum IssueKind {
+    /// An image has no alt text, so it's invisible to screen reader users.
+    ImageWithoutAlt,
+    /// A link has no text, so screen readers have nothing to announce.
+    EmptyLinkText,
+    /// A link's text is its URL, which is meaningless when read aloud.
+    BareUrlLinkText,
+    /// A heading's level is more than one deeper than the preceding heading's.
+    SkippedHeadingLevel {
+        /// The level of the preceding heading.
+        from: u8,
+        /// The level of the offending heading.
+        to: u8,
+    },
+    /// A table's header row is entirely empty.
+    TableWithoutHeader,
+    /// A relative link or image path doesn't resolve to an existing file.
+    MissingFile {
+        /// The path the link resolved to.
+        path: PathBuf,
+    },
+    /// A link's `#fragment` doesn't match any heading in the linked document.
+    MissingFragment {
+        /// The linked document, or `None` for a link within the checked document.
+        path: Option<PathBuf>,
+        /// The fragment without its leading `#`.
+        fragment: String,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match self.kind {
+            IssueKind::ImageWithoutAlt => f.write_str("image has no alt text"),
+            IssueKind::EmptyLinkText => f.write_str("link has no text"),
+            IssueKind::BareUrlLinkText => f.write_str("link text is a bare URL"),
+            IssueKind::SkippedHeadingLevel { from, to } => {
+                write!(f, "heading level skips from {from} to {to}")
+            }
+            IssueKind::TableWithoutHeader => f.write_str("table has no header"),
+            IssueKind::MissingFile { ref path } => {
+                write!(f, "linked file {} doesn't exist", path.display())
+            }
+            IssueKind::MissingFragment { ref path, ref fragment } => match path {
+                Some(path) => write!(f, "{} has no heading #{fragment}", path.display()),
+                None => write!(f, "document has no heading #{fragment}"),
+            },
+        }
+    }
+}
+
+/// Check a Markdown document for basic accessibility problems: images without alt text, links
+/// with empty or bare URL text, heading levels which skip (e.g. h2 → h4) and tables without
+/// headers.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::lint::{accessibility, IssueKind};
+///
+/// let markdown = "## Install\n\n#### Linux\n\n![](screenshot.png)";
+/// let issues = accessibility(markdown);
+///
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].kind, IssueKind::SkippedHeadingLevel { from: 2, to: 4 });
+/// assert_eq!(issues[1].to_string(), "line 5: image has no alt text");
+/// ```
+pub fn accessibility(markdown: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut previous_level: Option<u8> = None;
+    // The text collected for the link, image or table head currently being parsed.
+    let mut text: Option<(String, usize)> = None;
+
+    for (event, range) in Parser::new_ext(markdown, lint_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                let level = level as u8;
+
+                if let Some(from) = previous_level.filter(|from| level > from + 1) {
+                    issues.push(Issue {
+                        line: line(markdown, &range),
+                        kind: IssueKind::SkippedHeadingLevel { from, to: level },
+                    });
+                }
+
+                previous_level = Some(level);
+            }
+            Event::Start(Tag::Image { .. } | Tag::Link { .. } | Tag::TableHead) => {
+                text = Some((String::new(), line(markdown, &range)));
+            }
+            Event::Text(value) | Event::Code(value) => {
+                if let Some((text, _)) = text.as_mut() {
+                    text.push_str(&value);
+                }
+            }
+            Event::End(end @ (TagEnd::Image | TagEnd::Link | TagEnd::TableHead)) => {
+                let Some((text, line)) = text.take() else {
+                    continue;
+                };
+                let text = text.trim();
+                let kind = match end {
+                    TagEnd::Image if text.is_empty() => IssueKind::ImageWithoutAlt,
+                    TagEnd::Link if text.is_empty() => IssueKind::EmptyLinkText,
+                    TagEnd::Link if is_bare_url(text) => IssueKind::BareUrlLinkText,
+                    TagEnd::TableHead if text.is_empty() => IssueKind::TableWithoutHeader,
+                    _ => continue,
+                };
+
+                issues.push(Issue { line, kind });
+            }
+            _ => {}
+        }
+    }
+
+    issues
+}
+
+/// Check that every relative link and image path in a Markdown document resolves to an
+/// existing file and that every `#fragment` matches a heading in the linked (or the same)
+/// document.
+///
+/// Paths are resolved against the provided base directory, which is usually the directory of
+/// the document. Paths beginning with a `/` are resolved against it as well, as GitHub resolves
+/// them against the root of the repository. Links with a scheme (e.g. `https:` or `mailto:`)
+/// aren't checked. Fragments are only checked in linked Markdown files, against the
+/// GitHub-style anchors of their headings.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::lint::{check_links, IssueKind};
+///
+/// let markdown = "# Intro\n\nSee [intro](#intro), [usage](#usage) and [guide](missing.md).";
+/// let issues = check_links(markdown, std::env::temp_dir());
+///
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].to_string(), "line 3: document has no heading #usage");
+/// assert!(matches!(issues[1].kind, IssueKind::MissingFile { .. }));
+/// ```
+pub fn check_links(markdown: &str, base_dir: impl AsRef<Path>) -> Vec<Issue> {
+    let base_dir = base_dir.as_ref();
+    let mut anchors: HashMap<PathBuf, Option<Vec<String>>> = HashMap::new();
+    let own_anchors = heading_anchors(markdown);
+    let mut issues = Vec::new();
+
+    for (event, range) in Parser::new_ext(markdown, lint_options()).into_offset_iter() {
+        let destination = match event {
+            Event::Start(Tag::Link { dest_url, .. }) => dest_url,
+            Event::Start(Tag::Image { dest_url, .. }) => dest_url,
+            _ => continue,
+        };
+
+        if has_scheme(&destination) {
+            continue;
+        }
+
+        let line = line(markdown, &range);
+        let (path, fragment) = match destination.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (destination.as_ref(), None),
+        };
+        let path = percent_decode(path.split('?').next().unwrap_or_default());
+
+        if path.is_empty() {
+            let missing = fragment.filter(|fragment| !own_anchors.contains(&fragment.to_string()));
+
+            if let Some(fragment) = missing {
+                issues.push(Issue {
+                    line,
+                    kind: IssueKind::MissingFragment { path: None, fragment: fragment.to_owned() },
+                });
+            }
+
+            continue;
+        }
+
+        let resolved = base_dir.join(path.trim_start_matches('/'));
+
+        if !resolved.exists() {
+            issues.push(Issue { line, kind: IssueKind::MissingFile { path: resolved } });
+            continue;
+        }
+
+        let Some(fragment) = fragment.filter(|fragment| !fragment.is_empty()) else {
+            continue;
+        };
+        let file_anchors = anchors.entry(resolved.clone()).or_insert_with(|| {
+            let is_markdown = resolved
+                .extension()
+                .is_some_and(|extension| extension == "md" || extension == "markdown");
+
+            is_markdown
+                .then(|| fs::read_to_string(&resolved).ok())
+                .flatten()
+                .map(|contents| heading_anchors(&contents))
+        });
+
+        if let Some(file_anchors) = file_anchors {
+            if !file_anchors.contains(&fragment.to_string()) {
+                issues.push(Issue {
+                    line,
+                    kind: IssueKind::MissingFragment {
+                        path: Some(resolved),
+                        fragment: fragment.to_owned(),
+                    },
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+fn lint_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_HEADING_ATTRIBUTES
+}
+
+/// The anchors of every heading in a Markdown document: explicit `{#id}` attributes and
+/// otherwise GitHub-style slugs of the heading text.
+fn heading_anchors(markdown: &str) -> Vec<String> {
+    let mut slugger = Slugger::default();
+    let mut anchors = Vec::new();
+    let mut heading: Option<String> = None;
+
+    for event in Parser::new_ext(markdown, lint_options()) {
+        match event {
+            Event::Start(Tag::Heading { id: Some(id), .. }) => anchors.push(id.to_string()),
+            Event::Start(Tag::Heading { id: None, .. }) => heading = Some(String::new()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = heading.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(heading) = heading.take() {
+                    anchors.push(slugger.slug(&heading));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    anchors
+}
+
+/// Decode `%XX` escapes in a link path, leaving malformed escapes as is.
+fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escape = bytes
+            .get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        match escape {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn is_bare_url(text: &str) -> bool {
+    ["http://", "https://", "www.", "mailto:"]
+        .iter()
+        .any(|prefix| text.starts_with(prefix))
+}
+
+/// The 1-based line on which the provided byte range starts.
+fn line(markdown: &str, range: &Range<usize>) -> usize {
+    markdown[..range.start].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessibility_flags_link_text() {
+        let markdown = "[](https://a.example)\n[https://b.example](https://b.example)\n[docs](x)";
+        let kinds: Vec<IssueKind> = accessibility(markdown)
+            .into_iter()
+            .map(|issue| issue.kind)
+            .collect();
+
+        assert_eq!(kinds, [IssueKind::EmptyLinkText, IssueKind::BareUrlLinkText]);
+    }
+
+    #[test]
+    fn accessibility_flags_tables_without_headers() {
+        let markdown = "Intro\n\n|   |   |\n|---|---|\n| a | b |";
+
+        assert_eq!(
+            accessibility(markdown),
+            [Issue { line: 3, kind: IssueKind::TableWithoutHeader }]
+        );
+    }
+
+    #[test]
+    fn check_links_checks_fragments_in_linked_files() {
+        let dir = std::env::temp_dir().join(format!("md-writer-links-{}", std::process::id()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("guide file.md"), "# Setup\n\n## Setup\n").unwrap();
+
+        let markdown = "[a](guide%20file.md#setup-1)\n[b](guide%20file.md#teardown)\n\
+                        [c](https://x.example)";
+        let issues = check_links(markdown, &dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            issues,
+            [Issue {
+                line: 2,
+                kind: IssueKind::MissingFragment {
+                    path: Some(dir.join("guide file.md")),
+                    fragment: "teardown".to_owned(),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn accessibility_accepts_descending_headings_by_one() {
+        assert!(accessibility("# A\n\n## B\n\n### C\n\n# D").is_empty());
+    }
+}
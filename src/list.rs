@@ -0,0 +1,509 @@
+//! Markdown lists.
+
+use crate::flavor::Flavor;
+use crate::strict::{self, Expected};
+use crate::write::{write_list_item, write_task_list, write_task_list_item, written};
+use crate::LF;
+use std::fmt;
+
+/// The numbering style of an ordered list.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Numbering {
+    /// `1.`, `2.`, `3.`
+    #[default]
+    Decimal,
+    /// `a.`, `b.`, `c.`
+    LowerAlpha,
+    /// `A.`, `B.`, `C.`
+    UpperAlpha,
+    /// `i.`, `ii.`, `iii.`
+    LowerRoman,
+    /// `I.`, `II.`, `III.`
+    UpperRoman,
+}
+
+impl Numbering {
+    /// The value of the HTML `<ol>` element's `type` attribute for this numbering style.
+    pub fn html_type(self) -> &'static str {
+        match self {
+            Numbering::Decimal => "1",
+            Numbering::LowerAlpha => "a",
+            Numbering::UpperAlpha => "A",
+            Numbering::LowerRoman => "i",
+            Numbering::UpperRoman => "I",
+        }
+    }
+
+    /// Format the provided 1-based item number in this numbering style.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::list::Numbering;
+    ///
+    /// assert_eq!(Numbering::LowerAlpha.format(28), "ab");
+    /// assert_eq!(Numbering::UpperRoman.format(14), "XIV");
+    /// ```
+    pub fn format(self, number: usize) -> String {
+        match self {
+            Numbering::Decimal => number.to_string(),
+            Numbering::LowerAlpha => alphabetic(number),
+            Numbering::UpperAlpha => alphabetic(number).to_uppercase(),
+            Numbering::LowerRoman => roman(number),
+            Numbering::UpperRoman => roman(number).to_uppercase(),
+        }
+    }
+}
+
+/// The state of a task list item.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TaskState {
+    /// `[ ]`
+    #[default]
+    Todo,
+    /// `[x]`
+    Done,
+    /// `[/]`, downgraded to `[ ]`.
+    InProgress,
+    /// `[-]`, or `[~]` (inapplicable) for GitLab, downgraded to `[x]` since the task is closed.
+    Cancelled,
+    /// `[>]`, downgraded to `[ ]`.
+    Forwarded,
+}
+
+impl TaskState {
+    /// The character between the brackets of a task list item in this state.
+    ///
+    /// Flavors which only support to do and done (e.g. GFM) get extended states downgraded to
+    /// the closest of the two.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{flavor::Flavor, list::TaskState};
+    ///
+    /// assert_eq!(TaskState::InProgress.marker(Flavor::Obsidian), '/');
+    /// assert_eq!(TaskState::InProgress.marker(Flavor::Gfm), ' ');
+    /// ```
+    pub fn marker(self, flavor: Flavor) -> char {
+        let extended = flavor.supports_extended_task_states();
+
+        match self {
+            TaskState::Todo => ' ',
+            TaskState::Done => 'x',
+            TaskState::Cancelled if flavor == Flavor::GitLab => '~',
+            TaskState::InProgress if extended => '/',
+            TaskState::Cancelled if extended => '-',
+            TaskState::Forwarded if extended => '>',
+            TaskState::InProgress | TaskState::Forwarded => ' ',
+            TaskState::Cancelled => 'x',
+        }
+    }
+}
+
+/// Create a Markdown task list item. Flavors without task lists, e.g. CommonMark, get a plain
+/// list item starting with a ☐ or ☑ ballot box instead.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, list::{task_list_item, TaskState}};
+///
+/// assert_eq!(task_list_item("Write docs", TaskState::Done, Flavor::Gfm), "- [x] Write docs");
+/// assert_eq!(task_list_item("Ship", TaskState::Cancelled, Flavor::Obsidian), "- [-] Ship");
+/// assert_eq!(task_list_item("Ship", TaskState::Todo, Flavor::CommonMark), "- ☐ Ship");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#task-list-items-extension->
+pub fn task_list_item(text: &str, state: TaskState, flavor: Flavor) -> String {
+    written(|out| write_task_list_item(out, text, state, flavor))
+}
+
+/// Create a Markdown task list.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, list::{task_list, TaskState}};
+///
+/// let items = [(TaskState::Done, "Design"), (TaskState::InProgress, "Build")];
+///
+/// assert_eq!(task_list(&items, Flavor::Obsidian), "- [x] Design\n- [/] Build");
+/// assert_eq!(task_list(&items, Flavor::Gfm), "- [x] Design\n- [ ] Build");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#task-list-items-extension->
+pub fn task_list(items: &[(TaskState, &str)], flavor: Flavor) -> String {
+    let list = written(|out| write_task_list(out, items, flavor));
+
+    if !items.is_empty() {
+        strict::verify(&list, Expected::List(items.len()));
+    }
+
+    list
+}
+
+/// Create an ordered list numbered with letters or roman numerals, e.g. for legal-style
+/// documents.
+///
+/// Flavors which support fancy list markers get them directly (`a.`, `iv.`). For everything
+/// else non-decimal numbering falls back to an HTML `<ol type="…">` element. Note that most
+/// renderers don't process Markdown inside of an HTML block, so the items of the fallback
+/// should be plain text or HTML.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, list::{fancy_ordered_list, Numbering}};
+///
+/// let items = ["Definitions", "Obligations"];
+///
+/// assert_eq!(
+///     fancy_ordered_list(&items, Numbering::LowerRoman, Flavor::Pandoc),
+///     "i. Definitions\nii. Obligations"
+/// );
+/// assert_eq!(
+///     fancy_ordered_list(&items, Numbering::LowerRoman, Flavor::Gfm),
+///     "<ol type=\"i\">\n<li>Definitions</li>\n<li>Obligations</li>\n</ol>"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://pandoc.org/MANUAL.html#extension-fancy_lists>
+/// - <https://html.spec.whatwg.org/multipage/grouping-content.html#attr-ol-type>
+pub fn fancy_ordered_list(items: &[&str], numbering: Numbering, flavor: Flavor) -> String {
+    if numbering == Numbering::Decimal || flavor.supports_fancy_lists() {
+        // Pandoc requires two spaces after a capital letter and a period to tell the marker
+        // apart from an initial.
+        let spacing = if numbering == Numbering::UpperAlpha { "  " } else { " " };
+        let list = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| format!("{}.{spacing}{item}", numbering.format(index + 1)))
+            .collect::<Vec<String>>()
+            .join(&LF.to_string());
+
+        if numbering == Numbering::Decimal && !items.is_empty() {
+            strict::verify(&list, Expected::List(items.len()));
+        }
+
+        list
+    } else {
+        let mut lines = vec![format!("<ol type=\"{}\">", numbering.html_type())];
+
+        lines.extend(items.iter().map(|item| format!("<li>{item}</li>")));
+        lines.push("</ol>".to_owned());
+        lines.join(&LF.to_string())
+    }
+}
+
+/// A builder of bullet lists whose items may contain child lists.
+///
+/// Child lists (or any other blocks) are rendered separately and indented under their parent
+/// item, so bullet and ordered lists may be nested within each other to any depth.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::list::BulletList;
+///
+/// let platforms = BulletList::new().item("Linux").item("macOS").render();
+/// let list = BulletList::new()
+///     .item_with_children("Supported platforms", &platforms)
+///     .item("Multi-line\nitems");
+///
+/// assert_eq!(
+///     list.render(),
+///     "- Supported platforms\n  - Linux\n  - macOS\n- Multi-line\n  items"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#lists>
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BulletList {
+    items: Vec<(String, Option<String>)>,
+}
+
+impl BulletList {
+    /// Create an empty list.
+    pub fn new() -> BulletList {
+        BulletList::default()
+    }
+
+    /// Append an item.
+    pub fn item(mut self, text: &str) -> BulletList {
+        self.items.push((text.to_owned(), None));
+        self
+    }
+
+    /// Append an item followed by child Markdown, typically a rendered list, which is indented
+    /// under the item.
+    pub fn item_with_children(mut self, text: &str, children: &str) -> BulletList {
+        self.items.push((text.to_owned(), Some(children.to_owned())));
+        self
+    }
+
+    /// Render the list.
+    pub fn render(&self) -> String {
+        let list = written(|out| self.write(out));
+
+        if !self.items.is_empty() {
+            strict::verify(&list, Expected::List(self.items.len()));
+        }
+
+        list
+    }
+
+    /// Write the list, see [`write_bullet_list`](crate::write::write_bullet_list).
+    pub(crate) fn write(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        for (index, (text, children)) in self.items.iter().enumerate() {
+            if index > 0 {
+                out.write_char(LF)?;
+            }
+
+            write_list_item(out, "- ", text, children.as_deref())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder of ordered lists whose items may contain child lists.
+///
+/// Lists start at 1 unless told otherwise. With lazy numbering every item gets the start
+/// number, which renderers number sequentially anyway, so inserting an item doesn't renumber
+/// the following ones in the source. Child lists are indented to the width of their parent
+/// item's marker, e.g. four spaces under `10.`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::list::{BulletList, OrderedList};
+///
+/// let options = BulletList::new().item("`--release`").render();
+/// let steps = OrderedList::new()
+///     .start(9)
+///     .item("Install")
+///     .item_with_children("Build", &options);
+///
+/// assert_eq!(steps.render(), "9. Install\n10. Build\n    - `--release`");
+/// assert_eq!(steps.lazy_numbering(true).render(), "9. Install\n9. Build\n   - `--release`");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#ordered-list>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OrderedList {
+    items: Vec<(String, Option<String>)>,
+    start: u64,
+    lazy_numbering: bool,
+}
+
+impl Default for OrderedList {
+    fn default() -> OrderedList {
+        OrderedList { items: Vec::new(), start: 1, lazy_numbering: false }
+    }
+}
+
+impl OrderedList {
+    /// Create an empty list starting at 1.
+    pub fn new() -> OrderedList {
+        OrderedList::default()
+    }
+
+    /// Set the number of the first item. CommonMark list numbers have at most 9 digits, so
+    /// starts which would number an item above [`MAX_ORDERED_NUMBER`] are lowered when rendered.
+    pub fn start(self, start: u64) -> OrderedList {
+        OrderedList { start, ..self }
+    }
+
+    /// Set whether every item is numbered with the start number.
+    pub fn lazy_numbering(self, lazy_numbering: bool) -> OrderedList {
+        OrderedList { lazy_numbering, ..self }
+    }
+
+    /// Append an item.
+    pub fn item(mut self, text: &str) -> OrderedList {
+        self.items.push((text.to_owned(), None));
+        self
+    }
+
+    /// Append an item followed by child Markdown, typically a rendered list, which is indented
+    /// under the item.
+    pub fn item_with_children(mut self, text: &str, children: &str) -> OrderedList {
+        self.items.push((text.to_owned(), Some(children.to_owned())));
+        self
+    }
+
+    /// Render the list.
+    pub fn render(&self) -> String {
+        let list = written(|out| self.write(out));
+
+        if !self.items.is_empty() {
+            strict::verify(&list, Expected::List(self.items.len()));
+        }
+
+        list
+    }
+
+    /// Write the list, see [`write_ordered_list`](crate::write::write_ordered_list).
+    pub(crate) fn write(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        let count = if self.lazy_numbering { 1 } else { self.items.len() };
+        let start = clamp_start(self.start, count);
+
+        for (index, (text, children)) in self.items.iter().enumerate() {
+            let offset = if self.lazy_numbering { 0 } else { index as u64 };
+            let number = start.checked_add(offset).unwrap_or(MAX_ORDERED_NUMBER);
+
+            if index > 0 {
+                out.write_char(LF)?;
+            }
+
+            write_list_item(out, &format!("{number}. "), text, children.as_deref())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The largest number of an ordered list item, as CommonMark allows at most 9 digits.
+pub const MAX_ORDERED_NUMBER: u64 = 999_999_999;
+
+/// Lower the start number of an ordered list of the provided number of items so that none is
+/// numbered above [`MAX_ORDERED_NUMBER`].
+pub(crate) fn clamp_start(start: u64, items: usize) -> u64 {
+    let last_offset = (items as u64).saturating_sub(1);
+
+    start.min(MAX_ORDERED_NUMBER.saturating_sub(last_offset))
+}
+
+/// Render a list item, indenting its continuation lines and children to the width of its
+/// marker so they stay part of the item.
+pub(crate) fn list_item(marker: &str, text: &str, children: Option<&str>) -> String {
+    written(|out| write_list_item(out, marker, text, children))
+}
+
+/// Format a 1-based number as letters, continuing `…, y, z, aa, ab, …` past the alphabet.
+fn alphabetic(mut number: usize) -> String {
+    let mut letters = Vec::new();
+
+    while number > 0 {
+        number -= 1;
+        letters.push(char::from(b'a' + (number % 26) as u8));
+        number /= 26;
+    }
+
+    letters.iter().rev().collect()
+}
+
+/// Format a number as lowercase roman numerals.
+fn roman(mut number: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+
+    let mut numerals = String::new();
+
+    for (value, numeral) in NUMERALS {
+        while number >= value {
+            numerals.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    numerals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_state_downgrades_for_strict_targets() {
+        let states = [TaskState::Forwarded, TaskState::Cancelled];
+        let markers: Vec<char> = states.iter().map(|state| state.marker(Flavor::Gfm)).collect();
+
+        assert_eq!(markers, [' ', 'x']);
+    }
+
+    #[test]
+    fn task_state_cancelled_is_inapplicable_for_gitlab() {
+        assert_eq!(TaskState::Cancelled.marker(Flavor::GitLab), '~');
+        assert_eq!(TaskState::InProgress.marker(Flavor::GitLab), ' ');
+    }
+
+    #[test]
+    fn task_list_falls_back_to_ballot_boxes_without_task_lists() {
+        let items = [(TaskState::Done, "a"), (TaskState::InProgress, "b")];
+
+        assert_eq!(task_list(&items, Flavor::CommonMark), "- ☑ a\n- ☐ b");
+        assert_eq!(task_list(&items, Flavor::AzureDevOps), "- [x] a\n- [ ] b");
+    }
+
+    #[test]
+    fn bullet_list_nests_children_to_any_depth() {
+        let innermost = BulletList::new().item("c").render();
+        let inner = BulletList::new().item_with_children("b", &innermost).render();
+        let list = BulletList::new().item_with_children("a", &inner).item("d");
+
+        assert_eq!(list.render(), "- a\n  - b\n    - c\n- d");
+    }
+
+    #[test]
+    fn ordered_list_nests_under_bullet_lists() {
+        let steps = OrderedList::new().start(0).item("a").item("b").render();
+        let list = BulletList::new().item_with_children("Steps", &steps);
+
+        assert_eq!(list.render(), "- Steps\n  0. a\n  1. b");
+    }
+
+    #[test]
+    fn roman_formats_subtractive_numerals() {
+        assert_eq!(roman(1994), "mcmxciv");
+    }
+
+    #[test]
+    fn fancy_ordered_list_pads_uppercase_letters_for_pandoc() {
+        let list = fancy_ordered_list(&["First", "Second"], Numbering::UpperAlpha, Flavor::Pandoc);
+
+        assert_eq!(list, "A.  First\nB.  Second");
+    }
+
+    #[test]
+    fn fancy_ordered_list_is_plain_markdown_when_decimal() {
+        let list = fancy_ordered_list(&["First", "Second"], Numbering::Decimal, Flavor::Gfm);
+
+        assert_eq!(list, "1. First\n2. Second");
+    }
+
+    #[test]
+    fn ordered_list_start_is_lowered_to_nine_digits() {
+        let list = OrderedList::new().start(u64::MAX).item("a").item("b");
+
+        assert_eq!(list.render(), "999999998. a\n999999999. b");
+        assert_eq!(
+            OrderedList::new().start(1_000_000_000).lazy_numbering(true).item("a").render(),
+            "999999999. a"
+        );
+    }
+}
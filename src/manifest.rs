@@ -0,0 +1,218 @@
+//! Documentation generated from Cargo manifests.
+
+use crate::code_span;
+use crate::table::pipe_table;
+use std::collections::BTreeSet;
+
+/// A feature declared in the `[features]` table of a Cargo manifest.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Feature {
+    /// The name of the feature.
+    pub name: String,
+    /// Whether the feature is enabled by default, directly or through another default feature.
+    pub default: bool,
+    /// The description from the `##` comments preceding the feature, if any.
+    pub description: String,
+    /// The features and dependencies the feature enables, e.g. `dep:serde` or `serde/std`.
+    pub enables: Vec<String>,
+}
+
+/// Read the features declared in a Cargo manifest, in declaration order.
+///
+/// The `default` feature itself is omitted. Descriptions are taken from `##` comments preceding
+/// a feature, as popularized by the `document-features` crate; `#!` comments and ordinary `#`
+/// comments are ignored.
+///
+/// Examples
+/// ========
+/// ```
+/// let manifest = "[features]\ndefault = [\"std\"]\n## Use the standard library.\nstd = []\n";
+/// let features = md_writer::manifest::features(manifest);
+///
+/// assert_eq!(features[0].name, "std");
+/// assert!(features[0].default);
+/// assert_eq!(features[0].description, "Use the standard library.");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://doc.rust-lang.org/cargo/reference/features.html>
+/// - <https://docs.rs/document-features>
+pub fn features(manifest: &str) -> Vec<Feature> {
+    let mut features = Vec::new();
+    let mut defaults = Vec::new();
+    let mut description = Vec::new();
+    let mut in_features = false;
+    let mut lines = manifest.lines();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+
+        if line.starts_with('[') && !line.starts_with("[[") && line.ends_with(']') {
+            in_features = line == "[features]";
+            description.clear();
+            continue;
+        }
+
+        if !in_features {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix("##") {
+            description.push(comment.trim());
+            continue;
+        }
+
+        let Some((name, value)) = line.split_once('=') else {
+            if !line.starts_with('#') {
+                description.clear();
+            }
+            continue;
+        };
+        let mut value = strip_comment(value).to_owned();
+
+        while value.matches('[').count() > value.matches(']').count() {
+            match lines.next() {
+                Some(line) => value.push_str(strip_comment(line)),
+                None => break,
+            }
+        }
+
+        let name = name.trim().trim_matches('"').to_owned();
+        let enables = value
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|item| item.trim().trim_matches('"').to_owned())
+            .filter(|item| !item.is_empty())
+            .collect();
+
+        if name == "default" {
+            defaults = enables;
+        } else {
+            features.push(Feature {
+                name,
+                default: false,
+                description: description.join(" "),
+                enables,
+            });
+        }
+
+        description.clear();
+    }
+
+    let mut enabled = BTreeSet::new();
+
+    while let Some(name) = defaults.pop() {
+        if enabled.insert(name.clone()) {
+            if let Some(feature) = features.iter().find(|feature| feature.name == name) {
+                defaults.extend(feature.enables.iter().cloned());
+            }
+        }
+    }
+
+    for feature in &mut features {
+        feature.default = enabled.contains(&feature.name);
+    }
+
+    features
+}
+
+/// Render a table of the features declared in a Cargo manifest with their default status and
+/// description, e.g. for the "Features" section of a README.
+///
+/// Examples
+/// ========
+/// ```
+/// let manifest = r#"
+/// [features]
+/// default = ["std"]
+/// ### Use the standard library.
+/// std = []
+/// ### Serialize with [Serde](https://serde.rs).
+/// serde = ["dep:serde"]
+/// "#;
+///
+/// assert_eq!(
+///     md_writer::manifest::feature_table(manifest),
+///     "| Feature | Default | Description |\n\
+///      | --- | --- | --- |\n\
+///      | `std` | Yes | Use the standard library. |\n\
+///      | `serde` | No | Serialize with [Serde](https://serde.rs). |"
+/// );
+/// ```
+pub fn feature_table(manifest: &str) -> String {
+    let headers = ["Feature", "Default", "Description"].map(String::from);
+    let rows: Vec<Vec<String>> = features(manifest)
+        .into_iter()
+        .map(|feature| {
+            let default = if feature.default { "Yes" } else { "No" };
+
+            vec![code_span(&feature.name), default.to_owned(), feature.description]
+        })
+        .collect();
+
+    pipe_table(&headers, &rows)
+}
+
+/// Strip a trailing comment from a line of TOML, leaving comment markers in strings alone.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+
+    for (index, char) in line.char_indices() {
+        match char {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_follow_default_transitively_across_multi_line_arrays() {
+        let manifest = r#"
+[package]
+name = "x"
+
+[features]
+default = [
+  "full", # all
+]
+full = ["a", "b"]
+a = []
+b = []
+c = []
+
+[dependencies]
+d = "1"
+"#;
+        let defaults: Vec<(String, bool)> = features(manifest)
+            .into_iter()
+            .map(|feature| (feature.name, feature.default))
+            .collect();
+
+        assert_eq!(
+            defaults,
+            [
+                ("full".to_owned(), true),
+                ("a".to_owned(), true),
+                ("b".to_owned(), true),
+                ("c".to_owned(), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn features_ignore_inner_and_ordinary_comments() {
+        let manifest = "[features]\n#! ## Optional\n# internal\n## Described.\n## Twice.\nx = []";
+
+        assert_eq!(features(manifest)[0].description, "Described. Twice.");
+    }
+}
@@ -0,0 +1,242 @@
+//! Deterministic rendering of map-backed content.
+//!
+//! Iterating a [`HashMap`] yields its entries in an arbitrary order
+//! which differs between runs, so rendering one directly produces documents which aren't
+//! reproducible. Everything in this module which renders keyed content takes a [`KeyOrder`]
+//! which defaults to sorting by key.
+
+use crate::escape::{escape_text, EscapeMode};
+use crate::list::list_item;
+use crate::strict::{self, Expected};
+use crate::table::pipe_table;
+use crate::LF;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::BuildHasher;
+
+/// The order in which keyed entries are rendered.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum KeyOrder {
+    /// Sort the entries by key so the output is stable regardless of iteration order.
+    #[default]
+    Sorted,
+    /// Render the entries in the order they were provided in, e.g. for a `Vec` of pairs or an
+    /// insertion ordered map. This is only deterministic when the source iteration order is.
+    Insertion,
+}
+
+impl KeyOrder {
+    /// Collect the provided entries into a `Vec` ordered per this key order.
+    ///
+    /// Sorting is stable, so entries with equal keys retain their relative order.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::map::KeyOrder;
+    ///
+    /// let entries = [("b", 2), ("a", 1)];
+    ///
+    /// assert_eq!(KeyOrder::Sorted.arrange(entries), [("a", 1), ("b", 2)]);
+    /// assert_eq!(KeyOrder::Insertion.arrange(entries), [("b", 2), ("a", 1)]);
+    /// ```
+    pub fn arrange<K: Ord, V>(self, entries: impl IntoIterator<Item = (K, V)>) -> Vec<(K, V)> {
+        let mut entries: Vec<(K, V)> = entries.into_iter().collect();
+
+        if self == KeyOrder::Sorted {
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+
+        entries
+    }
+}
+
+/// Create a Markdown bullet list of `key: value` items from keyed entries.
+///
+/// Keys and values are escaped as plain text, and the continuation lines of multi-line values
+/// are indented so they stay part of their item.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::map::{key_value_list, KeyOrder};
+/// use std::collections::HashMap;
+///
+/// let map = HashMap::from([("version", "1.2.0"), ("edition", "2021")]);
+/// let list = key_value_list(&map, KeyOrder::Sorted);
+///
+/// assert_eq!(list, "- edition: 2021\n- version: 1.2.0");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#lists>
+pub fn key_value_list<K, V>(entries: impl IntoIterator<Item = (K, V)>, order: KeyOrder) -> String
+where
+    K: Display + Ord,
+    V: Display,
+{
+    let items: Vec<String> = order
+        .arrange(entries)
+        .into_iter()
+        .map(|(key, value)| {
+            let text = format!("{key}: {value}");
+
+            list_item("- ", &escape_text(&text, EscapeMode::Minimal), None)
+        })
+        .collect();
+    let list = items.join(&LF.to_string());
+
+    if !items.is_empty() {
+        strict::verify(&list, Expected::List(items.len()));
+    }
+
+    list
+}
+
+/// A value which can be rendered in a map table, either a scalar or a nested map.
+///
+/// Nested maps are flattened into one row per scalar with their keys joined by dots, e.g.
+/// `server.port`.
+pub trait MapValue {
+    /// Push the `(key, value)` rows of this value onto the provided rows, prefixing keys with
+    /// the provided key.
+    fn flatten(&self, key: String, order: KeyOrder, rows: &mut Vec<(String, String)>);
+}
+
+macro_rules! impl_scalar_map_value {
+    ($($ty:ty),*) => {
+        $(
+            impl MapValue for $ty {
+                fn flatten(&self, key: String, _order: KeyOrder, rows: &mut Vec<(String, String)>) {
+                    rows.push((key, self.to_string()));
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_map_value!(
+    bool, char, str, String, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize
+);
+
+impl<T: MapValue + ?Sized> MapValue for &T {
+    fn flatten(&self, key: String, order: KeyOrder, rows: &mut Vec<(String, String)>) {
+        (**self).flatten(key, order, rows);
+    }
+}
+
+impl<T: MapValue> MapValue for Option<T> {
+    fn flatten(&self, key: String, order: KeyOrder, rows: &mut Vec<(String, String)>) {
+        match self {
+            Some(value) => value.flatten(key, order, rows),
+            None => rows.push((key, String::new())),
+        }
+    }
+}
+
+impl<K: Display + Ord, V: MapValue> MapValue for BTreeMap<K, V> {
+    fn flatten(&self, key: String, order: KeyOrder, rows: &mut Vec<(String, String)>) {
+        flatten_entries(self, key, order, rows);
+    }
+}
+
+impl<K: Display + Ord, V: MapValue, S: BuildHasher> MapValue for HashMap<K, V, S> {
+    fn flatten(&self, key: String, order: KeyOrder, rows: &mut Vec<(String, String)>) {
+        flatten_entries(self, key, order, rows);
+    }
+}
+
+fn flatten_entries<'a, K, V>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+    key: String,
+    order: KeyOrder,
+    rows: &mut Vec<(String, String)>,
+) where
+    K: Display + Ord + 'a,
+    V: MapValue + 'a,
+{
+    for (child, value) in order.arrange(entries) {
+        let child = if key.is_empty() { child.to_string() } else { format!("{key}.{child}") };
+
+        value.flatten(child, order, rows);
+    }
+}
+
+/// Create a Markdown table of a map's keys and values, flattening nested maps into dotted keys.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::map::{map_table, KeyOrder};
+/// use std::collections::HashMap;
+///
+/// let server = HashMap::from([("port", 8080), ("workers", 4)]);
+/// let config = HashMap::from([("server", server)]);
+///
+/// assert_eq!(
+///     map_table(&config, KeyOrder::Sorted),
+///     "| Key | Value |\n| --- | --- |\n| server.port | 8080 |\n| server.workers | 4 |"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+pub fn map_table<M: MapValue + ?Sized>(map: &M, order: KeyOrder) -> String {
+    let mut rows = Vec::new();
+
+    map.flatten(String::new(), order, &mut rows);
+
+    let rows: Vec<Vec<String>> = rows.into_iter().map(|(key, value)| vec![key, value]).collect();
+
+    pipe_table(&["Key".to_owned(), "Value".to_owned()], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn sorted_output_is_independent_of_iteration_order() {
+        let map: HashMap<String, usize> = (0..32).map(|n| (format!("key{n:02}"), n)).collect();
+        let list = key_value_list(&map, KeyOrder::Sorted);
+        let expected = (0..32)
+            .map(|n| format!("- key{n:02}: {n}"))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        assert_eq!(list, expected);
+    }
+
+    #[test]
+    fn map_table_flattens_deeply_nested_maps() {
+        let mut config = BTreeMap::new();
+        let mut tls = BTreeMap::new();
+
+        tls.insert("cert", "server.pem");
+        config.insert("b", BTreeMap::from([("tls", tls)]));
+        config.insert("a", BTreeMap::new());
+
+        assert_eq!(
+            map_table(&config, KeyOrder::Sorted),
+            "| Key | Value |\n| --- | --- |\n| b.tls.cert | server.pem |"
+        );
+    }
+
+    #[test]
+    fn keys_and_values_are_escaped_and_kept_in_their_items() {
+        let list = key_value_list([("# key", "*a*\n- b"), ("c", "d")], KeyOrder::Insertion);
+
+        assert_eq!(list, "- \\# key: \\*a\\*\n  \\- b\n- c: d");
+    }
+
+    #[test]
+    fn insertion_order_is_preserved() {
+        let list = key_value_list([("z", 1), ("a", 2)], KeyOrder::Insertion);
+
+        assert_eq!(list, "- z: 1\n- a: 2");
+    }
+}
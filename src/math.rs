@@ -0,0 +1,107 @@
+//! TeX math, as rendered by GitHub, GitLab, Pandoc and MathJax or KaTeX based renderers.
+
+use crate::{fenced_code_block, LF};
+
+/// Create inline math delimited by single dollar signs, e.g. `$x^2$`.
+///
+/// The TeX is trimmed, as renderers don't recognize math which begins or ends with whitespace,
+/// and unescaped dollar signs in it are escaped so they don't end the math. Empty or blank TeX
+/// creates nothing, since `$$` would open display math instead.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::math::inline_math;
+///
+/// assert_eq!(inline_math(" \\sqrt{x} "), "$\\sqrt{x}$");
+/// assert_eq!(inline_math("5$ + \\$3"), "$5\\$ + \\$3$");
+/// assert_eq!(inline_math(" "), "");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/writing-mathematical-expressions>
+/// - <https://pandoc.org/MANUAL.html#extension-tex_math_dollars>
+pub fn inline_math(tex: &str) -> String {
+    let tex = tex.trim();
+
+    if tex.is_empty() {
+        return String::new();
+    }
+
+    format!("${}$", escape_dollars(tex))
+}
+
+/// Create display math delimited by double dollar signs on lines of their own.
+///
+/// Unescaped dollar signs in the TeX are escaped so they don't end the math.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::math::math_block;
+///
+/// assert_eq!(
+///     math_block("\\bar{x} = \\frac{1}{n} \\sum_{i=1}^{n} x_i"),
+///     "$$\n\\bar{x} = \\frac{1}{n} \\sum_{i=1}^{n} x_i\n$$"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/writing-mathematical-expressions>
+/// - <https://pandoc.org/MANUAL.html#extension-tex_math_dollars>
+pub fn math_block(tex: &str) -> String {
+    format!("$${LF}{}{LF}$$", escape_dollars(tex.trim_matches(LF)))
+}
+
+/// Create display math in a code block fenced with a `math` info string, as GitHub and GitLab
+/// support. The TeX doesn't need escaping, and renderers without math support show it as code.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::math::fenced_math_block;
+///
+/// assert_eq!(fenced_math_block("e^{i\\pi} + 1 = 0"), "```math\ne^{i\\pi} + 1 = 0\n```");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/writing-mathematical-expressions#writing-expressions-as-blocks>
+pub fn fenced_math_block(tex: &str) -> String {
+    fenced_code_block(tex, Some("math"))
+}
+
+/// Escape the dollar signs which aren't already escaped by a backslash.
+fn escape_dollars(tex: &str) -> String {
+    let mut escaped = String::with_capacity(tex.len());
+    let mut backslashes = 0;
+
+    for char in tex.chars() {
+        if char == '$' && backslashes % 2 == 0 {
+            escaped.push('\\');
+        }
+
+        backslashes = if char == '\\' { backslashes + 1 } else { 0 };
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_dollars_counts_preceding_backslashes() {
+        assert_eq!(escape_dollars(r"\\$ \$ $$"), r"\\\$ \$ \$\$");
+    }
+
+    #[test]
+    fn inline_math_of_blank_tex_is_empty() {
+        assert_eq!(inline_math(""), "");
+        assert_eq!(inline_math(" \n\t"), "");
+    }
+}
@@ -0,0 +1,102 @@
+//! Comparison matrices, e.g. of the features of a crate and its alternatives.
+
+use crate::flavor::Flavor;
+use crate::table::pipe_table;
+
+/// The extent to which an alternative supports a feature.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Support {
+    /// The feature is supported.
+    Yes,
+    /// The feature isn't supported.
+    No,
+    /// The feature is partially supported.
+    Partial,
+    /// The feature doesn't apply to the alternative.
+    NotApplicable,
+    /// Free-form Markdown, e.g. a version number or a footnote reference.
+    Text(String),
+}
+
+impl Support {
+    /// Render the cell as an emoji when the flavor renders them and as plain text otherwise.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{flavor::Flavor, matrix::Support};
+    ///
+    /// assert_eq!(Support::Yes.render(Flavor::Gfm), "✅");
+    /// assert_eq!(Support::Yes.render(Flavor::Pandoc), "Yes");
+    /// ```
+    pub fn render(&self, flavor: Flavor) -> String {
+        let (emoji, text) = match self {
+            Support::Yes => ("✅", "Yes"),
+            Support::No => ("❌", "No"),
+            Support::Partial => ("⚠️", "Partial"),
+            Support::NotApplicable => ("➖", "N/A"),
+            Support::Text(text) => return text.clone(),
+        };
+
+        if flavor.renders_emoji() { emoji } else { text }.to_owned()
+    }
+}
+
+/// Render a comparison matrix with a row per feature and a column per alternative.
+///
+/// Rows are pairs of a feature and its support by each alternative, in the order of the column
+/// labels.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, matrix::{comparison_matrix, Support}};
+///
+/// let rows = [
+///     ("Tables", vec![Support::Yes, Support::Partial]),
+///     ("Footnotes", vec![Support::Yes, Support::Text("Since 2.0".to_owned())]),
+/// ];
+///
+/// assert_eq!(
+///     comparison_matrix(&["md-writer", "Other"], &rows, Flavor::Gfm),
+///     "|  | md-writer | Other |\n\
+///      | --- | --- | --- |\n\
+///      | Tables | ✅ | ⚠️ |\n\
+///      | Footnotes | ✅ | Since 2.0 |"
+/// );
+/// ```
+pub fn comparison_matrix(
+    column_labels: &[&str],
+    rows: &[(&str, Vec<Support>)],
+    flavor: Flavor,
+) -> String {
+    let headers: Vec<String> = [""]
+        .iter()
+        .chain(column_labels)
+        .map(|label| label.to_string())
+        .collect();
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|(feature, cells)| {
+            [feature.to_string()]
+                .into_iter()
+                .chain(cells.iter().map(|cell| cell.render(flavor)))
+                .collect()
+        })
+        .collect();
+
+    pipe_table(&headers, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparison_matrix_uses_plain_text_without_emoji() {
+        let rows = [("Async", vec![Support::No, Support::NotApplicable])];
+
+        assert!(comparison_matrix(&["a", "b"], &rows, Flavor::CommonMark)
+            .ends_with("| Async | No | N/A |"));
+    }
+}
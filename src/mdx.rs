@@ -0,0 +1,194 @@
+//! MDX, Markdown with JSX components as used by Docusaurus among others.
+
+use crate::LF;
+
+/// A builder of JSX component usages, either self-closing (`<Image src="a.png" />`) or
+/// wrapping Markdown children (`<Tabs>…</Tabs>`).
+///
+/// String props are delimited by whichever of `"` and `'` they don't contain, since JSX
+/// strings have no escape sequences, and otherwise written as a JavaScript string expression.
+/// Children are separated from the tags by blank lines, which MDX requires for them to be
+/// parsed as Markdown blocks rather than inline content.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::mdx::Component;
+///
+/// let item = Component::new("TabItem")
+///     .prop("value", "rust")
+///     .prop("label", "Rust")
+///     .flag("default")
+///     .render_with("```rust\nfn main() {}\n```");
+///
+/// assert_eq!(
+///     Component::new("Tabs").expression("groupId", "\"lang\"").render_with(&item),
+///     "<Tabs groupId={\"lang\"}>\n\n<TabItem value=\"rust\" label=\"Rust\" default>\n\n\
+///      ```rust\nfn main() {}\n```\n\n</TabItem>\n\n</Tabs>"
+/// );
+/// assert_eq!(
+///     Component::new("Badge").prop("text", "\"New\" & 'improved'").render(),
+///     "<Badge text={\"\\\"New\\\" & 'improved'\"} />"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://mdxjs.com/docs/what-is-mdx/#jsx>
+/// - <https://docusaurus.io/docs/markdown-features/react>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Component<'a> {
+    name: &'a str,
+    props: Vec<(&'a str, Option<String>)>,
+}
+
+impl<'a> Component<'a> {
+    /// Create a component usage with the provided name and no props.
+    pub fn new(name: &'a str) -> Component<'a> {
+        Component { name, props: Vec::new() }
+    }
+
+    /// Add a string prop, e.g. `label="Rust"`.
+    pub fn prop(mut self, key: &'a str, value: &str) -> Component<'a> {
+        self.props.push((key, Some(quote(value))));
+        self
+    }
+
+    /// Add a prop whose value is a JavaScript expression, e.g. `width={300}`.
+    pub fn expression(mut self, key: &'a str, expression: &str) -> Component<'a> {
+        self.props.push((key, Some(format!("{{{expression}}}"))));
+        self
+    }
+
+    /// Add a boolean prop which is set by its presence, e.g. `default`.
+    pub fn flag(mut self, key: &'a str) -> Component<'a> {
+        self.props.push((key, None));
+        self
+    }
+
+    /// Render the component as a self-closing one.
+    pub fn render(&self) -> String {
+        format!("<{}{} />", self.name, self.props())
+    }
+
+    /// Render the component around the provided Markdown children.
+    pub fn render_with(&self, children: &str) -> String {
+        let children = children.trim_matches(LF);
+        let opening = format!("<{}{}>", self.name, self.props());
+
+        if children.is_empty() {
+            format!("{opening}</{}>", self.name)
+        } else {
+            format!("{opening}{LF}{LF}{children}{LF}{LF}</{}>", self.name)
+        }
+    }
+
+    fn props(&self) -> String {
+        self.props
+            .iter()
+            .map(|(key, value)| match value {
+                Some(value) => format!(" {key}={value}"),
+                None => format!(" {key}"),
+            })
+            .collect()
+    }
+}
+
+/// Create an ES module import of a component's default export, e.g. Docusaurus' `Tabs`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::mdx::import;
+///
+/// assert_eq!(import("Tabs", "@theme/Tabs"), "import Tabs from '@theme/Tabs';");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://mdxjs.com/docs/what-is-mdx/#esm>
+pub fn import(name: &str, source: &str) -> String {
+    format!("import {name} from {};", js_string(source, '\''))
+}
+
+/// Escape the characters MDX would otherwise take for the start of JSX or an expression (`<`,
+/// `{` and `}`) in text, so it renders literally.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::mdx::escape_mdx;
+///
+/// assert_eq!(escape_mdx("Use Vec<T> or {}"), r"Use Vec\<T> or \{\}");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://mdxjs.com/docs/troubleshooting-mdx/#could-not-parse-expression-with-acorn-error>
+pub fn escape_mdx(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if matches!(char, '<' | '{' | '}') {
+            escaped.push('\\');
+        }
+
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+/// Quote a string prop value with a delimiter it doesn't contain, falling back to an
+/// expression of a JavaScript string.
+fn quote(value: &str) -> String {
+    match ['"', '\''].into_iter().find(|quote| !value.contains(*quote)) {
+        Some(quote) => format!("{quote}{value}{quote}"),
+        None => format!("{{{}}}", js_string(value, '"')),
+    }
+}
+
+/// Create a JavaScript string literal delimited by the provided quote.
+fn js_string(value: &str, quote: char) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+
+    literal.push(quote);
+
+    for char in value.chars() {
+        match char {
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            char if char == quote => {
+                literal.push('\\');
+                literal.push(char);
+            }
+            char if char.is_control() => literal.push_str(&format!("\\u{{{:x}}}", char as u32)),
+            char => literal.push(char),
+        }
+    }
+
+    literal.push(quote);
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_prefers_single_quotes_over_an_expression() {
+        assert_eq!(quote("Say \"hi\""), "'Say \"hi\"'");
+    }
+
+    #[test]
+    fn js_string_escapes_backslashes_and_control_characters() {
+        assert_eq!(js_string("a\\b\n\u{7}'", '\''), r"'a\\b\n\u{7}\''");
+    }
+
+    #[test]
+    fn empty_children_keep_the_tags_together() {
+        assert_eq!(Component::new("Tabs").render_with("\n"), "<Tabs></Tabs>");
+    }
+}
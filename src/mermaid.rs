@@ -0,0 +1,256 @@
+//! Mermaid diagrams, rendered by GitHub, GitLab, Obsidian and MkDocs among others.
+
+use crate::{fenced_code_block, LF};
+use std::collections::BTreeMap;
+
+/// Create a code block fenced with a `mermaid` info string.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::mermaid::mermaid_block;
+///
+/// assert_eq!(
+///     mermaid_block("pie\n    \"Rust\" : 9\n    \"C\" : 1"),
+///     "```mermaid\npie\n    \"Rust\" : 9\n    \"C\" : 1\n```"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/creating-diagrams>
+pub fn mermaid_block(source: &str) -> String {
+    fenced_code_block(source, Some("mermaid"))
+}
+
+/// The direction a flowchart flows in.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// Top to bottom.
+    #[default]
+    TopDown,
+    /// Bottom to top.
+    BottomUp,
+    /// Left to right.
+    LeftRight,
+    /// Right to left.
+    RightLeft,
+}
+
+impl Direction {
+    /// The direction as written after `flowchart`, e.g. `LR`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::TopDown => "TD",
+            Direction::BottomUp => "BT",
+            Direction::LeftRight => "LR",
+            Direction::RightLeft => "RL",
+        }
+    }
+}
+
+/// The shape of a flowchart node.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Shape {
+    /// A rectangle, `id["label"]`.
+    #[default]
+    Rectangle,
+    /// A rectangle with rounded corners, `id("label")`.
+    Rounded,
+    /// A stadium, `id(["label"])`.
+    Stadium,
+    /// A cylinder, typically a database, `id[("label")]`.
+    Cylinder,
+    /// A circle, `id(("label"))`.
+    Circle,
+    /// A rhombus, typically a decision, `id{"label"}`.
+    Rhombus,
+}
+
+impl Shape {
+    /// The brackets opening and closing a node's label.
+    fn brackets(self) -> (&'static str, &'static str) {
+        match self {
+            Shape::Rectangle => ("[", "]"),
+            Shape::Rounded => ("(", ")"),
+            Shape::Stadium => ("([", "])"),
+            Shape::Cylinder => ("[(", ")]"),
+            Shape::Circle => ("((", "))"),
+            Shape::Rhombus => ("{", "}"),
+        }
+    }
+}
+
+/// A builder of Mermaid flowcharts.
+///
+/// Node IDs are sanitized so they're valid identifiers (e.g. `user-service` becomes
+/// `user_service` and the reserved `end` becomes `end_`), and labels are quoted so they may
+/// contain any text. IDs which only collide once sanitized, e.g. `a-b` and `a_b` or any two
+/// non-ASCII IDs of the same length, are told apart with `_1`, `_2`, … suffixes in the order
+/// they're first used, so every ID keeps referring to its own node. Edges may refer to nodes
+/// which weren't declared, which Mermaid then shows labeled with their ID.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::mermaid::{Direction, Flowchart, Shape};
+///
+/// let flowchart = Flowchart::new(Direction::LeftRight)
+///     .node("web", "Web \"app\"")
+///     .node_with_shape("user-db", "Users", Shape::Cylinder)
+///     .edge("web", "api")
+///     .edge_with_label("api", "user-db", "reads");
+///
+/// assert_eq!(
+///     flowchart.source(),
+///     "flowchart LR\n    web[\"Web #quot;app#quot;\"]\n    user_db[(\"Users\")]\n    \
+///      web --> api\n    api -->|\"reads\"| user_db"
+/// );
+/// assert!(flowchart.render().starts_with("```mermaid\nflowchart LR\n"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://mermaid.js.org/syntax/flowchart.html>
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Flowchart {
+    direction: Direction,
+    nodes: Vec<(String, String, Shape)>,
+    edges: Vec<(String, String, Option<String>)>,
+    ids: BTreeMap<String, String>,
+    occurrences: BTreeMap<String, usize>,
+}
+
+impl Flowchart {
+    /// Create an empty flowchart flowing in the provided direction.
+    pub fn new(direction: Direction) -> Flowchart {
+        Flowchart { direction, ..Flowchart::default() }
+    }
+
+    /// Add a rectangular node.
+    pub fn node(self, id: &str, label: &str) -> Flowchart {
+        self.node_with_shape(id, label, Shape::Rectangle)
+    }
+
+    /// Add a node of the provided shape.
+    pub fn node_with_shape(mut self, id: &str, label: &str, shape: Shape) -> Flowchart {
+        let id = self.id(id);
+
+        self.nodes.push((id, label.to_owned(), shape));
+        self
+    }
+
+    /// Add an arrow from one node to another.
+    pub fn edge(mut self, from: &str, to: &str) -> Flowchart {
+        let (from, to) = (self.id(from), self.id(to));
+
+        self.edges.push((from, to, None));
+        self
+    }
+
+    /// Add a labeled arrow from one node to another.
+    pub fn edge_with_label(mut self, from: &str, to: &str, label: &str) -> Flowchart {
+        let (from, to) = (self.id(from), self.id(to));
+
+        self.edges.push((from, to, Some(label.to_owned())));
+        self
+    }
+
+    /// The Mermaid source of the flowchart.
+    pub fn source(&self) -> String {
+        let nodes = self.nodes.iter().map(|(id, label, shape)| {
+            let (open, close) = shape.brackets();
+
+            format!("{id}{open}{}{close}", quote(label))
+        });
+        let edges = self.edges.iter().map(|(from, to, label)| match label {
+            Some(label) => format!("{from} -->|{}| {to}", quote(label)),
+            None => format!("{from} --> {to}"),
+        });
+
+        [format!("flowchart {}", self.direction.as_str())]
+            .into_iter()
+            .chain(nodes.chain(edges).map(|line| format!("    {line}")))
+            .collect::<Vec<String>>()
+            .join(&LF.to_string())
+    }
+
+    /// Render the flowchart in a code block fenced with a `mermaid` info string.
+    pub fn render(&self) -> String {
+        mermaid_block(&self.source())
+    }
+
+    /// The sanitized ID assigned to an ID, suffixed when it collides with that of another.
+    fn id(&mut self, id: &str) -> String {
+        if let Some(assigned) = self.ids.get(id) {
+            return assigned.clone();
+        }
+
+        let sanitized = node_id(id);
+        let mut assigned = sanitized.clone();
+
+        while self.occurrences.contains_key(&assigned) {
+            let count = self.occurrences.entry(sanitized.clone()).or_default();
+
+            *count += 1;
+            assigned = format!("{sanitized}_{count}");
+        }
+
+        self.occurrences.insert(assigned.clone(), 0);
+        self.ids.insert(id.to_owned(), assigned.clone());
+
+        assigned
+    }
+}
+
+/// Sanitize a node ID, replacing anything but letters, digits and underscores with
+/// underscores and suffixing the reserved `end`, which would otherwise break the flowchart.
+fn node_id(id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|char| if char.is_ascii_alphanumeric() || char == '_' { char } else { '_' })
+        .collect();
+
+    if id.is_empty() || id == "end" {
+        format!("{id}_")
+    } else {
+        id
+    }
+}
+
+/// Quote a label, replacing the quotes in it with their entity.
+fn quote(label: &str) -> String {
+    format!("\"{}\"", label.replace('"', "#quot;").replace(LF, "<br>"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_and_empty_ids_are_suffixed() {
+        let flowchart = Flowchart::default().node("end", "Done\nfinally").edge("", "end");
+
+        assert_eq!(
+            flowchart.source(),
+            "flowchart TD\n    end_[\"Done<br>finally\"]\n    _ --> end_"
+        );
+    }
+
+    #[test]
+    fn ids_colliding_once_sanitized_are_suffixed() {
+        let flowchart = Flowchart::default()
+            .node("a-b", "Hyphen")
+            .node("a_b", "Underscore")
+            .node("日本", "Japan")
+            .node("中国", "China")
+            .edge("a_b", "a-b")
+            .edge("中国", "日本");
+
+        assert_eq!(
+            flowchart.source(),
+            "flowchart TD\n    a_b[\"Hyphen\"]\n    a_b_1[\"Underscore\"]\n    __[\"Japan\"]\n    \
+             ___1[\"China\"]\n    a_b_1 --> a_b\n    ___1 --> __"
+        );
+    }
+}
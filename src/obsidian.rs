@@ -0,0 +1,103 @@
+//! Obsidian's wiki links and embeds.
+
+/// Create a wiki link to a note, optionally displayed as an alias, e.g. `[[Note|Alias]]`.
+///
+/// The target may point to a heading (`Note#Heading`) or a block (`Note#^block-id`). Obsidian
+/// doesn't support escapes inside wiki links, so the characters which would split or end the
+/// link are removed instead: pipes and brackets anywhere, and `#` and `^` in the note's name,
+/// where they'd start a heading or block reference. The pipe separating the alias would split a
+/// table cell, see [`wiki_link_in_table`] for links inside of tables.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::obsidian::wiki_link;
+///
+/// assert_eq!(wiki_link("Projects/Roadmap", None), "[[Projects/Roadmap]]");
+/// assert_eq!(wiki_link("Roadmap#Q3", Some("Q3 goals")), "[[Roadmap#Q3|Q3 goals]]");
+/// assert_eq!(wiki_link("Notes#^summary", None), "[[Notes#^summary]]");
+/// assert_eq!(wiki_link("A|B [draft]", Some("[[C]]")), "[[AB draft|C]]");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://help.obsidian.md/Linking+notes+and+files/Internal+links>
+pub fn wiki_link(target: &str, alias: Option<&str>) -> String {
+    link(target, alias, "|")
+}
+
+/// Create a wiki link for a table cell, whose alias is separated with an escaped pipe (`\|`) so
+/// it doesn't split the cell, e.g. `[[Note\|Alias]]`. See [`wiki_link`].
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::obsidian::wiki_link_in_table;
+///
+/// assert_eq!(wiki_link_in_table("Roadmap#Q3", Some("Q3")), r"[[Roadmap#Q3\|Q3]]");
+/// assert_eq!(wiki_link_in_table("Roadmap", None), "[[Roadmap]]");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://help.obsidian.md/Editing+and+formatting/Advanced+formatting+syntax#Tables>
+pub fn wiki_link_in_table(target: &str, alias: Option<&str>) -> String {
+    link(target, alias, r"\|")
+}
+
+/// Create an embed of a note or file, e.g. `![[image.png]]`, with the target cleaned like a
+/// [`wiki_link`]'s.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::obsidian::embed;
+///
+/// assert_eq!(embed("diagram.png"), "![[diagram.png]]");
+/// assert_eq!(embed("Meeting notes#Decisions"), "![[Meeting notes#Decisions]]");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://help.obsidian.md/Linking+notes+and+files/Embed+files>
+pub fn embed(target: &str) -> String {
+    format!("!{}", wiki_link(target, None))
+}
+
+/// Create a wiki link whose alias follows the provided separator.
+fn link(target: &str, alias: Option<&str>, separator: &str) -> String {
+    match alias {
+        Some(alias) => format!("[[{}{separator}{}]]", clean_target(target), clean(alias)),
+        None => format!("[[{}]]", clean_target(target)),
+    }
+}
+
+/// Remove the characters which would split or end a wiki link's target, keeping a heading or
+/// block reference following the note's name.
+fn clean_target(target: &str) -> String {
+    match target.split_once('#') {
+        Some((name, reference)) => format!("{}#{}", clean(name).replace('^', ""), clean(reference)),
+        None => clean(target).replace('^', ""),
+    }
+}
+
+/// Remove the pipes and brackets which would split or end a wiki link.
+fn clean(text: &str) -> String {
+    text.replace(['|', '[', ']'], "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embed_cleans_the_target() {
+        assert_eq!(embed("a]]b|c^1#^d|e"), "![[abc1#^de]]");
+    }
+
+    #[test]
+    fn headings_keep_their_hashes_and_carets() {
+        assert_eq!(wiki_link("Note#Part 1#Step ^2", None), "[[Note#Part 1#Step ^2]]");
+        assert_eq!(wiki_link("#Local heading", Some("here")), "[[#Local heading|here]]");
+    }
+}
@@ -0,0 +1,366 @@
+//! API reference documents generated from OpenAPI specifications.
+
+use crate::labels::Labels;
+use crate::options::HeadingStyle;
+use crate::table::pipe_table;
+use crate::{code_span, fenced_code_block, LF};
+use serde_json::Value;
+
+/// The HTTP methods an OpenAPI path item may describe, in the order they're documented.
+const METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// Render an API reference document for an OpenAPI 3 specification.
+///
+/// The document has a section per path and method with tables of its parameters and responses,
+/// and the schemas of request and response bodies in fenced JSON code blocks. It ends with a
+/// section per schema in `components.schemas`. `$ref`s to parameters are resolved, while
+/// `$ref`s to schemas are shown by name.
+///
+/// Examples
+/// ========
+/// ```
+/// let spec = serde_json::json!({
+///     "openapi": "3.0.3",
+///     "info": { "title": "Pets", "version": "1.0.0" },
+///     "paths": {
+///         "/pets/{id}": {
+///             "get": {
+///                 "summary": "Get a pet.",
+///                 "parameters": [
+///                     {
+///                         "name": "id",
+///                         "in": "path",
+///                         "required": true,
+///                         "schema": { "type": "string" }
+///                     }
+///                 ],
+///                 "responses": { "404": { "description": "No such pet." } }
+///             }
+///         }
+///     }
+/// });
+/// let reference = md_writer::openapi::api_reference(&spec);
+///
+/// assert!(reference.starts_with("Pets\n====\n\nVersion 1.0.0\n\nGET /pets/{id}\n--------------"));
+/// assert!(reference.contains("| `id` | path | string | Yes |  |"));
+/// assert!(reference.contains("| `404` | No such pet. |"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.openapis.org/oas/v3.1.0>
+pub fn api_reference(spec: &Value) -> String {
+    api_reference_with_style(spec, HeadingStyle::default())
+}
+
+/// Render an API reference document like [`api_reference`], with headings in the provided
+/// style.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{openapi::api_reference_with_style, options::HeadingStyle};
+///
+/// let spec = serde_json::json!({ "info": { "title": "Pets" }, "paths": {} });
+///
+/// assert_eq!(api_reference_with_style(&spec, HeadingStyle::Atx), "# Pets");
+/// ```
+pub fn api_reference_with_style(spec: &Value, heading_style: HeadingStyle) -> String {
+    api_reference_with_labels(spec, heading_style, &Labels::default())
+}
+
+/// Render an API reference document like [`api_reference_with_style`], with the titles of its
+/// sections and the headers of its tables taken from the provided labels.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{labels::Labels, openapi::api_reference_with_labels, options::HeadingStyle};
+///
+/// let spec = serde_json::json!({ "info": { "version": "2.1" }, "paths": {} });
+/// let labels = Labels {
+///     api_reference: "API-Referenz".into(),
+///     version: "Version".into(),
+///     ..Labels::default()
+/// };
+///
+/// assert_eq!(
+///     api_reference_with_labels(&spec, HeadingStyle::Atx, &labels),
+///     "# API-Referenz\n\nVersion 2.1"
+/// );
+/// ```
+pub fn api_reference_with_labels(
+    spec: &Value,
+    heading_style: HeadingStyle,
+    labels: &Labels,
+) -> String {
+    let info = &spec["info"];
+    let title = info["title"].as_str().unwrap_or(&labels.api_reference);
+    let mut blocks = vec![heading_style.header(1, title)];
+
+    if let Some(version) = info["version"].as_str() {
+        blocks.push(format!("{} {version}", labels.version));
+    }
+
+    blocks.extend(text(&info["description"]));
+
+    for (path, item) in spec["paths"].as_object().into_iter().flatten() {
+        for method in METHODS {
+            let operation = &item[method];
+
+            if operation.is_object() {
+                let section =
+                    operation_section(spec, path, method, item, operation, heading_style, labels);
+
+                blocks.extend(section);
+            }
+        }
+    }
+
+    let schemas = spec["components"]["schemas"].as_object();
+
+    if let Some(schemas) = schemas.filter(|schemas| !schemas.is_empty()) {
+        blocks.push(heading_style.header(2, &labels.schemas));
+
+        for (name, schema) in schemas {
+            blocks.push(heading_style.header(3, name));
+            blocks.extend(text(&schema["description"]));
+            blocks.push(json_block(schema));
+        }
+    }
+
+    blocks.join(&LF.to_string().repeat(2))
+}
+
+/// Parse an OpenAPI 3 specification in JSON and render an API reference document for it, as
+/// [`api_reference`] does.
+pub fn api_reference_from_json(json: &str) -> serde_json::Result<String> {
+    serde_json::from_str(json).map(|spec| api_reference(&spec))
+}
+
+fn operation_section(
+    spec: &Value,
+    path: &str,
+    method: &str,
+    item: &Value,
+    operation: &Value,
+    heading_style: HeadingStyle,
+    labels: &Labels,
+) -> Vec<String> {
+    let h3 = |text: &str| heading_style.header(3, text);
+    let mut blocks = vec![heading_style.header(2, &format!("{} {path}", method.to_uppercase()))];
+
+    if operation["deprecated"].as_bool() == Some(true) {
+        blocks.push(format!("**{}**", labels.deprecated_operation));
+    }
+
+    blocks.extend(text(&operation["summary"]));
+    blocks.extend(text(&operation["description"]));
+
+    let parameters: Vec<&Value> = [&item["parameters"], &operation["parameters"]]
+        .into_iter()
+        .filter_map(Value::as_array)
+        .flatten()
+        .map(|parameter| resolve(spec, parameter))
+        .collect();
+
+    if !parameters.is_empty() {
+        let headers = [
+            &labels.name,
+            &labels.location,
+            &labels.type_name,
+            &labels.required,
+            &labels.description,
+        ]
+        .map(String::clone);
+        let rows: Vec<Vec<String>> = parameters
+            .iter()
+            .map(|parameter| {
+                vec![
+                    code_span(parameter["name"].as_str().unwrap_or_default()),
+                    parameter["in"].as_str().unwrap_or_default().to_owned(),
+                    type_name(&parameter["schema"]),
+                    yes_no(parameter["required"].as_bool().unwrap_or(false), labels),
+                    parameter["description"].as_str().unwrap_or_default().to_owned(),
+                ]
+            })
+            .collect();
+
+        blocks.push(h3(&labels.parameters));
+        blocks.push(pipe_table(&headers, &rows));
+    }
+
+    let request_body = resolve(spec, &operation["requestBody"]);
+
+    if request_body.is_object() {
+        blocks.push(h3(&labels.request_body));
+        blocks.extend(text(&request_body["description"]));
+        blocks.extend(content_blocks(&request_body["content"]));
+    }
+
+    if let Some(responses) = operation["responses"].as_object() {
+        let headers = [&labels.status, &labels.description].map(String::clone);
+        let rows: Vec<Vec<String>> = responses
+            .iter()
+            .map(|(status, response)| {
+                let description = resolve(spec, response)["description"].as_str();
+
+                vec![code_span(status), description.unwrap_or_default().to_owned()]
+            })
+            .collect();
+
+        blocks.push(h3(&labels.responses));
+        blocks.push(pipe_table(&headers, &rows));
+
+        for (status, response) in responses {
+            let content = content_blocks(&resolve(spec, response)["content"]);
+
+            if !content.is_empty() {
+                blocks.push(format!("{}:", code_span(status)));
+                blocks.extend(content);
+            }
+        }
+    }
+
+    blocks
+}
+
+/// The media type and schema of every media type of a request or response body.
+fn content_blocks(content: &Value) -> Vec<String> {
+    content
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, media)| media["schema"].is_object())
+        .flat_map(|(media_type, media)| [code_span(media_type), json_block(&media["schema"])])
+        .collect()
+}
+
+fn json_block(value: &Value) -> String {
+    fenced_code_block(&serde_json::to_string_pretty(value).unwrap_or_default(), Some("json"))
+}
+
+/// Follow a local `$ref`, e.g. `#/components/parameters/limit`, returning the value itself
+/// when it isn't a reference or the reference can't be resolved.
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    value["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| spec.pointer(pointer))
+        .unwrap_or(value)
+}
+
+fn text(value: &Value) -> Option<String> {
+    value.as_str().map(str::trim).filter(|text| !text.is_empty()).map(str::to_owned)
+}
+
+/// A short description of the type of a schema, e.g. `array of Pet` or `string (uuid)`.
+fn type_name(schema: &Value) -> String {
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference.rsplit('/').next().unwrap_or(reference).to_owned();
+    }
+
+    let name = match &schema["type"] {
+        Value::String(name) if name == "array" => {
+            format!("array of {}", type_name(&schema["items"]))
+        }
+        Value::String(name) => name.clone(),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<&str>>()
+            .join(" or "),
+        _ => return String::new(),
+    };
+
+    match schema["format"].as_str() {
+        Some(format) => format!("{name} ({format})"),
+        None => name,
+    }
+}
+
+fn yes_no(value: bool, labels: &Labels) -> String {
+    if value { &labels.yes } else { &labels.no }.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn api_reference_resolves_parameter_refs_and_shows_schemas() {
+        let spec = json!({
+            "info": { "title": "Pets" },
+            "paths": {
+                "/pets": {
+                    "post": {
+                        "parameters": [{ "$ref": "#/components/parameters/dryRun" }],
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Pet" }
+                                }
+                            }
+                        },
+                        "responses": {}
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "dryRun": { "name": "dry_run", "in": "query", "schema": { "type": "boolean" } }
+                },
+                "schemas": { "Pet": { "type": "object" } }
+            }
+        });
+        let reference = api_reference(&spec);
+
+        assert!(reference.contains("| `dry_run` | query | boolean | No |  |"));
+        assert!(reference.contains(
+            "`application/json`\n\n```json\n{\n  \"$ref\": \"#/components/schemas/Pet\"\n}\n```"
+        ));
+        assert!(reference.ends_with("### Pet\n\n```json\n{\n  \"type\": \"object\"\n}\n```"));
+    }
+
+    #[test]
+    fn section_titles_and_table_headers_come_from_the_labels() {
+        let spec = json!({
+            "info": { "title": "Pets" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "parameters": [{ "name": "limit", "in": "query", "required": true }],
+                        "responses": { "200": { "description": "OK" } }
+                    }
+                }
+            }
+        });
+        let labels = Labels {
+            parameters: "Parameter".into(),
+            responses: "Antworten".into(),
+            name: "Name".into(),
+            location: "Ort".into(),
+            type_name: "Typ".into(),
+            required: "Erforderlich".into(),
+            description: "Beschreibung".into(),
+            status: "Status".into(),
+            yes: "Ja".into(),
+            ..Labels::default()
+        };
+        let reference = api_reference_with_labels(&spec, HeadingStyle::Atx, &labels);
+
+        assert!(reference.contains(
+            "### Parameter\n\n| Name | Ort | Typ | Erforderlich | Beschreibung |\n\
+             | --- | --- | --- | --- | --- |\n| `limit` | query |  | Ja |  |"
+        ));
+        assert!(reference.contains("### Antworten\n\n| Status | Beschreibung |"));
+    }
+
+    #[test]
+    fn type_name_describes_arrays_and_formats() {
+        let schema = json!({ "type": "array", "items": { "type": "string", "format": "uuid" } });
+
+        assert_eq!(type_name(&schema), "array of string (uuid)");
+    }
+}
@@ -0,0 +1,483 @@
+//! Options controlling how Markdown is rendered.
+
+use crate::bidi;
+use crate::error::MdError;
+use crate::escape::{escape_block_start, escape_text, EscapeMode};
+use crate::flavor::Flavor;
+use crate::strict::{self, Expected};
+use crate::write::{write_atx, write_fenced, written};
+use crate::{h1, h1_atx, h2, h2_atx, h3, h4, h5, h6, LF};
+use std::borrow::Cow;
+
+/// Options controlling how Markdown is rendered, so a whole generated document is rendered
+/// in one consistent style.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{escape::EscapeMode, options::RenderOptions};
+///
+/// let options = RenderOptions { escape_mode: EscapeMode::Off, ..RenderOptions::default() };
+///
+/// assert_eq!(options.escape("*trusted*"), "*trusted*");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RenderOptions {
+    /// The flavor of Markdown to render.
+    pub flavor: Flavor,
+    /// How aggressively user-supplied text is escaped.
+    pub escape_mode: EscapeMode,
+    /// The character delimiting emphasis and strong emphasis.
+    pub emphasis: EmphasisChar,
+    /// How hard line breaks are written.
+    pub hard_break: HardBreak,
+    /// The character fencing code blocks.
+    pub fence: FenceChar,
+    /// How headings are written.
+    pub heading: HeadingStyle,
+    /// The character marking bullet list items.
+    pub bullet: BulletChar,
+    /// The line ending written between lines.
+    pub line_ending: LineEnding,
+    /// Whether raw HTML is converted to Markdown or removed, for targets which sanitize HTML
+    /// aggressively, e.g. crates.io. `<details>` become their bold summary followed by their
+    /// body, `<br>` become hard line breaks and other tags are dropped, keeping their text.
+    pub strip_html: bool,
+    /// Whether right-to-left headings and table cells are wrapped in directional isolates, so
+    /// they don't reorder the left-to-right content around them. See
+    /// [`isolate_rtl`](crate::bidi::isolate_rtl).
+    pub isolate_rtl: bool,
+}
+
+/// An alias of [`RenderOptions`] for those who know them as Markdown options.
+pub type MdOptions = RenderOptions;
+
+impl RenderOptions {
+    /// The default options for the provided flavor: headings are written in ATX style for
+    /// Obsidian and MkDocs, whose documentation and editors use it throughout, and otherwise
+    /// in the default style.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::flavor::Flavor;
+    /// use md_writer::options::{HeadingStyle, MdOptions};
+    ///
+    /// assert_eq!(MdOptions::github().flavor, Flavor::Gfm);
+    /// assert_eq!(MdOptions::azure().flavor, Flavor::AzureDevOps);
+    /// assert_eq!(MdOptions::mkdocs().heading, HeadingStyle::Atx);
+    /// assert_eq!(MdOptions::for_flavor(Flavor::Pandoc), MdOptions::pandoc());
+    /// ```
+    pub fn for_flavor(flavor: Flavor) -> RenderOptions {
+        let heading = match flavor {
+            Flavor::Obsidian | Flavor::MkDocs => HeadingStyle::Atx,
+            _ => HeadingStyle::default(),
+        };
+
+        RenderOptions { flavor, heading, ..RenderOptions::default() }
+    }
+
+    /// The options for plain CommonMark, which are the default options.
+    pub fn commonmark() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::CommonMark)
+    }
+
+    /// The options for GitHub Flavored Markdown.
+    pub fn github() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::Gfm)
+    }
+
+    /// The options for Azure DevOps wiki Markdown.
+    pub fn azure() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::AzureDevOps)
+    }
+
+    /// The options for GitLab Flavored Markdown.
+    pub fn gitlab() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::GitLab)
+    }
+
+    /// The options for Pandoc's Markdown.
+    pub fn pandoc() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::Pandoc)
+    }
+
+    /// The options for Material for MkDocs.
+    pub fn mkdocs() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::MkDocs)
+    }
+
+    /// The options for Obsidian Flavored Markdown.
+    pub fn obsidian() -> RenderOptions {
+        RenderOptions::for_flavor(Flavor::Obsidian)
+    }
+
+    /// Escape plain text per these options' escape mode.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::options::RenderOptions;
+    ///
+    /// assert_eq!(RenderOptions::default().escape("*untrusted*"), r"\*untrusted\*");
+    /// ```
+    pub fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        escape_text(text, self.escape_mode)
+    }
+
+    /// Wrap right-to-left text in a directional isolate when these options
+    /// [`isolate_rtl`](RenderOptions::isolate_rtl), leaving it as is otherwise.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::options::RenderOptions;
+    ///
+    /// let options = RenderOptions { isolate_rtl: true, ..RenderOptions::default() };
+    ///
+    /// assert_eq!(options.isolate("שלום"), "\u{2067}שלום\u{2069}");
+    /// assert_eq!(RenderOptions::default().isolate("שלום"), "שלום");
+    /// ```
+    pub fn isolate<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.isolate_rtl {
+            bidi::isolate_rtl(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+}
+
+/// The character delimiting emphasis and strong emphasis, e.g. `*` in `*italic*`.
+///
+/// Underscores don't create emphasis within words (e.g. `snake_case_name`), which makes them
+/// the safer choice for prose about code, while asterisks do.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::EmphasisChar;
+///
+/// assert_eq!(EmphasisChar::Underscore.italic("Note"), "_Note_");
+/// assert_eq!(EmphasisChar::Underscore.bold("Note"), "__Note__");
+/// assert_eq!(EmphasisChar::Asterisk.bold_italic("Note"), "***Note***");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EmphasisChar {
+    /// `*`
+    #[default]
+    Asterisk,
+    /// `_`
+    Underscore,
+}
+
+impl EmphasisChar {
+    /// The delimiter character.
+    pub fn char(self) -> char {
+        match self {
+            EmphasisChar::Asterisk => '*',
+            EmphasisChar::Underscore => '_',
+        }
+    }
+
+    /// Wrap text in emphasis, typically rendered in italics.
+    pub fn italic(self, text: &str) -> String {
+        self.wrap(text, 1)
+    }
+
+    /// Wrap text in strong emphasis, typically rendered in bold.
+    pub fn bold(self, text: &str) -> String {
+        self.wrap(text, 2)
+    }
+
+    /// Wrap text in both emphasis and strong emphasis.
+    pub fn bold_italic(self, text: &str) -> String {
+        self.wrap(text, 3)
+    }
+
+    fn wrap(self, text: &str, count: usize) -> String {
+        let delimiter = self.char().to_string().repeat(count);
+
+        format!("{delimiter}{text}{delimiter}")
+    }
+}
+
+/// How a hard line break is written.
+///
+/// Trailing spaces are invisible and often stripped by editors or treated inconsistently by
+/// tools, so the backslash form is the default.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::HardBreak;
+///
+/// assert_eq!(HardBreak::Spaces.paragraph("Ada Lovelace\nLondon"), "Ada Lovelace  \nLondon");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#hard-line-breaks>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HardBreak {
+    /// A backslash at the end of the line.
+    #[default]
+    Backslash,
+    /// Two spaces at the end of the line.
+    Spaces,
+}
+
+impl HardBreak {
+    /// The hard line break, including the line feed ending the line.
+    pub fn render(self) -> &'static str {
+        match self {
+            HardBreak::Backslash => "\\\n",
+            HardBreak::Spaces => "  \n",
+        }
+    }
+
+    /// Create a paragraph whose lines are separated by hard line breaks, e.g. for an address
+    /// or a poem. Lines are trimmed and escaped like those of [`crate::paragraph`].
+    pub fn paragraph(self, text: &str) -> String {
+        paragraph_lines(text).join(self.render())
+    }
+}
+
+/// How headings are written.
+///
+/// Setext headers (text underlined with `=` or `-`) only exist for levels 1 and 2, so deeper
+/// headings are always ATX headers (text prefixed with `#`s). Use [`HeadingStyle::Atx`] to
+/// write a whole document in one consistent style, e.g. when a linter forbids setext headers.
+/// Besides [`RenderOptions`], the generators of whole documents take a style, e.g.
+/// [`Readme::heading_style`](crate::readme::Readme::heading_style) and
+/// [`Report::with_heading_style`](crate::report::Report::with_heading_style).
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::HeadingStyle;
+///
+/// assert_eq!(HeadingStyle::Setext.heading(1, "Title").unwrap(), "Title\n=====");
+/// assert_eq!(HeadingStyle::Atx.heading(1, "Title").unwrap(), "# Title");
+/// assert_eq!(HeadingStyle::Atx.heading(3, "Usage").unwrap(), "### Usage");
+/// assert_eq!(HeadingStyle::AtxClosed.heading(3, "Usage").unwrap(), "### Usage ###");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#atx-headings>
+/// - <https://spec.commonmark.org/0.30/#setext-headings>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HeadingStyle {
+    /// Setext headers for levels 1 and 2 and ATX headers beyond, as [`crate::h`] writes them.
+    #[default]
+    Setext,
+    /// ATX headers for every level.
+    Atx,
+    /// ATX headers for every level with a closing sequence matching the opening one, e.g.
+    /// `### Usage ###`, as some style guides require.
+    AtxClosed,
+}
+
+impl HeadingStyle {
+    /// Create a header of the provided level.
+    ///
+    /// Errors
+    /// ======
+    /// Returns [`MdError::InvalidHeadingLevel`] when the level is outside of 1–6.
+    pub fn heading(self, level: u8, text: &str) -> Result<String, MdError> {
+        match (self, level) {
+            (HeadingStyle::AtxClosed, 1..=6) => {
+                let opening = written(|out| write_atx(out, level.into(), text));
+                let heading = format!("{opening} {}", "#".repeat(level.into()));
+
+                strict::verify(&heading, Expected::Heading(level));
+
+                Ok(heading)
+            }
+            (HeadingStyle::Setext, 1) => Ok(h1(text)),
+            (HeadingStyle::Setext, 2) => Ok(h2(text)),
+            (HeadingStyle::Atx, 1) => Ok(h1_atx(text)),
+            (HeadingStyle::Atx, 2) => Ok(h2_atx(text)),
+            (_, 3) => Ok(h3(text)),
+            (_, 4) => Ok(h4(text)),
+            (_, 5) => Ok(h5(text)),
+            (_, 6) => Ok(h6(text)),
+            (_, level) => Err(MdError::InvalidHeadingLevel(level)),
+        }
+    }
+
+    /// Create a header of the provided level, clamped to 1–6.
+    pub(crate) fn header(self, level: u8, text: &str) -> String {
+        self.heading(level.clamp(1, 6), text).unwrap_or_default()
+    }
+}
+
+/// The character marking bullet list items, e.g. `-` in `- item`.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::BulletChar;
+///
+/// assert_eq!(BulletChar::Asterisk.marker(), "* ");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#bullet-list-marker>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BulletChar {
+    /// `-`
+    #[default]
+    Hyphen,
+    /// `*`
+    Asterisk,
+    /// `+`
+    Plus,
+}
+
+impl BulletChar {
+    /// The bullet character.
+    pub fn char(self) -> char {
+        match self {
+            BulletChar::Hyphen => '-',
+            BulletChar::Asterisk => '*',
+            BulletChar::Plus => '+',
+        }
+    }
+
+    /// The list item marker: the bullet character followed by a space.
+    pub fn marker(self) -> &'static str {
+        match self {
+            BulletChar::Hyphen => "- ",
+            BulletChar::Asterisk => "* ",
+            BulletChar::Plus => "+ ",
+        }
+    }
+}
+
+/// The line ending written between lines.
+///
+/// Rendering always produces line feeds, which the line ending is then applied to, e.g. for
+/// documents checked out with CRLF line endings on Windows.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::LineEnding;
+///
+/// assert_eq!(LineEnding::CrLf.apply("Title\n=====\n"), "Title\r\n=====\r\n");
+/// assert_eq!(LineEnding::CrLf.apply("a\r\nb\n"), "a\r\nb\r\n");
+/// assert_eq!(LineEnding::Lf.apply("a\r\nb\n"), "a\nb\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    /// The line ending.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+
+    /// Replace the line feeds of rendered Markdown with this line ending. CRLF line endings
+    /// already in the Markdown, e.g. those of a code block read from a file, are normalized
+    /// first so they aren't doubled up.
+    pub fn apply(self, markdown: &str) -> Cow<'_, str> {
+        let markdown = if markdown.contains("\r\n") {
+            Cow::Owned(markdown.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(markdown)
+        };
+
+        match self {
+            LineEnding::Lf => markdown,
+            LineEnding::CrLf => Cow::Owned(markdown.replace(LF, self.as_str())),
+        }
+    }
+}
+
+/// The trimmed, non-blank lines of a paragraph, escaped so that none of them starts another
+/// block or underlines the line before it as a setext heading.
+pub(crate) fn paragraph_lines(text: &str) -> Vec<Cow<'_, str>> {
+    let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let previous = index.checked_sub(1).map(|index| lines[index]);
+
+            escape_block_start(line, previous)
+        })
+        .collect()
+}
+
+/// The character code fences are made of.
+///
+/// Tilde fences are handy for showing backtick-fenced examples, and their info strings may
+/// contain backticks.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::FenceChar;
+///
+/// let example = "```rust\nfn main() {}\n```";
+///
+/// assert_eq!(
+///     FenceChar::Tilde.fenced_code_block(example, Some("markdown")),
+///     format!("~~~markdown\n{example}\n~~~")
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#code-fence>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum FenceChar {
+    /// `` ``` ``
+    #[default]
+    Backtick,
+    /// `~~~`
+    Tilde,
+}
+
+impl FenceChar {
+    /// The fence character.
+    pub fn char(self) -> char {
+        match self {
+            FenceChar::Backtick => '`',
+            FenceChar::Tilde => '~',
+        }
+    }
+
+    /// Create an opening code fence of the minimum length, three characters.
+    pub fn code_fence(self, info_string: Option<&str>) -> String {
+        format!("{}{}", self.char().to_string().repeat(3), info_string.unwrap_or(""))
+    }
+
+    /// Create a fenced code block. The fences are longer than any run of the fence character
+    /// in the code, so fences inside of it don't close the block. Empty code creates a block
+    /// with no lines between its fences.
+    pub fn fenced_code_block(self, code: &str, info_string: Option<&str>) -> String {
+        let fenced_code_block =
+            written(|out| write_fenced(out, self.char(), code, info_string));
+
+        strict::verify(&fenced_code_block, Expected::FencedCodeBlock(code));
+
+        fenced_code_block
+    }
+}
@@ -0,0 +1,221 @@
+//! READMEs assembled from their conventional sections.
+
+use crate::badge::Badge;
+use crate::labels::Labels;
+use crate::license::{render_license_section, InvalidExpression};
+use crate::options::HeadingStyle;
+use crate::{fenced_code_block, paragraph, LF};
+
+/// A builder of a README, laid out as a title, a row of badges, a description, Installation
+/// and Usage sections, any custom sections and a License section, omitting whichever are empty.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::badge::Badge;
+/// use md_writer::readme::Readme;
+///
+/// let readme = Readme::new("md-writer")
+///     .badge(Badge::new("license", "MIT", "blue"))
+///     .description("Write Markdown from Rust.")
+///     .install("cargo add md-writer")
+///     .usage("Create a heading:", "md_writer::h1(\"Hello\");", "rust")
+///     .license("MIT");
+///
+/// assert_eq!(
+///     readme.render().unwrap(),
+///     "md-writer\n=========\n\n\
+///      ![license: MIT](https://img.shields.io/badge/license-MIT-blue)\n\n\
+///      Write Markdown from Rust.\n\n\
+///      Installation\n------------\n\n```sh\ncargo add md-writer\n```\n\n\
+///      Usage\n-----\n\nCreate a heading:\n\n```rust\nmd_writer::h1(\"Hello\");\n```\n\n\
+///      License\n-------\n\nLicensed under the [MIT License](https://spdx.org/licenses/MIT.html)."
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Readme {
+    title: String,
+    badges: Vec<String>,
+    description: Option<String>,
+    install: Vec<String>,
+    usage: Vec<(String, String, String)>,
+    sections: Vec<(String, String)>,
+    license: Option<String>,
+    copyright: Vec<String>,
+    heading_style: HeadingStyle,
+    labels: Labels,
+}
+
+impl Readme {
+    /// Create a README with the provided title, typically the name of the project.
+    pub fn new(title: &str) -> Readme {
+        Readme { title: title.to_owned(), ..Readme::default() }
+    }
+
+    /// Add a badge to the row below the title.
+    pub fn badge(mut self, badge: Badge<'_>) -> Readme {
+        self.badges.push(badge.render());
+        self
+    }
+
+    /// Set the description of the project.
+    pub fn description(mut self, description: &str) -> Readme {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Add a shell command installing the project.
+    pub fn install(mut self, command: &str) -> Readme {
+        self.install.push(command.to_owned());
+        self
+    }
+
+    /// Add a usage example, introduced by the provided text unless it's empty, with its code
+    /// fenced with the provided info string.
+    pub fn usage(mut self, text: &str, code: &str, info_string: &str) -> Readme {
+        self.usage.push((text.to_owned(), code.to_owned(), info_string.to_owned()));
+        self
+    }
+
+    /// Add a custom section, placed after Usage and before License.
+    pub fn section(mut self, heading: &str, body: &str) -> Readme {
+        self.sections.push((heading.to_owned(), body.to_owned()));
+        self
+    }
+
+    /// Set the SPDX license expression of the project, e.g. `MIT OR Apache-2.0`, rendered as
+    /// a [`license_section`](crate::license::license_section).
+    pub fn license(mut self, expression: &str) -> Readme {
+        self.license = Some(expression.to_owned());
+        self
+    }
+
+    /// Add a copyright line to the License section, e.g. `Copyright (c) 2024 Acme Inc.`.
+    pub fn copyright(mut self, copyright: &str) -> Readme {
+        self.copyright.push(copyright.to_owned());
+        self
+    }
+
+    /// Set the style of the title's and sections' headings, setext by default.
+    pub fn heading_style(self, heading_style: HeadingStyle) -> Readme {
+        Readme { heading_style, ..self }
+    }
+
+    /// Use the provided labels for the titles of the Installation, Usage and License sections.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{labels::Labels, readme::Readme};
+    ///
+    /// let labels = Labels {
+    ///     installation: "Instalación".into(),
+    ///     license: "Licencia".into(),
+    ///     ..Labels::default()
+    /// };
+    /// let readme = Readme::new("herramienta")
+    ///     .install("cargo add herramienta")
+    ///     .license("MIT")
+    ///     .with_labels(labels);
+    /// let rendered = readme.render().unwrap();
+    ///
+    /// assert!(rendered.contains("Instalación\n-----------\n\n```sh\n"));
+    /// assert!(rendered.contains("Licencia\n--------\n\nLicensed under"));
+    /// ```
+    pub fn with_labels(self, labels: Labels) -> Readme {
+        Readme { labels, ..self }
+    }
+
+    /// Render the README.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an [`InvalidExpression`] when the license expression can't be parsed.
+    pub fn render(&self) -> Result<String, InvalidExpression> {
+        let h2 = |text: &str| self.heading_style.header(2, text);
+        let mut blocks = vec![self.heading_style.header(1, &self.title)];
+
+        if !self.badges.is_empty() {
+            blocks.push(self.badges.join(" "));
+        }
+
+        if let Some(description) = &self.description {
+            blocks.push(paragraph(description));
+        }
+
+        if !self.install.is_empty() {
+            blocks.push(h2(&self.labels.installation));
+            blocks.push(fenced_code_block(&self.install.join(&LF.to_string()), Some("sh")));
+        }
+
+        if !self.usage.is_empty() {
+            blocks.push(h2(&self.labels.usage));
+
+            for (text, code, info_string) in &self.usage {
+                if !text.is_empty() {
+                    blocks.push(paragraph(text));
+                }
+
+                blocks.push(fenced_code_block(code, Some(info_string)));
+            }
+        }
+
+        for (heading, body) in &self.sections {
+            blocks.push(h2(heading));
+            blocks.push(body.clone());
+        }
+
+        if let Some(expression) = &self.license {
+            let copyright: Vec<&str> = self.copyright.iter().map(String::as_str).collect();
+
+            let title = &self.labels.license;
+
+            blocks.push(render_license_section(expression, &copyright, title, self.heading_style)?);
+        }
+
+        Ok(blocks.join(&LF.to_string().repeat(2)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_sections_are_omitted() {
+        let readme = Readme::new("tool").install("cargo install tool").install("tool --help");
+
+        assert_eq!(
+            readme.render().unwrap(),
+            "tool\n====\n\n\
+             Installation\n------------\n\n```sh\ncargo install tool\ntool --help\n```"
+        );
+    }
+
+    #[test]
+    fn copyright_lines_follow_the_license_heading() {
+        let readme = Readme::new("tool").license("MIT").copyright("Copyright (c) 2024 Acme Inc.");
+
+        assert!(readme
+            .render()
+            .unwrap()
+            .contains("License\n-------\n\nCopyright (c) 2024 Acme Inc.\n\nLicensed under"));
+    }
+
+    #[test]
+    fn headings_follow_the_heading_style() {
+        let readme = Readme::new("tool")
+            .section("FAQ", "None yet.")
+            .license("MIT OR Apache-2.0")
+            .heading_style(HeadingStyle::AtxClosed);
+        let rendered = readme.render().unwrap();
+
+        assert!(rendered.starts_with("# tool #\n\n## FAQ ##\n\nNone yet.\n\n## License ##\n\n"));
+        assert!(rendered.contains("\n\n### Contribution ###\n\n"));
+    }
+
+    #[test]
+    fn invalid_license_is_an_error() {
+        assert!(Readme::new("tool").license("MIT AND").render().is_err());
+    }
+}
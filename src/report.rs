@@ -0,0 +1,342 @@
+//! Incrementally written Markdown reports.
+
+use crate::case::TextCase;
+use crate::footnote::{FootnotePlacement, Footnotes};
+use crate::labels::Labels;
+use crate::options::HeadingStyle;
+use crate::slug::slugify;
+use crate::{h1, link, LF};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// A Markdown report which is written to disk section by section.
+///
+/// Each completed section is flushed to the file as soon as it is added, so a job which dies
+/// part way through still leaves a readable document behind. Once the job is done
+/// [`Report::finalize`] rewrites the file with a summary and a table of contents preceding the
+/// sections. The titles of those sections come from the report's [`Labels`].
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::report::Report;
+///
+/// let path = std::env::temp_dir().join("md-writer-report-example.md");
+/// let mut report = Report::create(&path, "Nightly Build")?;
+///
+/// report.section("Compile", "All targets compiled.")?;
+/// report.section("Test", "All tests passed.")?;
+/// report.finalize(Some("The build succeeded."))?;
+///
+/// let contents = std::fs::read_to_string(&path)?;
+///
+/// assert!(contents.starts_with("Nightly Build\n============="));
+/// assert!(contents.contains("- [Compile](#compile)\n- [Test](#test)"));
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Report {
+    path: PathBuf,
+    title: String,
+    headings: Vec<String>,
+    labels: Labels,
+    heading_case: TextCase,
+    heading_style: HeadingStyle,
+    back_to_top: bool,
+    footnotes: Footnotes,
+    file: File,
+}
+
+impl Report {
+    /// Create a report at the provided path, truncating any existing file, and write its title.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error if the file cannot be created or written to.
+    pub fn create(path: impl AsRef<Path>, title: &str) -> io::Result<Report> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::create(&path)?;
+
+        write!(file, "{}{LF}", h1(title))?;
+        file.flush()?;
+
+        Ok(Report {
+            path,
+            title: title.to_owned(),
+            headings: Vec::new(),
+            labels: Labels::default(),
+            heading_case: TextCase::default(),
+            heading_style: HeadingStyle::default(),
+            back_to_top: false,
+            footnotes: Footnotes::default(),
+            file,
+        })
+    }
+
+    /// Use the provided labels for the generated sections of the report.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{labels::Labels, report::Report};
+    ///
+    /// let path = std::env::temp_dir().join("md-writer-report-labels-example.md");
+    /// let labels = Labels { summary: "Résumé".into(), ..Labels::default() };
+    /// let report = Report::create(&path, "Rapport")?.with_labels(labels);
+    ///
+    /// report.finalize(Some("Tout va bien."))?;
+    ///
+    /// assert!(std::fs::read_to_string(&path)?.contains("Résumé\n------"));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_labels(self, labels: Labels) -> Report {
+        Report { labels, ..self }
+    }
+
+    /// Apply the provided casing style to the headings of sections added from now on.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{case::TextCase, report::Report};
+    ///
+    /// let path = std::env::temp_dir().join("md-writer-report-case-example.md");
+    /// let mut report = Report::create(&path, "Deploy")?.with_heading_case(TextCase::Title);
+    ///
+    /// report.section("roll out to the canary fleet", "Healthy.")?;
+    ///
+    /// assert_eq!(report.headings(), ["Roll Out to the Canary Fleet"]);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_heading_case(self, heading_case: TextCase) -> Report {
+        Report { heading_case, ..self }
+    }
+
+    /// Write the headings of sections added from now on, and those of the title and the
+    /// generated sections when finalized, in the provided style.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{options::HeadingStyle, report::Report};
+    ///
+    /// let path = std::env::temp_dir().join("md-writer-report-style-example.md");
+    /// let mut report = Report::create(&path, "Deploy")?.with_heading_style(HeadingStyle::Atx);
+    ///
+    /// report.section("Canary", "Healthy.")?;
+    /// report.finalize(None)?;
+    ///
+    /// assert_eq!(
+    ///     std::fs::read_to_string(&path)?,
+    ///     "# Deploy\n\n## Table of Contents\n\n- [Canary](#canary)\n\n## Canary\n\nHealthy.\n"
+    /// );
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_heading_style(self, heading_style: HeadingStyle) -> Report {
+        Report { heading_style, ..self }
+    }
+
+    /// Set whether sections added from now on end with a link back to the title, labeled with
+    /// the report's [`Labels::back_to_top`].
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::report::Report;
+    ///
+    /// let path = std::env::temp_dir().join("md-writer-report-top-example.md");
+    /// let mut report = Report::create(&path, "Nightly Build")?.with_back_to_top_links(true);
+    ///
+    /// report.section("Test", "All tests passed.")?;
+    ///
+    /// assert!(std::fs::read_to_string(&path)?
+    ///     .ends_with("All tests passed.\n\n[Back to top](#nightly-build)\n"));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_back_to_top_links(self, back_to_top: bool) -> Report {
+        Report { back_to_top, ..self }
+    }
+
+    /// Emit the definitions of footnotes per the provided placement. Since each section is
+    /// written as a single block, placing footnotes after their first use places them at the
+    /// end of the section referencing them.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::{footnote::FootnotePlacement, report::Report};
+    ///
+    /// let path = std::env::temp_dir().join("md-writer-report-footnote-example.md");
+    /// let mut report = Report::create(&path, "Audit")?
+    ///     .with_footnote_placement(FootnotePlacement::EndOfSection);
+    /// let marker = report.footnote("Measured on the staging cluster.");
+    ///
+    /// report.section("Latency", &format!("p99 is 40ms.{marker}"))?;
+    ///
+    /// assert!(std::fs::read_to_string(&path)?
+    ///     .ends_with("p99 is 40ms.[^1]\n\n[^1]: Measured on the staging cluster.\n"));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_footnote_placement(self, placement: FootnotePlacement) -> Report {
+        Report {
+            footnotes: Footnotes::new(placement),
+            ..self
+        }
+    }
+
+    /// Register a footnote and get back the marker to reference it with in a section's body.
+    pub fn footnote(&mut self, text: &str) -> String {
+        self.footnotes.reference(text)
+    }
+
+    /// Append a completed section to the report and flush it to disk.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error if the file cannot be written to.
+    pub fn section(&mut self, heading: &str, body: &str) -> io::Result<()> {
+        let heading = self.heading_case.apply(heading);
+
+        let h2 = self.heading_style.header(2, &heading);
+
+        write!(self.file, "{LF}{h2}{LF}{LF}{}{LF}", body.trim_end())?;
+
+        let after_block = self.footnotes.after_block();
+
+        for definitions in [after_block, self.footnotes.after_section()].into_iter().flatten() {
+            write!(self.file, "{LF}{definitions}{LF}")?;
+        }
+
+        if self.back_to_top {
+            let top = link(&self.labels.back_to_top, &format!("#{}", slugify(&self.title)), None);
+
+            write!(self.file, "{LF}{top}{LF}")?;
+        }
+
+        self.file.flush()?;
+        self.headings.push(heading.into_owned());
+
+        Ok(())
+    }
+
+    /// The headings of the sections which have been written so far.
+    pub fn headings(&self) -> &[String] {
+        &self.headings
+    }
+
+    /// Finish the report by rewriting it with an optional summary and a table of contents placed
+    /// between the title and the sections.
+    ///
+    /// The rewritten report is written to a sibling temporary file which then replaces the
+    /// original, so the report on disk is never left half written.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an error if the report cannot be read back, written or renamed.
+    pub fn finalize(self, summary: Option<&str>) -> io::Result<()> {
+        let Report { path, title, headings, labels, heading_style, mut footnotes, file, .. } = self;
+
+        drop(file);
+
+        let contents = fs::read_to_string(&path)?;
+        let created = format!("{}{LF}", h1(&title));
+        let sections = contents.strip_prefix(&created).unwrap_or(&contents);
+        let h2 = |text: &str| heading_style.header(2, text);
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+
+        temp_name.push(".tmp");
+
+        let temp_path = path.with_file_name(temp_name);
+        let mut temp = File::create(&temp_path)?;
+
+        write!(temp, "{}{LF}", heading_style.header(1, &title))?;
+
+        if let Some(summary) = summary {
+            write!(temp, "{LF}{}{LF}{LF}{}{LF}", h2(&labels.summary), summary.trim_end())?;
+        }
+
+        if !headings.is_empty() {
+            write!(temp, "{LF}{}{LF}{LF}{}{LF}", h2(&labels.table_of_contents), toc(&headings))?;
+        }
+
+        temp.write_all(sections.as_bytes())?;
+
+        if let Some(definitions) = footnotes.at_end() {
+            write!(temp, "{LF}{definitions}{LF}")?;
+        }
+
+        temp.sync_all()?;
+        drop(temp);
+
+        fs::rename(temp_path, path)
+    }
+}
+
+fn toc(headings: &[String]) -> String {
+    let headings: Vec<(u8, &str)> = headings.iter().map(|heading| (2, heading.as_str())).collect();
+
+    crate::toc::toc(&headings, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("md-writer-{name}-{}.md", std::process::id()))
+    }
+
+    #[test]
+    fn section_is_flushed_before_finalize() {
+        let path = temp_path("report-partial");
+        let mut report = Report::create(&path, "Job").unwrap();
+
+        report.section("Step One", "Done.").unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(contents, "Job\n===\n\nStep One\n--------\n\nDone.\n");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn finalize_writes_summary_and_toc() {
+        let path = temp_path("report-final");
+        let mut report = Report::create(&path, "Job").unwrap();
+
+        report.section("Step One", "Done.").unwrap();
+        report.finalize(Some("It worked.")).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(
+            contents,
+            "Job\n===\n\nSummary\n-------\n\nIt worked.\n\nTable of Contents\n-----------------\n\n\
+             - [Step One](#step-one)\n\nStep One\n--------\n\nDone.\n"
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn finalize_places_footnotes_at_the_end_by_default() {
+        let path = temp_path("report-footnotes");
+        let mut report = Report::create(&path, "Job").unwrap();
+        let marker = report.footnote("A note.");
+
+        report.section("One", &format!("Text.{marker}")).unwrap();
+        report.section("Two", "More text.").unwrap();
+        report.finalize(None).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+
+        assert!(contents.ends_with("Two\n---\n\nMore text.\n\n[^1]: A note.\n"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,209 @@
+//! Shortcodes of static site generators, e.g. Hugo or Zola.
+
+use crate::LF;
+
+/// A builder of Hugo shortcodes, either self-closing (`{{< figure src="a.png" >}}`) or paired
+/// around inner content (`{{% note %}}…{{% /note %}}`).
+///
+/// Argument values are quoted so the shortcode lexer reads them whole: values containing quotes
+/// are written as backtick delimited raw strings or, when they also contain backticks, with their
+/// quotes escaped. Hugo doesn't allow mixing named and positional arguments in one shortcode.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::shortcode::HugoShortcode;
+///
+/// assert_eq!(
+///     HugoShortcode::new("figure").arg("src", "cat.png").arg("alt", "A \"cat\"").render(),
+///     "{{< figure src=\"cat.png\" alt=`A \"cat\"` >}}"
+/// );
+/// assert_eq!(
+///     HugoShortcode::new("note").markdown(true).render_with("Mind the **gap**."),
+///     "{{% note %}}\nMind the **gap**.\n{{% /note %}}"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://gohugo.io/content-management/shortcodes/>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct HugoShortcode<'a> {
+    name: &'a str,
+    args: Vec<(Option<&'a str>, &'a str)>,
+    markdown: bool,
+}
+
+impl<'a> HugoShortcode<'a> {
+    /// Create a shortcode with the provided name and no arguments.
+    pub fn new(name: &'a str) -> HugoShortcode<'a> {
+        HugoShortcode { name, args: Vec::new(), markdown: false }
+    }
+
+    /// Add a named argument.
+    pub fn arg(mut self, key: &'a str, value: &'a str) -> HugoShortcode<'a> {
+        self.args.push((Some(key), value));
+        self
+    }
+
+    /// Add a positional argument.
+    pub fn positional(mut self, value: &'a str) -> HugoShortcode<'a> {
+        self.args.push((None, value));
+        self
+    }
+
+    /// Set whether the shortcode uses the `{{% %}}` delimiters, whose inner content Hugo renders
+    /// as Markdown, rather than the `{{< >}}` ones.
+    pub fn markdown(self, markdown: bool) -> HugoShortcode<'a> {
+        HugoShortcode { markdown, ..self }
+    }
+
+    /// Render the shortcode as a self-closing one.
+    pub fn render(&self) -> String {
+        self.tag(self.name, true)
+    }
+
+    /// Render the shortcode as a paired one around the provided inner content.
+    pub fn render_with(&self, inner: &str) -> String {
+        let closing = format!("/{}", self.name);
+
+        format!(
+            "{}{LF}{}{LF}{}",
+            self.tag(self.name, true),
+            inner.trim_matches(LF),
+            self.tag(&closing, false)
+        )
+    }
+
+    fn tag(&self, name: &str, with_args: bool) -> String {
+        let (open, close) = if self.markdown { ("{{%", "%}}") } else { ("{{<", ">}}") };
+        let mut tag = format!("{open} {name}");
+
+        if with_args {
+            for (key, value) in &self.args {
+                tag.push(' ');
+
+                if let Some(key) = key {
+                    tag.push_str(key);
+                    tag.push('=');
+                }
+
+                tag.push_str(&hugo_quote(value));
+            }
+        }
+
+        format!("{tag} {close}")
+    }
+}
+
+/// A builder of Zola shortcodes, either called inline (`{{ youtube(id="dQw4w9WgXcQ") }}`) or
+/// with a body (`{% quote(author="Ferris") %}…{% end %}`).
+///
+/// Argument values are written as Tera string literals, which have no escape sequences, so each
+/// is delimited by whichever of `"`, `'` and `` ` `` it doesn't contain. Values containing all
+/// three have their double quotes replaced with `&quot;`, as shortcodes typically output HTML.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::shortcode::ZolaShortcode;
+///
+/// assert_eq!(
+///     ZolaShortcode::new("youtube").arg("id", "dQw4w9WgXcQ").render(),
+///     "{{ youtube(id=\"dQw4w9WgXcQ\") }}"
+/// );
+/// assert_eq!(
+///     ZolaShortcode::new("quote").arg("author", "Ferris \"the crab\"").render_with("Hi!"),
+///     "{% quote(author='Ferris \"the crab\"') %}\nHi!\n{% end %}"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://www.getzola.org/documentation/content/shortcodes/>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ZolaShortcode<'a> {
+    name: &'a str,
+    args: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> ZolaShortcode<'a> {
+    /// Create a shortcode with the provided name and no arguments.
+    pub fn new(name: &'a str) -> ZolaShortcode<'a> {
+        ZolaShortcode { name, args: Vec::new() }
+    }
+
+    /// Add a named argument. Zola shortcodes take no positional arguments.
+    pub fn arg(mut self, key: &'a str, value: &'a str) -> ZolaShortcode<'a> {
+        self.args.push((key, value));
+        self
+    }
+
+    /// Render the shortcode as an inline call.
+    pub fn render(&self) -> String {
+        format!("{{{{ {} }}}}", self.call())
+    }
+
+    /// Render the shortcode as a call with the provided body.
+    pub fn render_with(&self, body: &str) -> String {
+        format!("{{% {} %}}{LF}{}{LF}{{% end %}}", self.call(), body.trim_matches(LF))
+    }
+
+    fn call(&self) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|(key, value)| format!("{key}={}", zola_quote(value)))
+            .collect();
+
+        format!("{}({})", self.name, args.join(", "))
+    }
+}
+
+/// Quote a Hugo argument value, preferring a raw string to escaping the quotes in it.
+fn hugo_quote(value: &str) -> String {
+    if !value.contains('"') {
+        format!("\"{value}\"")
+    } else if !value.contains('`') {
+        format!("`{value}`")
+    } else {
+        format!("\"{}\"", value.replace('"', "\\\""))
+    }
+}
+
+/// Quote a Tera string literal with a delimiter it doesn't contain.
+fn zola_quote(value: &str) -> String {
+    match ['"', '\'', '`'].into_iter().find(|quote| !value.contains(*quote)) {
+        Some(quote) => format!("{quote}{value}{quote}"),
+        None => format!("\"{}\"", value.replace('"', "&quot;")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hugo_quote_escapes_quotes_when_a_raw_string_is_impossible() {
+        assert_eq!(hugo_quote("a \"b\" `c`"), "\"a \\\"b\\\" `c`\"");
+    }
+
+    #[test]
+    fn hugo_positional_arguments_are_quoted() {
+        assert_eq!(
+            HugoShortcode::new("youtube").positional("dQw4w9WgXcQ").render(),
+            "{{< youtube \"dQw4w9WgXcQ\" >}}"
+        );
+    }
+
+    #[test]
+    fn zola_quote_falls_back_to_an_entity() {
+        assert_eq!(zola_quote("\"it's\" `x`"), "\"&quot;it's&quot; `x`\"");
+        assert_eq!(zola_quote("\"it's\""), "`\"it's\"`");
+    }
+
+    #[test]
+    fn zola_shortcode_without_arguments_keeps_the_parentheses() {
+        assert_eq!(ZolaShortcode::new("toc").render(), "{{ toc() }}");
+    }
+}
@@ -0,0 +1,188 @@
+//! Heading anchor generation.
+
+use crate::bidi::is_bidi_control;
+use std::collections::HashMap;
+use unicode_width::UnicodeWidthChar;
+
+/// Create an anchor slug for the provided heading text, as GitHub does.
+///
+/// The text is lowercased, characters other than letters, digits, combining marks (e.g. the
+/// virama of Devanagari or an emoji's variation selector), connector punctuation (e.g. `_`),
+/// spaces and hyphens are dropped, and each space is replaced with a hyphen.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::slug::slugify;
+///
+/// assert_eq!(slugify("What's New?"), "whats-new");
+/// assert_eq!(slugify("C++ & Rust -- FFI"), "c--rust----ffi");
+/// assert_eq!(slugify("नमस्ते दुनिया"), "नमस्ते-दुनिया");
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.com/Flet/github-slugger>
+pub fn slugify(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|char| match char {
+            ' ' => Some('-'),
+            '-' => Some(char),
+            _ if char.is_alphanumeric() || is_mark(char) || is_connector(char) => Some(char),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether a character is a combining mark, approximated as a zero-width character which isn't
+/// a control or formatting character.
+fn is_mark(char: char) -> bool {
+    char.width() == Some(0)
+        && !char.is_control()
+        && !is_bidi_control(char)
+        && !matches!(
+            char,
+            '\u{00AD}' | '\u{200B}'..='\u{200D}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}'
+        )
+}
+
+/// Whether a character is connector punctuation, e.g. `_` or `‿`.
+fn is_connector(char: char) -> bool {
+    matches!(
+        char,
+        '_' | '\u{203F}'
+            | '\u{2040}'
+            | '\u{2054}'
+            | '\u{FE33}'
+            | '\u{FE34}'
+            | '\u{FE4D}'..='\u{FE4F}'
+            | '\u{FF3F}'
+    )
+}
+
+/// A heading whose slug collided with that of an earlier heading.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Collision {
+    /// The text of the heading.
+    pub heading: String,
+    /// The slug the heading would have had were it unique.
+    pub slug: String,
+    /// The deduplicated slug the heading was given.
+    pub assigned: String,
+}
+
+/// A document-wide tracker of heading slugs which gives duplicates GitHub-style `-1`, `-2`, …
+/// suffixes.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::slug::Slugger;
+///
+/// let mut slugger = Slugger::default();
+///
+/// assert_eq!(slugger.slug("Usage"), "usage");
+/// assert_eq!(slugger.slug("Usage"), "usage-1");
+/// assert_eq!(slugger.slug("Usage"), "usage-2");
+/// assert_eq!(slugger.collisions().len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Slugger {
+    occurrences: HashMap<String, usize>,
+    collisions: Vec<Collision>,
+}
+
+impl Slugger {
+    /// Create a slugger which hasn't seen any headings.
+    pub fn new() -> Slugger {
+        Slugger::default()
+    }
+
+    /// Create a unique slug for the provided heading text, recording a [`Collision`] when its
+    /// slug was already taken.
+    pub fn slug(&mut self, heading: &str) -> String {
+        let slug = slugify(heading);
+        let mut assigned = slug.clone();
+
+        while self.occurrences.contains_key(&assigned) {
+            let count = self.occurrences.entry(slug.clone()).or_default();
+
+            *count += 1;
+            assigned = format!("{slug}-{count}");
+        }
+
+        self.occurrences.insert(assigned.clone(), 0);
+
+        if assigned != slug {
+            self.collisions.push(Collision {
+                heading: heading.to_owned(),
+                slug,
+                assigned: assigned.clone(),
+            });
+        }
+
+        assigned
+    }
+
+    /// Forget every slug and collision, e.g. to start on another document.
+    pub fn reset(&mut self) {
+        self.occurrences.clear();
+        self.collisions.clear();
+    }
+
+    /// The headings whose slugs collided with those of earlier headings, in document order.
+    pub fn collisions(&self) -> &[Collision] {
+        &self.collisions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_lowercases_and_hyphenates() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+    }
+
+    #[test]
+    fn slugify_drops_punctuation() {
+        assert_eq!(slugify("What's new?"), "whats-new");
+    }
+
+    #[test]
+    fn slugify_keeps_marks_and_connectors_but_not_format_characters() {
+        assert_eq!(slugify("e\u{301}te\u{200B}\u{200F} a\u{203F}b"), "e\u{301}te-a\u{203F}b");
+    }
+
+    #[test]
+    fn reset_forgets_slugs() {
+        let mut slugger = Slugger::new();
+
+        slugger.slug("Usage");
+        slugger.slug("Usage");
+        slugger.reset();
+
+        assert_eq!(slugger.slug("Usage"), "usage");
+        assert!(slugger.collisions().is_empty());
+    }
+
+    #[test]
+    fn slugger_skips_suffixes_taken_by_other_headings() {
+        let mut slugger = Slugger::default();
+
+        assert_eq!(slugger.slug("Foo 1"), "foo-1");
+        assert_eq!(slugger.slug("Foo"), "foo");
+        assert_eq!(slugger.slug("Foo"), "foo-2");
+        assert_eq!(
+            slugger.collisions(),
+            [Collision {
+                heading: "Foo".to_owned(),
+                slug: "foo".to_owned(),
+                assigned: "foo-2".to_owned(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Debug-mode validation of generated blocks.
+//!
+//! When the `strict` feature is enabled in a debug build every block-producing function
+//! re-parses its output and panics if the block it intended to produce isn't what a CommonMark
+//! parser sees. In any other build [`verify`] compiles down to nothing.
+
+/// The block a function intended to produce.
+#[cfg_attr(not(all(feature = "strict", debug_assertions)), allow(dead_code))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Expected<'a> {
+    /// A block quote.
+    BlockQuote,
+    /// A heading of the provided level.
+    Heading(u8),
+    /// A fenced code block with the provided content.
+    FencedCodeBlock(&'a str),
+    /// A list with the provided number of items.
+    List(usize),
+}
+
+/// Panic if the provided output doesn't parse as exactly the expected block.
+#[cfg(all(feature = "strict", debug_assertions))]
+#[track_caller]
+pub(crate) fn verify(output: &str, expected: Expected) {
+    if let Err(found) = check(output, expected) {
+        panic!(
+            "md-writer produced Markdown which doesn't parse as intended: expected {}, but found \
+             {found}\n\nOutput:\n{output}",
+            describe(expected)
+        );
+    }
+}
+
+/// Panic if the provided output doesn't parse as exactly the expected block.
+#[cfg(not(all(feature = "strict", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn verify(_output: &str, _expected: Expected) {}
+
+#[cfg(all(feature = "strict", debug_assertions))]
+fn describe(expected: Expected) -> String {
+    match expected {
+        Expected::BlockQuote => "a single block quote".to_owned(),
+        Expected::Heading(level) => format!("a single level {level} heading"),
+        Expected::FencedCodeBlock(code) => {
+            format!("a single fenced code block containing {code:?}")
+        }
+        Expected::List(items) => format!("a single list of {items} items"),
+    }
+}
+
+/// Parse the output and compare its top-level blocks against the expected block, returning a
+/// description of what was found instead on mismatch.
+#[cfg(all(feature = "strict", debug_assertions))]
+fn check(output: &str, expected: Expected) -> Result<(), String> {
+    use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+
+    let mut blocks = Vec::new();
+    let mut depth = 0usize;
+    let mut code = String::new();
+    let mut items = 0usize;
+
+    for event in Parser::new(output) {
+        match event {
+            Event::Start(tag) => {
+                if depth == 0 {
+                    blocks.push(match tag {
+                        Tag::Heading { level, .. } => format!("a level {} heading", level as u8),
+                        Tag::CodeBlock(CodeBlockKind::Fenced(_)) => "a fenced code block".into(),
+                        Tag::CodeBlock(CodeBlockKind::Indented) => "an indented code block".into(),
+                        Tag::List(_) => "a list".into(),
+                        Tag::Paragraph => "a paragraph".into(),
+                        Tag::BlockQuote(_) => "a block quote".into(),
+                        Tag::HtmlBlock => "an HTML block".into(),
+                        other => format!("{other:?}"),
+                    });
+                } else if depth == 1 && matches!(tag, Tag::Item) {
+                    items += 1;
+                }
+
+                depth += 1;
+            }
+            Event::End(_) => depth -= 1,
+            Event::Text(text) if depth == 1 && blocks.len() == 1 => code.push_str(&text),
+            Event::Rule if depth == 0 => blocks.push("a thematic break".into()),
+            _ => {}
+        }
+    }
+
+    let found = match blocks.as_slice() {
+        [] => "nothing".to_owned(),
+        [block] => block.clone(),
+        blocks => blocks.join(", then "),
+    };
+    let matches = match expected {
+        Expected::BlockQuote => found == "a block quote",
+        Expected::Heading(level) => found == format!("a level {level} heading"),
+        Expected::FencedCodeBlock(expected) => {
+            // The parser reports the content of a non-empty block with a trailing line feed.
+            let expected = match expected {
+                "" => String::new(),
+                expected => format!("{expected}\n"),
+            };
+
+            found == "a fenced code block" && code == expected
+        }
+        Expected::List(expected) => found == "a list" && items == expected,
+    };
+
+    if matches {
+        Ok(())
+    } else if found == "a fenced code block" {
+        Err(format!("a fenced code block containing {code:?}"))
+    } else if found == "a list" {
+        Err(format!("a list of {items} items"))
+    } else {
+        Err(found)
+    }
+}
+
+#[cfg(all(test, feature = "strict", debug_assertions))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_accepts_the_intended_block() {
+        assert!(check("### Hello", Expected::Heading(3)).is_ok());
+        assert!(check("```\nfoo\n```", Expected::FencedCodeBlock("foo")).is_ok());
+        assert!(check("- a\n- b", Expected::List(2)).is_ok());
+    }
+
+    #[test]
+    fn check_rejects_an_unintended_block() {
+        let found = check("```\nfoo\n```\nbar\n```", Expected::FencedCodeBlock("foo\n```\nbar"));
+
+        assert_eq!(
+            found,
+            Err("a fenced code block, then a paragraph, then a fenced code block".to_owned())
+        );
+    }
+
+    #[test]
+    fn empty_fenced_code_blocks_pass_verification() {
+        assert_eq!(crate::fenced_code_block("", None), "```\n```");
+        assert_eq!(
+            crate::options::FenceChar::Tilde.fenced_code_block("", Some("rust")),
+            "~~~rust\n~~~"
+        );
+    }
+
+    #[test]
+    fn empty_blockquotes_pass_verification() {
+        assert_eq!(crate::blockquote(""), ">");
+        assert_eq!(crate::blockquote_with_depth("", 2), ">>");
+    }
+}
@@ -0,0 +1,426 @@
+//! GFM pipe tables.
+
+use crate::bidi::display_width;
+use crate::escape::escape_table_cell;
+use crate::write::written;
+use crate::LF;
+use std::fmt::{self, Write};
+
+/// A row of a table, e.g. a query result type from a database library.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::table::{table_from_rows, TableRow};
+///
+/// struct User {
+///     id: i64,
+///     name: String,
+/// }
+///
+/// impl TableRow for User {
+///     fn columns() -> Vec<String> {
+///         vec!["ID".to_owned(), "Name".to_owned()]
+///     }
+///
+///     fn cells(&self) -> Vec<String> {
+///         vec![self.id.to_string(), self.name.clone()]
+///     }
+/// }
+///
+/// let users = vec![User { id: 1, name: "Ada".to_owned() }];
+///
+/// assert_eq!(table_from_rows(&users), "| ID | Name |\n| --- | --- |\n| 1 | Ada |");
+/// ```
+pub trait TableRow {
+    /// The headers of the columns of the table.
+    fn columns() -> Vec<String>;
+
+    /// The cells of the row, in the order of the columns.
+    fn cells(&self) -> Vec<String>;
+}
+
+impl<T: TableRow> TableRow for &T {
+    fn columns() -> Vec<String> {
+        T::columns()
+    }
+
+    fn cells(&self) -> Vec<String> {
+        T::cells(self)
+    }
+}
+
+/// Render a GFM pipe table of rows, with the columns of the row type as its headers.
+///
+/// Cells are escaped so pipes and line feeds don't break the table.
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+pub fn table_from_rows<R: TableRow>(rows: impl IntoIterator<Item = R>) -> String {
+    let rows: Vec<Vec<String>> = rows.into_iter().map(|row| row.cells()).collect();
+
+    pipe_table(&R::columns(), &rows)
+}
+
+/// Render a GFM pipe table of serializable rows, e.g. structs, with the names of their fields
+/// as its headers.
+///
+/// Headers are collected from every row in the order they're first seen, so rows which skip
+/// fields (e.g. with `#[serde(skip_serializing_if = "…")]`) get empty cells for them. Strings
+/// are rendered as is, `null` as an empty cell and any other value as compact JSON. A row which
+/// doesn't serialize to a map, e.g. a number, has its value rendered in the first column.
+///
+/// Errors
+/// ======
+/// Returns an error when a row fails to serialize, e.g. because it's a map with non-string keys.
+///
+/// Examples
+/// ========
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Metric {
+///     name: &'static str,
+///     p99_ms: f64,
+///     tags: Vec<&'static str>,
+/// }
+///
+/// let metrics = [Metric { name: "render", p99_ms: 1.5, tags: vec!["cpu"] }];
+///
+/// assert_eq!(
+///     md_writer::table::table_from_serialize(&metrics).unwrap(),
+///     "| name | p99_ms | tags |\n| --- | --- | --- |\n| render | 1.5 | [\"cpu\"] |"
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn table_from_serialize<T: serde::Serialize>(rows: &[T]) -> serde_json::Result<String> {
+    let values = rows
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<Vec<serde_json::Value>>>()?;
+
+    Ok(table_from_values(&values))
+}
+
+/// Render a pipe table of JSON values, with the keys of the objects among them as its headers.
+#[cfg(any(feature = "json", feature = "serde"))]
+pub(crate) fn table_from_values(values: &[serde_json::Value]) -> String {
+    use serde_json::Value;
+
+    let mut headers: Vec<String> = Vec::new();
+
+    for key in values.iter().filter_map(Value::as_object).flat_map(|object| object.keys()) {
+        if !headers.contains(key) {
+            headers.push(key.clone());
+        }
+    }
+
+    let rows: Vec<Vec<String>> = values
+        .iter()
+        .map(|value| match value.as_object() {
+            Some(object) => headers
+                .iter()
+                .map(|header| object.get(header).map_or_else(String::new, json_cell))
+                .collect(),
+            None => vec![json_cell(value)],
+        })
+        .collect();
+
+    pipe_table(&headers, &rows)
+}
+
+/// Render a JSON value as a table cell: strings as is, `null` as an empty cell and any other
+/// value as compact JSON.
+#[cfg(any(feature = "json", feature = "serde"))]
+pub(crate) fn json_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// The alignment of a table column.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Align {
+    /// `:---`
+    Left,
+    /// `:---:`
+    Center,
+    /// `---:`
+    Right,
+}
+
+/// A builder of GFM pipe tables with optionally aligned columns.
+///
+/// Cells are escaped so pipes and line feeds don't break the table, and rows with fewer cells
+/// than the widest row are padded with empty ones.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::table::{Align, Table};
+///
+/// let table = Table::new()
+///     .headers(["Crate", "Downloads", "Status"])
+///     .align(1, Align::Right)
+///     .align(2, Align::Center)
+///     .row(["md-writer", "1,024", "✅"]);
+///
+/// assert_eq!(
+///     table.render(),
+///     "| Crate | Downloads | Status |\n| --- | ---: | :---: |\n| md-writer | 1,024 | ✅ |"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://github.github.com/gfm/#tables-extension->
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Table {
+    headers: Vec<String>,
+    alignments: Vec<Option<Align>>,
+    rows: Vec<Vec<String>>,
+    padded: bool,
+}
+
+impl Table {
+    /// Create an empty table.
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    /// Create a table from headers and rows of anything displayable, e.g. query results or a
+    /// `Vec<Vec<String>>`.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::table::Table;
+    ///
+    /// let rows = vec![vec![1, 2], vec![3, 4]];
+    /// let table = Table::from_rows(["a", "a|b"], rows);
+    ///
+    /// assert_eq!(table.render(), "| a | a\\|b |\n| --- | --- |\n| 1 | 2 |\n| 3 | 4 |");
+    /// ```
+    pub fn from_rows(
+        headers: impl IntoIterator<Item = impl Into<String>>,
+        rows: impl IntoIterator<Item = impl IntoIterator<Item = impl fmt::Display>>,
+    ) -> Table {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+            .collect();
+
+        Table { rows, ..Table::new().headers(headers) }
+    }
+
+    /// Set the headers of the columns.
+    pub fn headers(self, headers: impl IntoIterator<Item = impl Into<String>>) -> Table {
+        Table { headers: headers.into_iter().map(Into::into).collect(), ..self }
+    }
+
+    /// Set the alignment of the column at the provided 0-based index. Columns are unaligned
+    /// unless set.
+    pub fn align(mut self, column: usize, align: Align) -> Table {
+        if self.alignments.len() <= column {
+            self.alignments.resize(column + 1, None);
+        }
+
+        self.alignments[column] = Some(align);
+        self
+    }
+
+    /// Set whether cells are padded to the width of their column so the columns line up in the
+    /// Markdown source, e.g. for documents reviewed as plain text. Widths account for wide
+    /// characters such as CJK and emoji.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::table::{Align, Table};
+    ///
+    /// let table = Table::from_rows(["Language", "Files"], [["Rust", "42"], ["日本語", "7"]])
+    ///     .align(1, Align::Right)
+    ///     .padded(true);
+    ///
+    /// assert_eq!(
+    ///     table.render(),
+    ///     "| Language | Files |\n\
+    ///      | -------- | ----: |\n\
+    ///      | Rust     |    42 |\n\
+    ///      | 日本語   |     7 |"
+    /// );
+    /// ```
+    pub fn padded(self, padded: bool) -> Table {
+        Table { padded, ..self }
+    }
+
+    /// Append a row.
+    pub fn row(mut self, cells: impl IntoIterator<Item = impl Into<String>>) -> Table {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Render the table. A table without headers or cells renders as nothing.
+    pub fn render(&self) -> String {
+        written(|out| self.write(out))
+    }
+
+    /// Write the table, see [`write_table`](crate::write::write_table).
+    pub(crate) fn write(&self, out: &mut impl Write) -> fmt::Result {
+        write_aligned_pipe_table(out, &self.headers, &self.rows, &self.alignments, self.padded)
+    }
+}
+
+/// Render a pipe table, padding every row to the width of the widest one.
+pub(crate) fn pipe_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    aligned_pipe_table(headers, rows, &[], false)
+}
+
+/// Render a pipe table with the provided column alignments, padding every row to the width of
+/// the widest one. When `padded`, cells are also padded to the display width of their column so
+/// the columns line up in the source.
+pub(crate) fn aligned_pipe_table(
+    headers: &[String],
+    rows: &[Vec<String>],
+    alignments: &[Option<Align>],
+    padded: bool,
+) -> String {
+    written(|out| write_aligned_pipe_table(out, headers, rows, alignments, padded))
+}
+
+/// Write a pipe table, see [`aligned_pipe_table`]. A table without columns writes nothing,
+/// since a delimiter row needs at least one cell.
+fn write_aligned_pipe_table(
+    out: &mut impl Write,
+    headers: &[String],
+    rows: &[Vec<String>],
+    alignments: &[Option<Align>],
+    padded: bool,
+) -> fmt::Result {
+    let columns = rows.iter().map(Vec::len).chain([headers.len()]).max().unwrap_or(0);
+
+    if columns == 0 {
+        return Ok(());
+    }
+
+    let escape_row = |cells: &[String]| -> Vec<String> {
+        (0..columns)
+            .map(|index| cells.get(index).map_or_else(String::new, |cell| escape_table_cell(cell)))
+            .collect()
+    };
+    let headers = escape_row(headers);
+    let rows: Vec<Vec<String>> = rows.iter().map(|cells| escape_row(cells)).collect();
+    let alignment = |index: usize| alignments.get(index).copied().flatten();
+    let widths: Vec<usize> = (0..columns)
+        .map(|index| {
+            let widest = rows
+                .iter()
+                .chain([&headers])
+                .map(|cells| display_width(&cells[index]))
+                .max()
+                .unwrap_or(0);
+
+            widest.max(3)
+        })
+        .collect();
+    let write_row = |out: &mut dyn Write, cells: &[String]| -> fmt::Result {
+        out.write_char('|')?;
+
+        for (index, cell) in cells.iter().enumerate() {
+            let padding =
+                if padded { widths[index].saturating_sub(display_width(cell)) } else { 0 };
+            let (before, after) = match alignment(index) {
+                Some(Align::Right) => (padding, 0),
+                Some(Align::Center) => (padding / 2, padding - padding / 2),
+                _ => (0, padding),
+            };
+
+            write!(out, " {:before$}{cell}{:after$} |", "", "")?;
+        }
+
+        Ok(())
+    };
+    // Padded delimiters span the width of their column, colons included.
+    let dashes = |index: usize, colons: usize| {
+        "-".repeat(if padded { widths[index] - colons } else { 3 })
+    };
+    let delimiters: Vec<String> = (0..columns)
+        .map(|index| match alignment(index) {
+            None => dashes(index, 0),
+            Some(Align::Left) => format!(":{}", dashes(index, 1)),
+            Some(Align::Center) => format!(":{}:", dashes(index, 2)),
+            Some(Align::Right) => format!("{}:", dashes(index, 1)),
+        })
+        .collect();
+    write_row(out, &headers)?;
+    write!(out, "{LF}| {} |", delimiters.join(" | "))?;
+
+    for cells in &rows {
+        out.write_char(LF)?;
+        write_row(out, cells)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_without_columns_is_empty() {
+        assert_eq!(Table::new().render(), "");
+        assert_eq!(pipe_table(&[], &[vec![], vec![]]), "");
+    }
+
+    #[test]
+    fn pipe_table_pads_short_rows() {
+        let headers = vec!["a".to_owned(), "b".to_owned()];
+        let rows = vec![vec!["1".to_owned()], vec!["2".to_owned(), "x|y".to_owned()]];
+
+        assert_eq!(
+            pipe_table(&headers, &rows),
+            "| a | b |\n| --- | --- |\n| 1 |  |\n| 2 | x\\|y |"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn table_from_serialize_unions_the_fields_of_every_row() {
+        use std::collections::BTreeMap;
+
+        let rows = [
+            BTreeMap::from([("a", Some(1))]),
+            BTreeMap::from([("a", None), ("b", Some(2))]),
+        ];
+
+        assert_eq!(
+            table_from_serialize(&rows).unwrap(),
+            "| a | b |\n| --- | --- |\n| 1 |  |\n|  | 2 |"
+        );
+    }
+
+    #[test]
+    fn padded_table_centers_and_widens_short_columns() {
+        let table = Table::from_rows(["a", "bbbbb"], [["x|y", "z"]]).align(1, Align::Center);
+
+        assert_eq!(
+            table.padded(true).render(),
+            "| a    | bbbbb |\n| ---- | :---: |\n| x\\|y |   z   |"
+        );
+    }
+
+    #[test]
+    fn table_aligns_columns_beyond_the_headers() {
+        let table = Table::new().headers(["a"]).align(0, Align::Left).align(2, Align::Right);
+
+        assert_eq!(
+            table.row(["1", "2", "3"]).render(),
+            "| a |  |  |\n| :--- | --- | ---: |\n| 1 | 2 | 3 |"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Tabbed content, e.g. installation instructions per package manager.
+
+use crate::diagnostics::collapsed;
+use crate::flavor::Flavor;
+use crate::LF;
+
+/// Render a group of tabs from pairs of titles and Markdown bodies.
+///
+/// Flavors which support content tabs get PyMdown `=== "Title"` blocks with the body indented
+/// beneath them. Everything else gets a collapsible `<details>` element per tab.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::{flavor::Flavor, tabs::tabs};
+///
+/// let install = [("Cargo", "`cargo add md-writer`"), ("Manual", "Add it to *Cargo.toml*.")];
+///
+/// assert_eq!(
+///     tabs(&install, Flavor::MkDocs),
+///     "=== \"Cargo\"\n\n    `cargo add md-writer`\n\n=== \"Manual\"\n\n    Add it to *Cargo.toml*."
+/// );
+/// assert!(tabs(&install, Flavor::Gfm).starts_with("<details>\n<summary>Cargo</summary>\n"));
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://facelessuser.github.io/pymdown-extensions/extensions/tabbed/>
+pub fn tabs(tabs: &[(&str, &str)], flavor: Flavor) -> String {
+    let tabs: Vec<String> = tabs
+        .iter()
+        .map(|(title, body)| {
+            if flavor.supports_content_tabs() {
+                format!("=== \"{title}\"{LF}{LF}{}", indent(body))
+            } else {
+                collapsed(title, body)
+            }
+        })
+        .collect();
+
+    tabs.join(&LF.to_string().repeat(2))
+}
+
+/// Indent every non-blank line by four spaces, nesting it under a tab.
+fn indent(body: &str) -> String {
+    body.lines()
+        .map(|line| if line.trim().is_empty() { String::new() } else { format!("    {line}") })
+        .collect::<Vec<String>>()
+        .join(&LF.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabs_indent_multi_paragraph_bodies() {
+        let body = "```sh\nbrew install jq\n```\n\nThen restart.";
+
+        assert_eq!(
+            tabs(&[("macOS", body)], Flavor::MkDocs),
+            "=== \"macOS\"\n\n    ```sh\n    brew install jq\n    ```\n\n    Then restart."
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Test support for crates which generate Markdown.
+//!
+//! See [`assert_markdown_eq!`](crate::assert_markdown_eq) for comparing generated documents.
+
+use crate::LF;
+
+/// Asserts that two Markdown strings are structurally equal, ignoring insignificant whitespace.
+///
+/// Both sides are [normalized](normalize) before being compared and on failure the panic
+/// message contains a line diff of the normalized documents. Like [`assert_eq!`] an optional
+/// format string and arguments may be provided to add context to the panic message.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::assert_markdown_eq;
+///
+/// assert_markdown_eq!("Title\n=====\n\n\nBody text. \n", "Title\n=====\n\nBody text.");
+/// ```
+#[macro_export]
+macro_rules! assert_markdown_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_markdown_eq!($left, $right, "Markdown differs")
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let left = $crate::testing::normalize(::core::convert::AsRef::<str>::as_ref(&$left));
+        let right = $crate::testing::normalize(::core::convert::AsRef::<str>::as_ref(&$right));
+
+        if left != right {
+            ::core::panic!(
+                "assertion `left == right` failed: {}\n{}",
+                ::core::format_args!($($arg)+),
+                $crate::testing::diff(&left, &right)
+            );
+        }
+    }};
+}
+
+/// Normalize a Markdown string so that documents which only differ by insignificant whitespace
+/// compare equal.
+///
+/// - CRLF and CR line endings are converted to LF.
+/// - Trailing whitespace is removed from each line, except that a trailing run of two or more
+///   spaces followed by another line of the paragraph (a hard line break) is rewritten as the
+///   equivalent trailing backslash.
+/// - Runs of blank lines are collapsed into a single blank line.
+/// - Leading and trailing blank lines are removed.
+///
+/// The contents of fenced code blocks are significant and are left untouched.
+///
+/// Examples
+/// ========
+/// ```
+/// let markdown = "\r\nfoo  \r\nbar\r\n\r\n\r\nbaz \r\n";
+///
+/// assert_eq!(md_writer::testing::normalize(markdown), "foo\\\nbar\n\nbaz");
+/// ```
+pub fn normalize(markdown: &str) -> String {
+    let markdown = markdown.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines: Vec<String> = Vec::new();
+    let mut fence: Option<String> = None;
+    let mut source = markdown.lines().peekable();
+
+    while let Some(line) = source.next() {
+        let trimmed = line.trim_end();
+        let marker = fence_marker(trimmed);
+
+        if let Some(open) = &fence {
+            // A closing fence is at least as long as the opening one and has no info string.
+            let closes = marker.is_some_and(|marker| {
+                marker.starts_with(open.as_str()) && trimmed.trim_start().len() == marker.len()
+            });
+
+            if closes {
+                fence = None;
+                lines.push(trimmed.to_owned());
+            } else {
+                lines.push(line.to_owned());
+            }
+
+            continue;
+        }
+
+        if let Some(marker) = marker {
+            fence = Some(marker.to_owned());
+            lines.push(trimmed.to_owned());
+        } else if trimmed.is_empty() {
+            if lines.last().is_none_or(|last| last.is_empty()) {
+                continue;
+            }
+
+            lines.push(String::new());
+        } else if line[trimmed.len()..].starts_with("  ") && continues_paragraph(source.peek()) {
+            lines.push(format!("{trimmed}\\"));
+        } else {
+            lines.push(trimmed.to_owned());
+        }
+    }
+
+    if lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+
+    lines.join(&LF.to_string())
+}
+
+/// Whether the provided next line continues a paragraph, so that a hard line break before it
+/// is one rather than trailing spaces or a literal backslash.
+fn continues_paragraph(next: Option<&&str>) -> bool {
+    next.is_some_and(|next| !next.trim().is_empty() && fence_marker(next.trim_end()).is_none())
+}
+
+/// The backtick or tilde run which opens or closes a fenced code block on the provided line.
+fn fence_marker(line: &str) -> Option<&str> {
+    let unindented = line.trim_start_matches(' ');
+
+    if line.len() - unindented.len() > 3 {
+        return None;
+    }
+
+    ['`', '~'].into_iter().find_map(|char| {
+        let rest = unindented.trim_start_matches(char);
+        let run = &unindented[..unindented.len() - rest.len()];
+
+        (run.len() >= 3).then_some(run)
+    })
+}
+
+/// Create a line diff between two strings where removed lines are prefixed with `-`, added
+/// lines with `+` and unchanged lines with a space.
+///
+/// Examples
+/// ========
+/// ```
+/// let diff = md_writer::testing::diff("a\nb\nc", "a\nB\nc");
+///
+/// assert_eq!(diff, "  a\n- b\n+ B\n  c");
+/// ```
+pub fn diff(left: &str, right: &str) -> String {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+
+    // Longest common subsequence lengths of every pair of suffixes.
+    let mut lengths = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lengths[i][j] = if left[i] == right[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            lines.push(format!("  {}", left[i]));
+            i += 1;
+            j += 1;
+        } else if j == right.len() || (i < left.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push(format!("- {}", left[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", right[j]));
+            j += 1;
+        }
+    }
+
+    lines.join(&LF.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_treats_hard_break_styles_as_equal() {
+        assert_eq!(normalize("foo  \nbar"), normalize("foo\\\nbar"));
+    }
+
+    #[test]
+    fn normalize_only_rewrites_hard_breaks_followed_by_a_paragraph_line() {
+        assert_eq!(normalize("foo  \n\nbar  "), "foo\n\nbar");
+        assert_ne!(normalize("foo  \n\nbar"), normalize("foo\\\n\nbar"));
+    }
+
+    #[test]
+    fn normalize_doesnt_close_fences_on_info_strings() {
+        let markdown = "```\n```rust\nfoo  \n```";
+
+        assert_eq!(normalize(markdown), markdown);
+    }
+
+    #[test]
+    fn normalize_leaves_fenced_code_untouched() {
+        let markdown = "```\nfoo  \n\n\nbar\n```\n\n\nbaz";
+
+        assert_eq!(normalize(markdown), "```\nfoo  \n\n\nbar\n```\n\nbaz");
+    }
+
+    #[test]
+    fn assert_markdown_eq_passes_for_insignificant_differences() {
+        assert_markdown_eq!("# Title\n\n\n\n- item \n", "# Title\n\n- item");
+    }
+
+    #[test]
+    #[should_panic(expected = "- Hello\n+ Goodbye")]
+    fn assert_markdown_eq_panics_with_a_diff() {
+        assert_markdown_eq!("# Title\n\nHello", "# Title\n\nGoodbye");
+    }
+}
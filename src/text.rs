@@ -0,0 +1,197 @@
+//! Passes over paragraph text.
+
+/// Wrap bare `http://`, `https://` and `www.` URLs in paragraph text as explicit links, for
+/// renderers which don't implement GFM autolink literals.
+///
+/// URLs with a scheme become autolinks (`<https://…>`) and `www.` URLs become inline links
+/// with an `http://` destination. Trailing punctuation and unbalanced closing parentheses are
+/// left out of the URL as GFM does. Code spans, autolinks and existing links, including their
+/// text, are left untouched.
+///
+/// Examples
+/// ========
+/// ```
+/// let text = "See https://example.com/docs, or www.example.com. Not `https://example.org`.";
+///
+/// assert_eq!(
+///     md_writer::text::autolink_bare_urls(text),
+///     "See <https://example.com/docs>, or [www.example.com](http://www.example.com). \
+///      Not `https://example.org`."
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#autolinks>
+/// - <https://github.github.com/gfm/#autolinks-extension->
+pub fn autolink_bare_urls(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(char) = rest.chars().next() {
+        if char == '`' {
+            let span = code_span_length(rest);
+
+            output.push_str(&rest[..span]);
+            previous = rest[..span].chars().next_back();
+            rest = &rest[span..];
+            continue;
+        }
+
+        if char == '[' {
+            if let Some(span) = link_length(rest) {
+                output.push_str(&rest[..span]);
+                previous = rest[..span].chars().next_back();
+                rest = &rest[span..];
+                continue;
+            }
+        }
+
+        let at_boundary = previous.is_none_or(|previous| {
+            previous.is_whitespace() || matches!(previous, '(' | '*' | '_' | '~')
+        });
+        let in_link = matches!(previous, Some('(')) && output.ends_with("](");
+
+        if at_boundary && !in_link {
+            if let Some(length) = url_length(rest) {
+                let url = &rest[..length];
+
+                if url.starts_with("www.") {
+                    output.push_str(&format!("[{url}](http://{url})"));
+                } else {
+                    output.push_str(&format!("<{url}>"));
+                }
+
+                previous = url.chars().next_back();
+                rest = &rest[length..];
+                continue;
+            }
+        }
+
+        output.push(char);
+        previous = Some(char);
+        rest = &rest[char.len_utf8()..];
+    }
+
+    output
+}
+
+/// The byte length of the inline or reference link beginning the text (`[text](destination)`
+/// or `[text][label]`), including its text, or `None` when the brackets don't form one.
+fn link_length(text: &str) -> Option<usize> {
+    let text_end = bracketed_length(text, '[', ']')?;
+    let rest = &text[text_end..];
+
+    match rest.chars().next() {
+        Some('(') => bracketed_length(rest, '(', ')').map(|length| text_end + length),
+        Some('[') => bracketed_length(rest, '[', ']').map(|length| text_end + length),
+        _ => None,
+    }
+}
+
+/// The byte length of the balanced run of brackets beginning the text, skipping escaped ones.
+fn bracketed_length(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut escaped = false;
+
+    for (index, char) in text.char_indices() {
+        match char {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if char == open => depth += 1,
+            _ if char == close => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index + char.len_utf8());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The byte length of the code span beginning the text, or of its opening backtick run if it
+/// is never closed.
+fn code_span_length(text: &str) -> usize {
+    let run = text.len() - text.trim_start_matches('`').len();
+    let mut offset = run;
+
+    while let Some(start) = text[offset..].find('`') {
+        let start = offset + start;
+        let length = text[start..].len() - text[start..].trim_start_matches('`').len();
+
+        if length == run {
+            return start + length;
+        }
+
+        offset = start + length;
+    }
+
+    run
+}
+
+/// The byte length of the URL beginning the text, if it begins with one.
+fn url_length(text: &str) -> Option<usize> {
+    let prefix = ["https://", "http://", "www."]
+        .into_iter()
+        .find(|prefix| text.starts_with(prefix))?;
+    let end = text
+        .find(|char: char| char.is_whitespace() || char == '<' || char == '`')
+        .unwrap_or(text.len());
+    let mut url = &text[..end];
+
+    loop {
+        let trimmed = url.trim_end_matches(['?', '!', '.', ',', ':', '*', '_', '~', '"', '\'']);
+        let unbalanced = trimmed.ends_with(')')
+            && trimmed.matches(')').count() > trimmed.matches('(').count();
+
+        url = if unbalanced { &trimmed[..trimmed.len() - 1] } else { trimmed };
+
+        if !unbalanced {
+            break;
+        }
+    }
+
+    (url.len() > prefix.len()).then_some(url.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autolink_bare_urls_handles_parentheses() {
+        let text = "(see https://en.wikipedia.org/wiki/Rust_(programming_language))";
+
+        assert_eq!(
+            autolink_bare_urls(text),
+            "(see <https://en.wikipedia.org/wiki/Rust_(programming_language)>)"
+        );
+    }
+
+    #[test]
+    fn autolink_bare_urls_skips_existing_links() {
+        let text = "[https://a.example](https://a.example) and <https://b.example>";
+
+        assert_eq!(autolink_bare_urls(text), text);
+
+        let text = "[see https://example.com docs](https://x.org) or [www.a.example][ref]";
+
+        assert_eq!(autolink_bare_urls(text), text);
+        assert_eq!(
+            autolink_bare_urls("[not a link] https://b.example"),
+            "[not a link] <https://b.example>"
+        );
+    }
+
+    #[test]
+    fn autolink_bare_urls_skips_multi_backtick_code_spans() {
+        let text = "``a ` https://a.example`` https://b.example";
+
+        assert_eq!(autolink_bare_urls(text), "``a ` https://a.example`` <https://b.example>");
+    }
+}
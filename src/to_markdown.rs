@@ -0,0 +1,157 @@
+//! Conversion of common types to Markdown, for generic report code.
+
+use crate::bold;
+use crate::escape::{escape_text, EscapeMode};
+use crate::list::BulletList;
+use crate::map::KeyOrder;
+use crate::table::pipe_table;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::BuildHasher;
+
+/// A value which can be rendered as Markdown.
+///
+/// - Strings are escaped and other scalars are rendered with [`Display`].
+/// - Slices, arrays and `Vec`s become bullet lists of their rendered items.
+/// - `HashMap`s and `BTreeMap`s become Key | Value tables sorted by key, see [`KeyOrder`].
+/// - `None` renders as nothing and `Some` as its value.
+/// - `Ok` renders as its value and `Err` as a bold "Error:" followed by the escaped error.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::to_markdown::ToMarkdown;
+/// use std::collections::HashMap;
+///
+/// let tags = vec!["*fast*", "safe"];
+/// let sizes = HashMap::from([("md-writer", 42), ("serde", 512)]);
+/// let lookup: Result<u32, String> = Err("not found".to_owned());
+///
+/// assert_eq!(tags.to_markdown(), "- \\*fast\\*\n- safe");
+/// assert_eq!(
+///     sizes.to_markdown(),
+///     "| Key | Value |\n| --- | --- |\n| md-writer | 42 |\n| serde | 512 |"
+/// );
+/// assert_eq!(lookup.to_markdown(), "**Error:** not found");
+/// assert_eq!(None::<u32>.to_markdown(), "");
+/// ```
+pub trait ToMarkdown {
+    /// Render the value as Markdown.
+    fn to_markdown(&self) -> String;
+}
+
+macro_rules! impl_display_to_markdown {
+    ($($ty:ty),*) => {
+        $(
+            impl ToMarkdown for $ty {
+                fn to_markdown(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_display_to_markdown!(
+    bool, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl ToMarkdown for char {
+    fn to_markdown(&self) -> String {
+        escape_text(self.encode_utf8(&mut [0; 4]), EscapeMode::default()).into_owned()
+    }
+}
+
+impl ToMarkdown for str {
+    fn to_markdown(&self) -> String {
+        escape_text(self, EscapeMode::default()).into_owned()
+    }
+}
+
+impl ToMarkdown for String {
+    fn to_markdown(&self) -> String {
+        self.as_str().to_markdown()
+    }
+}
+
+impl<T: ToMarkdown + ?Sized> ToMarkdown for &T {
+    fn to_markdown(&self) -> String {
+        (**self).to_markdown()
+    }
+}
+
+impl<T: ToMarkdown> ToMarkdown for [T] {
+    fn to_markdown(&self) -> String {
+        self.iter()
+            .fold(BulletList::new(), |list, item| list.item(&item.to_markdown()))
+            .render()
+    }
+}
+
+impl<T: ToMarkdown, const N: usize> ToMarkdown for [T; N] {
+    fn to_markdown(&self) -> String {
+        self.as_slice().to_markdown()
+    }
+}
+
+impl<T: ToMarkdown> ToMarkdown for Vec<T> {
+    fn to_markdown(&self) -> String {
+        self.as_slice().to_markdown()
+    }
+}
+
+impl<T: ToMarkdown> ToMarkdown for Option<T> {
+    fn to_markdown(&self) -> String {
+        self.as_ref().map_or_else(String::new, ToMarkdown::to_markdown)
+    }
+}
+
+impl<T: ToMarkdown, E: Display> ToMarkdown for Result<T, E> {
+    fn to_markdown(&self) -> String {
+        match self {
+            Ok(value) => value.to_markdown(),
+            Err(error) => format!("{} {}", bold("Error:"), error.to_string().to_markdown()),
+        }
+    }
+}
+
+impl<K: Display + Ord, V: ToMarkdown> ToMarkdown for BTreeMap<K, V> {
+    fn to_markdown(&self) -> String {
+        entries_table(self)
+    }
+}
+
+impl<K: Display + Ord, V: ToMarkdown, S: BuildHasher> ToMarkdown for HashMap<K, V, S> {
+    fn to_markdown(&self) -> String {
+        entries_table(self)
+    }
+}
+
+/// A Key | Value table of the provided entries sorted by key.
+fn entries_table<'a, K, V>(entries: impl IntoIterator<Item = (&'a K, &'a V)>) -> String
+where
+    K: Display + Ord + 'a,
+    V: ToMarkdown + 'a,
+{
+    let rows: Vec<Vec<String>> = KeyOrder::Sorted
+        .arrange(entries)
+        .into_iter()
+        .map(|(key, value)| vec![key.to_string(), value.to_markdown()])
+        .collect();
+
+    pipe_table(&["Key".to_owned(), "Value".to_owned()], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_values_render_recursively() {
+        let nested = vec![vec![1, 2], vec![]];
+        let map = BTreeMap::from([("b", Some("x|y")), ("a", None)]);
+
+        assert_eq!(nested.to_markdown(), "- - 1\n  - 2\n- ");
+        assert_eq!(map.to_markdown(), "| Key | Value |\n| --- | --- |\n| a |  |\n| b | x\\|y |");
+    }
+}
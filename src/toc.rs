@@ -0,0 +1,99 @@
+//! Tables of contents linking to the headings of a document.
+
+use crate::escape::{escape_text, EscapeMode};
+use crate::slug::Slugger;
+use crate::LF;
+
+/// Create a table of contents as a nested bullet list of links to the provided headings, given
+/// as their levels and plain text, which is escaped as the text of the links.
+///
+/// Headings deeper than `max_depth` are left out, though they still take up slugs so the
+/// anchors of later duplicates match those GitHub generates. Nesting is relative to the
+/// shallowest listed heading, and skipped levels (e.g. a level 4 heading directly below a level
+/// 2 one) nest a single level deeper.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::toc::toc;
+///
+/// let headings = [
+///     (1, "md-writer"),
+///     (2, "Usage"),
+///     (3, "Tables"),
+///     (4, "Alignment"),
+///     (2, "Usage"),
+/// ];
+///
+/// assert_eq!(
+///     toc(&headings, 3),
+///     "- [md-writer](#md-writer)\n  - [Usage](#usage)\n    - [Tables](#tables)\n  \
+///      - [Usage](#usage-1)"
+/// );
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#section-links>
+pub fn toc(headings: &[(u8, &str)], max_depth: u8) -> String {
+    let mut slugger = Slugger::default();
+    let mut entries = Vec::new();
+
+    for (level, text) in headings {
+        let slug = slugger.slug(text);
+
+        if *level <= max_depth {
+            entries.push((*level, *text, slug));
+        }
+    }
+
+    let base = entries.iter().map(|(level, ..)| *level).min().unwrap_or_default();
+    let mut depths: Vec<(u8, usize)> = Vec::new();
+
+    entries
+        .iter()
+        .map(|(level, text, slug)| {
+            while depths.last().is_some_and(|(parent, _)| parent >= level) {
+                depths.pop();
+            }
+
+            let depth = depths
+                .last()
+                .map_or(0, |(_, depth)| depth + 1)
+                .min(usize::from(level - base));
+
+            depths.push((*level, depth));
+
+            let text = escape_text(text, EscapeMode::Minimal);
+
+            format!("{}- [{text}](#{slug})", "  ".repeat(depth))
+        })
+        .collect::<Vec<String>>()
+        .join(&LF.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipped_levels_nest_one_level_deeper() {
+        assert_eq!(
+            toc(&[(2, "Install"), (4, "From [source]"), (3, "Linux")], 6),
+            "- [Install](#install)\n  - [From \\[source\\]](#from-source)\n  - [Linux](#linux)"
+        );
+    }
+
+    #[test]
+    fn entries_are_escaped_as_plain_text() {
+        assert_eq!(
+            toc(&[(1, "The *args_ <T> & `code`")], 6),
+            "- [The \\*args\\_ \\<T> & \\`code\\`](#the-args_-t--code)"
+        );
+    }
+
+    #[test]
+    fn toc_of_no_headings_is_empty() {
+        assert_eq!(toc(&[(3, "Deep")], 2), "");
+    }
+}
@@ -0,0 +1,266 @@
+//! A [`tracing`](https://docs.rs/tracing) layer which writes a Markdown execution log.
+
+use crate::escape::{escape_heading, escape_text, EscapeMode};
+use crate::list::list_item;
+use crate::{code_span, h3, h4, h5, h6, LF};
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// A [`Layer`] which renders spans as nested headings and events as bullet items.
+///
+/// Top-level spans are rendered as level 3 headings and each level of nesting adds one to the
+/// heading level. Spans nested deeper than a level 6 heading are rendered as bold bullet items
+/// instead. A span is rendered every time it is entered, so a span which is entered repeatedly
+/// (e.g. one attached to a future) will produce repeated headings.
+///
+/// Span names and messages are escaped as plain text and field values are shown in code spans,
+/// so they can't break the structure of the log. Headings are kept on one line, while the
+/// continuation lines of multi-line messages are indented to stay part of their item.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::trace::MarkdownLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(MarkdownLayer::new(std::io::stderr()));
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("build", target = "x86_64").entered();
+///
+///     tracing::info!("Compiling...");
+/// });
+/// ```
+#[derive(Debug)]
+pub struct MarkdownLayer<W> {
+    state: Mutex<State<W>>,
+}
+
+#[derive(Debug)]
+struct State<W> {
+    writer: W,
+    in_list: bool,
+}
+
+impl<W: Write> MarkdownLayer<W> {
+    /// Create a layer which writes its log to the provided writer.
+    pub fn new(writer: W) -> MarkdownLayer<W> {
+        MarkdownLayer {
+            state: Mutex::new(State { writer, in_list: false }),
+        }
+    }
+
+    fn write_block(&self, block: &str) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        if state.in_list {
+            // Close the preceding list with a blank line.
+            write!(state.writer, "{LF}")?;
+        }
+
+        state.in_list = false;
+        write!(state.writer, "{block}{LF}{LF}")?;
+        state.writer.flush()
+    }
+
+    fn write_item(&self, item: &str) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        state.in_list = true;
+        write!(state.writer, "{item}{LF}")?;
+        state.writer.flush()
+    }
+}
+
+/// The formatted fields of a span, stored in the span's extensions.
+struct SpanFields(String);
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: Vec<String>,
+}
+
+impl FieldVisitor {
+    fn render(self) -> String {
+        // Code spans render line feeds as spaces anyway, and could otherwise end up starting a
+        // line of their own.
+        let fields = self.fields.join(" ").replace(['\r', '\n'], " ");
+        let message = self.message.map(|message| {
+            escape_text(&message.replace("\r\n", "\n"), EscapeMode::Minimal).into_owned()
+        });
+
+        match (message, fields.is_empty()) {
+            (Some(message), true) => message,
+            (Some(message), false) => format!("{message} {}", code_span(&fields)),
+            (None, false) => code_span(&fields),
+            (None, true) => String::new(),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_owned());
+        } else {
+            self.fields.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+        } else {
+            self.fields.push(format!("{}={value:?}", field.name()));
+        }
+    }
+}
+
+impl<S, W> Layer<S> for MarkdownLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: Write + Send + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+
+        attrs.record(&mut visitor);
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.render()));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+
+        values.record(&mut visitor);
+
+        if let Some(span) = ctx.span(id) {
+            let mut extensions = span.extensions_mut();
+            let recorded = visitor.render();
+
+            match extensions.get_mut::<SpanFields>() {
+                Some(SpanFields(fields)) if !recorded.is_empty() => {
+                    if !fields.is_empty() {
+                        fields.push(' ');
+                    }
+
+                    fields.push_str(&recorded);
+                }
+                Some(_) => {}
+                None => extensions.insert(SpanFields(recorded)),
+            }
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let depth = span.scope().skip(1).count();
+        let mut text = escape_heading(span.name());
+
+        if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>() {
+            if !fields.is_empty() {
+                let _ = write!(text, " {fields}");
+            }
+        }
+
+        let text = text.lines().map(str::trim).collect::<Vec<&str>>().join(" ");
+
+        let _ = match depth {
+            0 => self.write_block(&h3(&text)),
+            1 => self.write_block(&h4(&text)),
+            2 => self.write_block(&h5(&text)),
+            3 => self.write_block(&h6(&text)),
+            _ => self.write_item(&format!("- **{text}**")),
+        };
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        let metadata = event.metadata();
+
+        event.record(&mut visitor);
+
+        let text = format!(
+            "**{}** {}: {}",
+            metadata.level(),
+            code_span(metadata.target()),
+            visitor.render()
+        );
+
+        let _ = self.write_item(&list_item("- ", &text, None));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn spans_render_as_nested_headings_and_events_as_items() {
+        let buffer = SharedBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(MarkdownLayer::new(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _outer = tracing::info_span!("job", id = 7).entered();
+            let _inner = tracing::info_span!("step").entered();
+
+            tracing::info!(target: "app", "started");
+            tracing::warn!(target: "app", retries = 2, "slow");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        assert_eq!(
+            output,
+            "### job `id=7`\n\n#### step\n\n\
+             - **INFO** `app`: started\n- **WARN** `app`: slow `retries=2`\n"
+        );
+    }
+
+    #[test]
+    fn names_and_messages_are_escaped_and_kept_in_their_blocks() {
+        let buffer = SharedBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(MarkdownLayer::new(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("*job*", path = "a\nb").entered();
+
+            tracing::info!(target: "app", "first\n# second\n- third");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        assert_eq!(
+            output,
+            "### \\*job\\* `path=\"a\\nb\"`\n\n\
+             - **INFO** `app`: first\n  \\# second\n  \\- third\n"
+        );
+    }
+}
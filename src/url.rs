@@ -0,0 +1,159 @@
+//! URL and email autolinks.
+
+use crate::error::MdError;
+use crate::write::{write_title, written};
+
+/// Create an autolink to an absolute URI, e.g. `<https://example.com>`.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidAutolink`] when the URI doesn't begin with a scheme of 2 to 32
+/// characters or contains whitespace, control characters, `<` or `>`, any of which would stop
+/// it from being recognized as an autolink.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::url::autolink;
+///
+/// assert_eq!(autolink("https://docs.rs/md-writer").unwrap(), "<https://docs.rs/md-writer>");
+/// assert!(autolink("docs.rs/md-writer").is_err());
+/// assert!(autolink("https://example.com/a b").is_err());
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#uri-autolink>
+pub fn autolink(uri: &str) -> Result<String, MdError> {
+    validate_autolink(uri)?;
+
+    Ok(format!("<{uri}>"))
+}
+
+/// Check that a URI can be written as an autolink, see [`autolink`].
+pub(crate) fn validate_autolink(uri: &str) -> Result<(), MdError> {
+    let is_forbidden = |char: char| char.is_ascii_control() || matches!(char, ' ' | '<' | '>');
+    let valid = uri.split_once(':').is_some_and(|(scheme, rest)| {
+        (2..=32).contains(&scheme.len()) && is_scheme(scheme) && !rest.contains(is_forbidden)
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(MdError::InvalidAutolink(uri.to_owned()))
+    }
+}
+
+/// Create an autolink to an email address, e.g. `<user@example.com>`, which renders as a
+/// `mailto:` link.
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidAutolink`] when the address doesn't match the restricted form
+/// CommonMark recognizes, e.g. because it's quoted or its domain has an empty label.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::url::email_autolink;
+///
+/// assert_eq!(email_autolink("foo.bar@example.com").unwrap(), "<foo.bar@example.com>");
+/// assert!(email_autolink("foo@bar@example.com").is_err());
+/// assert!(email_autolink("foo@-example.com").is_err());
+/// ```
+///
+/// Reference
+/// =========
+/// - <https://spec.commonmark.org/0.30/#email-autolink>
+pub fn email_autolink(address: &str) -> Result<String, MdError> {
+    validate_email_autolink(address)?;
+
+    Ok(format!("<{address}>"))
+}
+
+/// Check that an email address can be written as an autolink, see [`email_autolink`].
+pub(crate) fn validate_email_autolink(address: &str) -> Result<(), MdError> {
+    let valid = address.split_once('@').is_some_and(|(local, domain)| {
+        let is_local_char = |char: char| {
+            char.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(char)
+        };
+        let is_domain_label = |label: &str| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|char| char.is_ascii_alphanumeric() || char == '-')
+        };
+
+        !local.is_empty()
+            && local.chars().all(is_local_char)
+            && domain.split('.').all(is_domain_label)
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(MdError::InvalidAutolink(address.to_owned()))
+    }
+}
+
+/// Whether a link destination begins with a URI scheme, e.g. `https:`.
+pub(crate) fn has_scheme(destination: &str) -> bool {
+    destination
+        .split_once(':')
+        .is_some_and(|(scheme, _)| scheme.len() > 1 && is_scheme(scheme))
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    scheme.starts_with(|char: char| char.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '+' | '-' | '.'))
+}
+
+/// Whether a link destination is relative to the document, e.g. `docs/usage.md` or
+/// `/assets/logo.png`, as opposed to a URL, a protocol-relative URL or a fragment.
+pub(crate) fn is_relative(destination: &str) -> bool {
+    !destination.is_empty()
+        && !destination.starts_with('#')
+        && !destination.starts_with("//")
+        && !has_scheme(destination)
+}
+
+/// Resolve a relative link destination against a base URL. Paths beginning with `/` are
+/// relative to the root of the base, e.g. of a repository.
+pub(crate) fn rebase(destination: &str, base: &str) -> String {
+    let path = destination.trim_start_matches('/');
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{}/{path}", base.trim_end_matches('/'))
+}
+
+/// Percent-encode everything but the unreserved characters of a URL, e.g. for a query value or
+/// a path segment.
+pub(crate) fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+pub(crate) fn link_title(title: Option<&str>) -> String {
+    written(|out| write_title(out, title))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autolink_requires_a_scheme_of_2_to_32_characters() {
+        assert!(autolink("a:b").is_err());
+        assert!(autolink(&format!("{}:b", "a".repeat(33))).is_err());
+        assert_eq!(autolink("irc://irc.example.com").unwrap(), "<irc://irc.example.com>");
+        assert!(autolink("https://example.com/<script>").is_err());
+    }
+}
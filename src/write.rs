@@ -0,0 +1,577 @@
+//! Writing of elements into existing buffers.
+//!
+//! Every function writes the same Markdown as its namesake in the crate root or its module
+//! (e.g. [`write_h1`] and [`crate::h1`], or [`write_table`] and [`Table::render`]) into any
+//! [`fmt::Write`], e.g. a reused `String` or a [`fmt::Formatter`], without allocating a
+//! `String` per element. The namesakes are rendered with these functions, so the two never
+//! disagree. Unlike their namesakes they aren't checked by the `strict` feature, as that
+//! requires the rendered element.
+//!
+//! Examples
+//! ========
+//! ```
+//! use md_writer::write::{write_bold, write_code_span, write_h3};
+//!
+//! let mut buffer = String::new();
+//!
+//! for name in ["parse", "render"] {
+//!     buffer.clear();
+//!     write_h3(&mut buffer, name)?;
+//!     buffer.push_str("\n\nSee ");
+//!     write_code_span(&mut buffer, name)?;
+//!     buffer.push_str(", which is ");
+//!     write_bold(&mut buffer, "stable")?;
+//!     buffer.push('.');
+//!
+//!     assert_eq!(buffer, format!("### {name}\n\nSee `{name}`, which is **stable**."));
+//! }
+//! # Ok::<(), std::fmt::Error>(())
+//! ```
+
+use crate::alert::AlertKind;
+use crate::error::MdError;
+use crate::escape::one_line;
+use crate::flavor::Flavor;
+use crate::list::{BulletList, OrderedList, TaskState};
+use crate::options::paragraph_lines;
+use crate::table::Table;
+use crate::url::{validate_autolink, validate_email_autolink};
+use crate::{longest_run, setext_underline_width, validate_thematic_break, LF};
+use std::fmt::{self, Write};
+
+/// Write a GitHub alert, see [`crate::alert::alert`].
+pub fn write_alert(out: &mut impl Write, kind: AlertKind, text: &str) -> fmt::Result {
+    let marker = format!("[!{}]", kind.as_str());
+
+    write_quoted_lines(out, [marker.as_str()].into_iter().chain(text.lines()), 1)
+}
+
+/// Write an autolink to an absolute URI, see [`crate::url::autolink`].
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidAutolink`] when the URI can't be written as an autolink, and
+/// [`MdError::Write`] when the writer fails.
+pub fn write_autolink(out: &mut impl Write, uri: &str) -> Result<(), MdError> {
+    validate_autolink(uri)?;
+
+    Ok(write!(out, "<{uri}>")?)
+}
+
+/// Write a blockquote, see [`crate::blockquote`].
+pub fn write_blockquote(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_blockquote_with_depth(out, text, 1)
+}
+
+/// Write a blockquote nested `depth` levels deep, see [`crate::blockquote_with_depth`].
+pub fn write_blockquote_with_depth(out: &mut impl Write, text: &str, depth: usize) -> fmt::Result {
+    if depth == 0 {
+        return out.write_str(text);
+    }
+
+    if text.is_empty() {
+        return write_repeated(out, '>', depth);
+    }
+
+    write_quoted_lines(out, text.lines(), depth)
+}
+
+/// Write text in strong emphasis, see [`crate::bold`].
+pub fn write_bold(out: &mut impl Write, text: &str) -> fmt::Result {
+    write!(out, "**{text}**")
+}
+
+/// Write text in both emphasis and strong emphasis, see [`crate::bold_italic`].
+pub fn write_bold_italic(out: &mut impl Write, text: &str) -> fmt::Result {
+    write!(out, "***{text}***")
+}
+
+/// Write a bullet list, see [`BulletList`].
+pub fn write_bullet_list(out: &mut impl Write, list: &BulletList) -> fmt::Result {
+    list.write(out)
+}
+
+/// Write an opening code fence, see [`crate::code_fence`].
+pub fn write_code_fence(out: &mut impl Write, info_string: Option<&str>) -> fmt::Result {
+    write!(out, "```{}", info_string.unwrap_or(""))
+}
+
+/// Write a code span, see [`crate::code_span`].
+pub fn write_code_span(out: &mut impl Write, code: &str) -> fmt::Result {
+    // A lone pair of backticks would render literally rather than as an empty code span.
+    if code.is_empty() {
+        return Ok(());
+    }
+
+    let delimiter = longest_run(code, '`') + 1;
+    let padded = code.starts_with('`')
+        || code.ends_with('`')
+        || (code.starts_with(' ') && code.ends_with(' ') && !code.trim().is_empty());
+    let padding = if padded { " " } else { "" };
+
+    write_repeated(out, '`', delimiter)?;
+    write!(out, "{padding}{code}{padding}")?;
+    write_repeated(out, '`', delimiter)
+}
+
+/// Write an autolink to an email address, see [`crate::url::email_autolink`].
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidAutolink`] when the address can't be written as an autolink, and
+/// [`MdError::Write`] when the writer fails.
+pub fn write_email_autolink(out: &mut impl Write, address: &str) -> Result<(), MdError> {
+    validate_email_autolink(address)?;
+
+    Ok(write!(out, "<{address}>")?)
+}
+
+/// Write a fenced code block, see [`crate::fenced_code_block`].
+pub fn write_fenced_code_block(
+    out: &mut impl Write,
+    code: &str,
+    info_string: Option<&str>,
+) -> fmt::Result {
+    write_fenced(out, '`', code, info_string)
+}
+
+/// Write a fenced JavaScript code block, see [`crate::fenced_js_code_block`].
+pub fn write_fenced_js_code_block(out: &mut impl Write, code: &str) -> fmt::Result {
+    write_fenced_code_block(out, code, Some("javascript"))
+}
+
+/// Write a fenced Rust code block, see [`crate::fenced_rs_code_block`].
+pub fn write_fenced_rs_code_block(out: &mut impl Write, code: &str) -> fmt::Result {
+    write_fenced_code_block(out, code, Some("rust"))
+}
+
+/// Write a fenced shell code block, see [`crate::fenced_sh_code_block`].
+pub fn write_fenced_sh_code_block(out: &mut impl Write, code: &str) -> fmt::Result {
+    write_fenced_code_block(out, code, Some("shell"))
+}
+
+/// Write a fenced TypeScript code block, see [`crate::fenced_ts_code_block`].
+pub fn write_fenced_ts_code_block(out: &mut impl Write, code: &str) -> fmt::Result {
+    write_fenced_code_block(out, code, Some("typescript"))
+}
+
+/// Write a header of the provided level, see [`crate::h()`].
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidHeadingLevel`] unless the level is 1–6, and [`MdError::Write`]
+/// when the writer fails.
+pub fn write_h(out: &mut impl Write, level: u8, text: &str) -> Result<(), MdError> {
+    match level {
+        1 => write_h1(out, text)?,
+        2 => write_h2(out, text)?,
+        3 => write_h3(out, text)?,
+        4 => write_h4(out, text)?,
+        5 => write_h5(out, text)?,
+        6 => write_h6(out, text)?,
+        level => return Err(MdError::InvalidHeadingLevel(level)),
+    }
+
+    Ok(())
+}
+
+/// Write a level 1 setext header, see [`crate::h1`].
+pub fn write_h1(out: &mut impl Write, text: &str) -> fmt::Result {
+    if text.trim().is_empty() {
+        return write_h1_atx(out, text);
+    }
+
+    write_setext(out, text, '=')
+}
+
+/// Write a level 1 ATX header, see [`crate::h1_atx`].
+pub fn write_h1_atx(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 1, text)
+}
+
+/// Write a level 2 setext header, see [`crate::h2`].
+pub fn write_h2(out: &mut impl Write, text: &str) -> fmt::Result {
+    if text.trim().is_empty() {
+        return write_h2_atx(out, text);
+    }
+
+    write_setext(out, text, '-')
+}
+
+/// Write a level 2 ATX header, see [`crate::h2_atx`].
+pub fn write_h2_atx(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 2, text)
+}
+
+/// Write a level 3 ATX header, see [`crate::h3`].
+pub fn write_h3(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 3, text)
+}
+
+/// Write a level 4 ATX header, see [`crate::h4`].
+pub fn write_h4(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 4, text)
+}
+
+/// Write a level 5 ATX header, see [`crate::h5`].
+pub fn write_h5(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 5, text)
+}
+
+/// Write a level 6 ATX header, see [`crate::h6`].
+pub fn write_h6(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_atx(out, 6, text)
+}
+
+/// Write a hard line break, see [`crate::hard_break`].
+pub fn write_hard_break(out: &mut impl Write) -> fmt::Result {
+    write!(out, "\\{LF}")
+}
+
+/// Write an image, see [`crate::image()`].
+pub fn write_image(
+    out: &mut impl Write,
+    alt: &str,
+    source: &str,
+    title: Option<&str>,
+) -> fmt::Result {
+    out.write_str("![")?;
+
+    for char in alt.chars() {
+        if matches!(char, '[' | ']') {
+            out.write_char('\\')?;
+        }
+
+        out.write_char(char)?;
+    }
+
+    out.write_str("](")?;
+    write_destination(out, source)?;
+    write_title(out, title)?;
+    out.write_char(')')
+}
+
+/// Write text in emphasis, see [`crate::italic`].
+pub fn write_italic(out: &mut impl Write, text: &str) -> fmt::Result {
+    write!(out, "*{text}*")
+}
+
+/// Write a link, see [`crate::link`].
+pub fn write_link(
+    out: &mut impl Write,
+    text: &str,
+    destination: &str,
+    title: Option<&str>,
+) -> fmt::Result {
+    write!(out, "[{text}](")?;
+    write_destination(out, destination)?;
+    write_title(out, title)?;
+    out.write_char(')')
+}
+
+/// Write an ordered list, see [`OrderedList`].
+pub fn write_ordered_list(out: &mut impl Write, list: &OrderedList) -> fmt::Result {
+    list.write(out)
+}
+
+/// Write a paragraph, see [`crate::paragraph`].
+pub fn write_paragraph(out: &mut impl Write, text: &str) -> fmt::Result {
+    write_lines(out, paragraph_lines(text))
+}
+
+/// Write struck through text, see [`crate::strikethrough`].
+pub fn write_strikethrough(out: &mut impl Write, text: &str) -> fmt::Result {
+    write!(out, "~~{text}~~")
+}
+
+/// Write a pipe table, see [`Table`].
+pub fn write_table(out: &mut impl Write, table: &Table) -> fmt::Result {
+    table.write(out)
+}
+
+/// Write a task list, see [`crate::list::task_list`].
+pub fn write_task_list(
+    out: &mut impl Write,
+    items: &[(TaskState, &str)],
+    flavor: Flavor,
+) -> fmt::Result {
+    for (index, (state, text)) in items.iter().enumerate() {
+        if index > 0 {
+            out.write_char(LF)?;
+        }
+
+        write_task_list_item(out, text, *state, flavor)?;
+    }
+
+    Ok(())
+}
+
+/// Write a task list item, see [`crate::list::task_list_item`].
+pub fn write_task_list_item(
+    out: &mut impl Write,
+    text: &str,
+    state: TaskState,
+    flavor: Flavor,
+) -> fmt::Result {
+    let marker = state.marker(flavor);
+
+    if flavor.supports_task_lists() {
+        write!(out, "- [{marker}] {text}")
+    } else {
+        let checkbox = if marker == ' ' { '☐' } else { '☑' };
+
+        write!(out, "- {checkbox} {text}")
+    }
+}
+
+/// Write a thematic break, see [`crate::thematic_break`].
+pub fn write_thematic_break(out: &mut impl Write) -> fmt::Result {
+    out.write_str("---")
+}
+
+/// Write a thematic break of the provided marker character and length, see
+/// [`crate::thematic_break_with`].
+///
+/// Errors
+/// ======
+/// Returns [`MdError::InvalidThematicBreak`] unless the marker is `-`, `*` or `_` and the
+/// length is at least 3, and [`MdError::Write`] when the writer fails.
+pub fn write_thematic_break_with(
+    out: &mut impl Write,
+    marker: char,
+    length: usize,
+) -> Result<(), MdError> {
+    validate_thematic_break(marker, length)?;
+
+    Ok(write_repeated(out, marker, length)?)
+}
+
+/// Render an element into a new `String`, for the functions which return one.
+pub(crate) fn written(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut buffer = String::new();
+
+    write(&mut buffer).expect("writing into a String doesn't fail");
+
+    buffer
+}
+
+/// Write a fenced code block fenced with the provided character, see
+/// [`FenceChar`](crate::options::FenceChar).
+pub(crate) fn write_fenced(
+    out: &mut impl Write,
+    fence_char: char,
+    code: &str,
+    info_string: Option<&str>,
+) -> fmt::Result {
+    // A fence is only closed by a fence at least as long, so it must outrun the code's.
+    let fence = (longest_run(code, fence_char) + 1).max(3);
+
+    write_repeated(out, fence_char, fence)?;
+    write!(out, "{}{LF}", info_string.unwrap_or(""))?;
+
+    // Empty code gets no line of its own, which would make it a line feed.
+    if !code.is_empty() {
+        write!(out, "{code}{LF}")?;
+    }
+
+    write_repeated(out, fence_char, fence)
+}
+
+/// Write a list item, indenting its continuation lines and children to the width of its
+/// marker so they stay part of the item.
+pub(crate) fn write_list_item(
+    out: &mut impl Write,
+    marker: &str,
+    text: &str,
+    children: Option<&str>,
+) -> fmt::Result {
+    let mut lines = text.lines().chain(children.into_iter().flat_map(str::lines));
+
+    write!(out, "{marker}{}", lines.next().unwrap_or_default())?;
+
+    for line in lines {
+        out.write_char(LF)?;
+
+        if !line.is_empty() {
+            write_repeated(out, ' ', marker.len())?;
+            out.write_str(line)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write lines separated by line feeds.
+pub(crate) fn write_lines(
+    out: &mut impl Write,
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+) -> fmt::Result {
+    for (index, line) in lines.into_iter().enumerate() {
+        if index > 0 {
+            out.write_char(LF)?;
+        }
+
+        out.write_str(line.as_ref())?;
+    }
+
+    Ok(())
+}
+
+/// Write a link destination escaped as [`crate::escape::escape_link_destination`] does.
+pub(crate) fn write_destination(out: &mut impl Write, destination: &str) -> fmt::Result {
+    let bracketed = destination.is_empty() || destination.contains([' ', '(', ')', '<', '>']);
+
+    if bracketed {
+        out.write_char('<')?;
+    }
+
+    for char in destination.chars() {
+        match char {
+            '\r' => out.write_str("%0D")?,
+            '\n' => out.write_str("%0A")?,
+            '<' | '>' if bracketed => write!(out, "\\{char}")?,
+            char => out.write_char(char)?,
+        }
+    }
+
+    if bracketed {
+        out.write_char('>')?;
+    }
+
+    Ok(())
+}
+
+/// Write a link title, preceded by a space and with its quotes escaped, if there is one.
+pub(crate) fn write_title(out: &mut impl Write, title: Option<&str>) -> fmt::Result {
+    let Some(title) = title else {
+        return Ok(());
+    };
+
+    out.write_str(" \"")?;
+
+    for char in title.chars() {
+        if char == '"' {
+            out.write_char('\\')?;
+        }
+
+        out.write_char(char)?;
+    }
+
+    out.write_char('"')
+}
+
+/// Write a setext header of the provided underline character.
+/// Write an ATX header, joining the lines of its text with spaces as a line feed would end it.
+pub(crate) fn write_atx(out: &mut impl Write, level: usize, text: &str) -> fmt::Result {
+    write_repeated(out, '#', level)?;
+    write!(out, " {}", one_line(text))
+}
+
+fn write_setext(out: &mut impl Write, text: &str, char: char) -> fmt::Result {
+    write!(out, "{text}{LF}")?;
+    write_repeated(out, char, setext_underline_width(text))
+}
+
+/// Write lines prefixed with `depth` quote markers, leaving the markers of empty lines bare.
+fn write_quoted_lines<'a>(
+    out: &mut impl Write,
+    lines: impl Iterator<Item = &'a str>,
+    depth: usize,
+) -> fmt::Result {
+    for (index, line) in lines.enumerate() {
+        if index > 0 {
+            out.write_char(LF)?;
+        }
+
+        write_repeated(out, '>', depth)?;
+
+        if !line.is_empty() {
+            write!(out, " {line}")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_repeated(out: &mut impl Write, char: char, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| out.write_char(char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_the_same_markdown_as_the_string_functions() {
+        let text = "Hello `world`!\n\n  second line ";
+
+        assert_eq!(written(|out| write_blockquote(out, text)), crate::blockquote(text));
+        assert_eq!(
+            written(|out| write_blockquote_with_depth(out, text, 3)),
+            crate::blockquote_with_depth(text, 3)
+        );
+        assert_eq!(written(|out| write_code_span(out, "`a``")), crate::code_span("`a``"));
+        assert_eq!(written(|out| write_code_span(out, " a ")), crate::code_span(" a "));
+        assert_eq!(
+            written(|out| write_fenced_code_block(out, "````", Some("md"))),
+            crate::fenced_code_block("````", Some("md"))
+        );
+        assert_eq!(written(|out| write_h1(out, "日本語")), crate::h1("日本語"));
+        assert_eq!(written(|out| write_h2(out, "a\nbc")), crate::h2("a\nbc"));
+        assert_eq!(written(|out| write_paragraph(out, text)), crate::paragraph(text));
+        assert_eq!(written(write_hard_break), crate::hard_break());
+        assert_eq!(
+            written(|out| write_image(out, "[a]", "a b.png", Some("\"A\""))),
+            crate::image("[a]", "a b.png", Some("\"A\""))
+        );
+        assert_eq!(
+            written(|out| write_link(out, "a", "<x>\n", None)),
+            crate::link("a", "<x>\n", None)
+        );
+        assert_eq!(written(|out| write_link(out, "a", "", None)), crate::link("a", "", None));
+    }
+
+    #[test]
+    fn writes_the_same_blocks_as_the_builders() {
+        let children = BulletList::new().item("child").render();
+        let bullets = BulletList::new().item("a\nb").item_with_children("c", &children);
+        let numbers = OrderedList::new().start(99).item("a").item_with_children("b", &children);
+        let table = Table::from_rows(["a", "b"], [["x|y", "日本"]]).padded(true);
+        let tasks = [(TaskState::Done, "a"), (TaskState::Todo, "b")];
+
+        assert_eq!(written(|out| write_bullet_list(out, &bullets)), bullets.render());
+        assert_eq!(written(|out| write_ordered_list(out, &numbers)), numbers.render());
+        assert_eq!(written(|out| write_table(out, &table)), table.render());
+        assert_eq!(
+            written(|out| write_task_list(out, &tasks, Flavor::Gfm)),
+            crate::list::task_list(&tasks, Flavor::Gfm)
+        );
+        assert_eq!(
+            written(|out| write_alert(out, AlertKind::Tip, "a\n\nb")),
+            crate::alert::tip("a\n\nb")
+        );
+    }
+
+    #[test]
+    fn validating_writers_reject_what_their_namesakes_do() {
+        let mut buffer = String::new();
+
+        assert_eq!(write_h(&mut buffer, 7, "a"), Err(MdError::InvalidHeadingLevel(7)));
+        assert_eq!(
+            write_thematic_break_with(&mut buffer, '=', 3),
+            Err(MdError::InvalidThematicBreak { marker: '=', length: 3 })
+        );
+        assert_eq!(
+            write_autolink(&mut buffer, "a b"),
+            Err(MdError::InvalidAutolink("a b".to_owned()))
+        );
+        assert!(write_email_autolink(&mut buffer, "a@-b").is_err());
+        assert_eq!(buffer, "");
+
+        write_h(&mut buffer, 2, "").unwrap();
+        write_thematic_break_with(&mut buffer, '*', 3).unwrap();
+        write_email_autolink(&mut buffer, "a@b.c").unwrap();
+
+        assert_eq!(buffer, "## ***<a@b.c>");
+    }
+}
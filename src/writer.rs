@@ -0,0 +1,331 @@
+//! Streaming of Markdown to files, sockets and other writers.
+
+use crate::document::{Block, Inline};
+use crate::escape::escape_table_cell;
+use crate::options::RenderOptions;
+use crate::paragraph;
+use std::io::{self, Write};
+
+/// A writer of Markdown blocks which streams them to an [`io::Write`] as they're written,
+/// rather than accumulating the whole document in memory.
+///
+/// Blocks are separated by blank lines and the output ends with a line ending once
+/// [`finish`](MarkdownWriter::finish) is called. Headings, code fences and line endings follow
+/// the writer's [`RenderOptions`], so the whole document is written in one consistent style.
+/// Wrap files and sockets in an [`io::BufWriter`], as every block is written with several
+/// small writes.
+///
+/// Examples
+/// ========
+/// ```
+/// use md_writer::options::RenderOptions;
+/// use md_writer::writer::MarkdownWriter;
+///
+/// let mut writer = MarkdownWriter::with_options(Vec::new(), RenderOptions::github());
+///
+/// writer.h1("Report")?;
+/// writer.paragraph("Generated nightly.")?;
+/// writer.table(&["Test", "Result"], [["parse", "ok"], ["render", "failed"]])?;
+///
+/// let markdown = String::from_utf8(writer.finish()?).unwrap();
+///
+/// assert_eq!(
+///     markdown,
+///     "Report\n======\n\nGenerated nightly.\n\n\
+///      | Test | Result |\n| --- | --- |\n| parse | ok |\n| render | failed |\n"
+/// );
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct MarkdownWriter<W: Write> {
+    inner: W,
+    options: RenderOptions,
+    empty: bool,
+}
+
+impl<W: Write> MarkdownWriter<W> {
+    /// Create a writer streaming to the provided one with the default options.
+    pub fn new(inner: W) -> MarkdownWriter<W> {
+        MarkdownWriter::with_options(inner, RenderOptions::default())
+    }
+
+    /// Create a writer streaming to the provided one with the provided options.
+    ///
+    /// Examples
+    /// ========
+    /// ```
+    /// use md_writer::options::{FenceChar, HeadingStyle, LineEnding, RenderOptions};
+    /// use md_writer::writer::MarkdownWriter;
+    ///
+    /// let options = RenderOptions {
+    ///     heading: HeadingStyle::Atx,
+    ///     fence: FenceChar::Tilde,
+    ///     line_ending: LineEnding::CrLf,
+    ///     ..RenderOptions::default()
+    /// };
+    /// let mut writer = MarkdownWriter::with_options(Vec::new(), options);
+    ///
+    /// writer.h1("Usage")?;
+    /// writer.fenced_code_block("cargo test", Some("sh"))?;
+    ///
+    /// assert_eq!(writer.finish()?, b"# Usage\r\n\r\n~~~sh\r\ncargo test\r\n~~~\r\n");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_options(inner: W, options: RenderOptions) -> MarkdownWriter<W> {
+        MarkdownWriter { inner, options, empty: true }
+    }
+
+    /// The options the writer writes with.
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    /// Write a block of already rendered Markdown, e.g. the output of one of the crate's
+    /// functions. Its line feeds are replaced with the writer's line ending.
+    pub fn block(&mut self, markdown: &str) -> io::Result<()> {
+        self.separate()?;
+        self.inner.write_all(self.options.line_ending.apply(markdown).as_bytes())
+    }
+
+    /// Write a header of the provided level in the writer's heading style.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an [`io::ErrorKind::InvalidInput`] error when the level is outside of 1–6.
+    pub fn heading(&mut self, level: u8, text: &str) -> io::Result<()> {
+        let heading = self
+            .options
+            .heading
+            .heading(level, &self.options.isolate(text))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        self.block(&heading)
+    }
+
+    /// Write a level 1 header.
+    pub fn h1(&mut self, text: &str) -> io::Result<()> {
+        self.heading(1, text)
+    }
+
+    /// Write a level 2 header.
+    pub fn h2(&mut self, text: &str) -> io::Result<()> {
+        self.heading(2, text)
+    }
+
+    /// Write a level 3 header.
+    pub fn h3(&mut self, text: &str) -> io::Result<()> {
+        self.heading(3, text)
+    }
+
+    /// Write a level 4 header.
+    pub fn h4(&mut self, text: &str) -> io::Result<()> {
+        self.heading(4, text)
+    }
+
+    /// Write a level 5 header.
+    pub fn h5(&mut self, text: &str) -> io::Result<()> {
+        self.heading(5, text)
+    }
+
+    /// Write a level 6 header.
+    pub fn h6(&mut self, text: &str) -> io::Result<()> {
+        self.heading(6, text)
+    }
+
+    /// Write a paragraph of plain text, escaped per the writer's
+    /// [`escape_mode`](RenderOptions::escape_mode) as [`Document`](crate::document::Document)
+    /// escapes it. See [`block`](MarkdownWriter::block) to write Markdown as is.
+    pub fn paragraph(&mut self, text: &str) -> io::Result<()> {
+        let paragraph = paragraph(&self.options.escape(text));
+
+        self.block(&paragraph)
+    }
+
+    /// Write a fenced code block fenced with the writer's fence character.
+    pub fn fenced_code_block(&mut self, code: &str, info_string: Option<&str>) -> io::Result<()> {
+        self.block(&self.options.fence.fenced_code_block(code, info_string))
+    }
+
+    /// Write a pipe table, streaming its rows one at a time.
+    ///
+    /// As the rows aren't known in advance, the table has as many columns as it has headers:
+    /// shorter rows are padded with empty cells and longer ones are truncated. Cells are plain
+    /// text, escaped per the writer's [`escape_mode`](RenderOptions::escape_mode) and so pipes
+    /// and line feeds don't break the table. For flavors without tables, see
+    /// [`Flavor::supports_tables`](crate::flavor::Flavor::supports_tables), the rows are
+    /// buffered and the table is written as raw HTML as [`Document`](crate::document::Document)
+    /// writes it.
+    ///
+    /// Errors
+    /// ======
+    /// Returns an [`io::ErrorKind::InvalidInput`] error when there are no headers, since a
+    /// table needs at least one column.
+    pub fn table<H, R, C>(&mut self, headers: &[H], rows: R) -> io::Result<()>
+    where
+        H: AsRef<str>,
+        R: IntoIterator,
+        R::Item: IntoIterator<Item = C>,
+        C: AsRef<str>,
+    {
+        let columns = headers.len();
+
+        if columns == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "a table needs at least one column",
+            ));
+        }
+
+        if !self.options.flavor.supports_tables() {
+            let row = |cells: &mut dyn Iterator<Item = String>| -> Vec<Vec<Inline>> {
+                (0..columns).map(|_| vec![Inline::text(cells.next().unwrap_or_default())]).collect()
+            };
+            let table = Block::Table {
+                headers: row(&mut headers.iter().map(|header| header.as_ref().to_owned())),
+                rows: rows
+                    .into_iter()
+                    .map(|cells| row(&mut cells.into_iter().map(|cell| cell.as_ref().to_owned())))
+                    .collect(),
+            };
+
+            return self.block(&table.render(&self.options));
+        }
+
+        let options = self.options.clone();
+        let escape = |cell: &str| escape_table_cell(&options.isolate(&options.escape(cell)));
+
+        self.separate()?;
+        self.row(headers.iter().map(|header| escape(header.as_ref())), columns)?;
+        self.line_ending()?;
+        self.row((0..columns).map(|_| "---".to_owned()), columns)?;
+
+        for cells in rows {
+            self.line_ending()?;
+            self.row(cells.into_iter().map(|cell| escape(cell.as_ref())), columns)?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// End the output with a line ending, flush it and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.empty {
+            self.line_ending()?;
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// The underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Write the blank line separating a block from the preceding one, if any.
+    fn separate(&mut self) -> io::Result<()> {
+        if !std::mem::replace(&mut self.empty, false) {
+            self.line_ending()?;
+            self.line_ending()?;
+        }
+
+        Ok(())
+    }
+
+    fn line_ending(&mut self) -> io::Result<()> {
+        self.inner.write_all(self.options.line_ending.as_str().as_bytes())
+    }
+
+    /// Write a row of already escaped cells without a trailing line feed, padding or
+    /// truncating it to the provided number of columns.
+    fn row(&mut self, mut cells: impl Iterator<Item = String>, columns: usize) -> io::Result<()> {
+        self.inner.write_all(b"|")?;
+
+        for _ in 0..columns {
+            let cell = cells.next().unwrap_or_default();
+
+            write!(self.inner, " {cell} |")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::Document;
+
+    #[test]
+    fn table_pads_and_truncates_rows_to_the_headers() {
+        let mut writer = MarkdownWriter::with_options(Vec::new(), RenderOptions::github());
+
+        writer.table(&["a", "b"], [vec!["1"], vec!["2", "3|", "4"]]).unwrap();
+        assert!(writer.heading(7, "Deep").is_err());
+
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            "| a | b |\n| --- | --- |\n| 1 |  |\n| 2 | 3\\| |\n"
+        );
+    }
+
+    #[test]
+    fn paragraphs_and_cells_are_escaped_as_plain_text() {
+        let mut writer = MarkdownWriter::with_options(Vec::new(), RenderOptions::github());
+
+        writer.paragraph("# Not a heading\n*not emphasis*").unwrap();
+        writer.table(&["[a]"], [["`b` | c"]]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            "\\# Not a heading\n\\*not emphasis\\*\n\n\
+             | \\[a\\] |\n| --- |\n| \\`b\\` \\| c |\n"
+        );
+    }
+
+    #[test]
+    fn table_is_html_for_flavors_without_tables() {
+        let headers = ["a", "b"];
+        let rows = [vec!["*1*"], vec!["2", "3", "4"]];
+        let mut writer = MarkdownWriter::new(Vec::new());
+
+        writer.table(&headers, rows.clone()).unwrap();
+
+        let document = Document::new().table(&headers, &[vec!["*1*", ""], vec!["2", "3"]]);
+
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            format!("{}\n", document.render(&RenderOptions::default()))
+        );
+    }
+
+    #[test]
+    fn isolate_rtl_wraps_rtl_headings_and_cells() {
+        let options = RenderOptions { isolate_rtl: true, ..RenderOptions::github() };
+        let mut writer = MarkdownWriter::with_options(Vec::new(), options);
+
+        writer.h2("مرحبا").unwrap();
+        writer.table(&["Word"], [["مرحبا"], ["hello"]]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            "\u{2067}مرحبا\u{2069}\n-----\n\n\
+             | Word |\n| --- |\n| \u{2067}مرحبا\u{2069} |\n| hello |\n"
+        );
+    }
+
+    #[test]
+    fn table_without_headers_is_an_error() {
+        let mut writer = MarkdownWriter::with_options(Vec::new(), RenderOptions::github());
+        let error = writer.table::<&str, _, &str>(&[], [["a"]]).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(writer.finish().unwrap(), b"");
+    }
+}
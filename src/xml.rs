@@ -0,0 +1,208 @@
+//! A minimal XML tokenizer for importing CI reports.
+
+/// A node of an XML document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Node<'a> {
+    /// A start tag, or an empty-element tag when `empty` is true.
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        empty: bool,
+    },
+    /// An end tag.
+    End(&'a str),
+    /// Character data, with references resolved. CDATA sections are included verbatim.
+    Text(String),
+}
+
+impl Node<'_> {
+    /// The value of an attribute of a start tag.
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        match self {
+            Node::Start { attributes, .. } => attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Split an XML document into tags and text, skipping declarations, processing instructions,
+/// comments and whitespace-only text.
+pub(crate) fn nodes(xml: &str) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut rest = xml;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut nodes, unescape(rest));
+            break;
+        };
+
+        push_text(&mut nodes, unescape(&rest[..start]));
+        rest = &rest[start..];
+
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").unwrap_or(cdata.len());
+
+            push_text(&mut nodes, cdata[..end].to_owned());
+            rest = cdata.get(end + 3..).unwrap_or_default();
+        } else if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else {
+            let end = tag_end(rest);
+            let tag = &rest[1..end];
+
+            rest = rest.get(end + 1..).unwrap_or_default();
+
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            } else if let Some(name) = tag.strip_prefix('/') {
+                nodes.push(Node::End(name.trim()));
+            } else {
+                let empty = tag.ends_with('/');
+                let tag = tag.trim_end_matches('/');
+                let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+
+                nodes.push(Node::Start {
+                    name: &tag[..name_end],
+                    attributes: attributes(&tag[name_end..]),
+                    empty,
+                });
+            }
+        }
+    }
+
+    nodes
+}
+
+/// The index of the `>` ending the tag at the start of the provided text, skipping quoted
+/// attribute values which may contain `>`, or the length of the text when it's unterminated.
+fn tag_end(tag: &str) -> usize {
+    let mut quote = None;
+
+    for (index, char) in tag.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (None, '>') => return index,
+            (Some(open), _) if open == char => quote = None,
+            _ => {}
+        }
+    }
+
+    tag.len()
+}
+
+/// Merge adjacent text, e.g. either side of a CDATA section, and drop whitespace-only text.
+fn push_text(nodes: &mut Vec<Node<'_>>, text: String) {
+    if let Some(Node::Text(previous)) = nodes.last_mut() {
+        previous.push_str(&text);
+    } else if !text.trim().is_empty() {
+        nodes.push(Node::Text(text));
+    }
+}
+
+fn attributes(mut rest: &str) -> Vec<(&str, String)> {
+    let mut attributes = Vec::new();
+
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].trim();
+        let value = rest[equals + 1..].trim_start();
+        let Some(quote) = value.chars().next().filter(|quote| *quote == '"' || *quote == '\'')
+        else {
+            break;
+        };
+        let Some(end) = value[1..].find(quote) else {
+            break;
+        };
+
+        attributes.push((name, unescape(&value[1..end + 1])));
+        rest = &value[end + 2..];
+    }
+
+    attributes
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let reference = &rest[1..end];
+        let char = match reference {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => reference
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| reference.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+
+        match char {
+            Some(char) => {
+                unescaped.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_resolve_references_and_cdata() {
+        let xml = "<?xml?><!-- c --><a b='1 &amp; 2'/>\n<c>x &lt; <![CDATA[<y>]]></c>";
+
+        assert_eq!(
+            nodes(xml),
+            [
+                Node::Start { name: "a", attributes: vec![("b", "1 & 2".to_owned())], empty: true },
+                Node::Start { name: "c", attributes: Vec::new(), empty: false },
+                Node::Text("x < <y>".to_owned()),
+                Node::End("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_attribute_values_may_contain_greater_than_signs() {
+        let xml = r#"<failure message="expected a > b" type='x>y'>trace</failure>"#;
+
+        assert_eq!(
+            nodes(xml),
+            [
+                Node::Start {
+                    name: "failure",
+                    attributes: vec![
+                        ("message", "expected a > b".to_owned()),
+                        ("type", "x>y".to_owned()),
+                    ],
+                    empty: false,
+                },
+                Node::Text("trace".to_owned()),
+                Node::End("failure"),
+            ]
+        );
+    }
+}